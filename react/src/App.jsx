@@ -12,6 +12,7 @@ import WalletInfo from './pages/WalletInfo';
 import WalletCredentials from './pages/WalletCredentials';
 import WalletOidc4vp from './pages/WalletOidc4vp';
 import WalletOidc4vci from './pages/WalletOidc4vci';
+import Login from './pages/Login';
 
 import NotificationProvider from '@/contexts/NotificationContext';
 
@@ -27,6 +28,7 @@ function App() {
             <Route index element={<Navigate to="/home" replace />} />
             <Route path="home" element={<Home />} />
             <Route path="about" element={<About />} />
+            <Route path="login" element={<Login />} />
             <Route path="minions" element={<Minions />} />
             <Route path="minions/:id" element={<MinionDetails />} />
             <Route path="requests" element={<Requests />} />
@@ -136,7 +136,12 @@ export const Header = () => {
             )}
           </DropdownMenuContent>
         </DropdownMenu>
-        <Button variant="ghost" size="icon" className="h-9 w-9">
+        <Button
+          variant="ghost"
+          size="icon"
+          className="h-9 w-9"
+          onClick={() => navigate('/login')}
+        >
           <User className="h-5 w-5 text-muted-foreground" />
         </Button>
       </div>
@@ -1,5 +1,5 @@
 import React, { createContext, useContext, useEffect, useState } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, getAdminToken } from '@/lib/api';
 
 const NotificationContext = createContext();
 
@@ -12,8 +12,11 @@ export default function NotificationProvider({ children }) {
   const [unreadCount, setUnreadCount] = useState(0);
 
   useEffect(() => {
-    const sseUrl = `${apiUrl}/react/notifications/stream`;
-    console.log('Connecting to SSE at', sseUrl);
+    // EventSource cannot send headers, so the token goes in the query
+    const token = getAdminToken();
+    const query = token ? `?access_token=${encodeURIComponent(token)}` : '';
+    const sseUrl = `${apiUrl}/react/notifications/stream${query}`;
+    console.log('Connecting to SSE at', `${apiUrl}/react/notifications/stream`);
 
     const eventSource = new EventSource(sseUrl);
 
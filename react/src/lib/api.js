@@ -1,3 +1,26 @@
 export const HOST_URL = import.meta.env.VITE_HOST_URL;
 export const API_PATH = import.meta.env.VITE_API_PATH;
 export const VITE_API_SERVER_URL = `${HOST_URL}${API_PATH}`;
+
+const TOKEN_KEY = 'adminToken';
+
+export const getAdminToken = () => localStorage.getItem(TOKEN_KEY);
+
+export const setAdminToken = (token) => {
+  if (token) localStorage.setItem(TOKEN_KEY, token);
+  else localStorage.removeItem(TOKEN_KEY);
+};
+
+// fetch with the admin API key or access token, sending the user to login when it is rejected
+export const apiFetch = async (url, options = {}) => {
+  const token = getAdminToken();
+  const headers = { ...(options.headers || {}) };
+  if (token) headers.Authorization = `Bearer ${token}`;
+
+  const response = await fetch(url, { ...options, headers });
+  if (response.status === 401) {
+    setAdminToken(null);
+    window.location.assign(`${import.meta.env.BASE_URL}login`);
+  }
+  return response;
+};
@@ -0,0 +1,185 @@
+import { useState, useEffect } from 'react';
+import { useNavigate } from 'react-router-dom';
+import QRCode from 'react-qr-code';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch, getAdminToken, setAdminToken } from '@/lib/api';
+import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+
+const Login = () => {
+  const [token, setToken] = useState('');
+  const [login, setLogin] = useState(null);
+  const [loginError, setLoginError] = useState(null);
+  const [holder, setHolder] = useState('');
+  const [operatorName, setOperatorName] = useState('');
+  const [operatorRole, setOperatorRole] = useState('viewer');
+  const [operatorVc, setOperatorVc] = useState(null);
+  const navigate = useNavigate();
+  const loggedIn = Boolean(getAdminToken());
+
+  const finishLogin = (newToken) => {
+    setAdminToken(newToken);
+    // Reload so the notification stream reconnects with the new token
+    window.location.assign(`${import.meta.env.BASE_URL}home`);
+  };
+
+  const handleLogin = (e) => {
+    e.preventDefault();
+    if (!token) return;
+    finishLogin(token.trim());
+  };
+
+  const handleLogout = () => {
+    setAdminToken(null);
+    navigate('/login');
+    window.location.reload();
+  };
+
+  const handleWalletLogin = async () => {
+    setLoginError(null);
+    try {
+      const response = await fetch(`${apiUrl}/auth/login`, { method: 'POST' });
+      if (!response.ok) {
+        throw new Error('Wallet login is not available');
+      }
+      setLogin(await response.json());
+    } catch (err) {
+      console.error('Error starting wallet login:', err);
+      setLoginError(err.message);
+    }
+  };
+
+  useEffect(() => {
+    if (!login) return;
+    const timer = setInterval(async () => {
+      try {
+        const response = await fetch(`${apiUrl}/auth/login/${login.id}`, {
+          headers: { 'x-login-nonce': login.nonce },
+        });
+        if (!response.ok) return;
+        const poll = await response.json();
+        if (poll.session) {
+          clearInterval(timer);
+          finishLogin(poll.session.token);
+        } else if (poll.status === 'Pending') {
+          setLoginError(poll.error);
+        } else if (poll.status !== 'Pending') {
+          clearInterval(timer);
+          setLogin(null);
+          setLoginError(poll.error || `Login ${poll.status.toLowerCase()}`);
+        }
+      } catch (err) {
+        console.error('Error polling wallet login:', err);
+      }
+    }, 2000);
+    return () => clearInterval(timer);
+  }, [login]);
+
+  const handleIssueOperator = async (e) => {
+    e.preventDefault();
+    try {
+      const response = await apiFetch(`${apiUrl}/auth/operators`, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify({
+          holder,
+          name: operatorName || undefined,
+          role: operatorRole,
+        }),
+      });
+      if (!response.ok) {
+        throw new Error('Failed to issue operator credential');
+      }
+      setOperatorVc(await response.json());
+    } catch (err) {
+      console.error('Error issuing operator credential:', err);
+      alert('Error: ' + err.message);
+    }
+  };
+
+  return (
+    <div className="p-8 max-w-xl">
+      <h2 className="text-2xl font-bold text-brand-purple drop-shadow-md mb-6">Admin Login</h2>
+      <form onSubmit={handleLogin} className="flex gap-4 mb-6">
+        <Input
+          type="password"
+          value={token}
+          onChange={(e) => setToken(e.target.value)}
+          placeholder="API key or access token"
+        />
+        <Button type="submit" className="bg-brand-sky/20 text-brand-sky border border-brand-sky">
+          LOGIN
+        </Button>
+      </form>
+
+      <div className="mb-6">
+        <Button
+          onClick={handleWalletLogin}
+          className="bg-brand-purple/20 text-brand-purple border border-brand-purple"
+        >
+          LOGIN WITH WALLET
+        </Button>
+        {loginError && <div className="text-danger mt-2">{loginError}</div>}
+        {login && (
+          <div className="mt-4">
+            <p className="text-muted-foreground mb-2">
+              Scan with your wallet and present your operator credential
+            </p>
+            <div className="p-4 bg-white/10 rounded-lg inline-block">
+              <QRCode
+                value={login.uri}
+                size={200}
+                style={{ height: 'auto', maxWidth: '100%', width: '100%' }}
+                viewBox={`0 0 200 200`}
+              />
+            </div>
+          </div>
+        )}
+      </div>
+
+      {loggedIn && (
+        <>
+          <form onSubmit={handleIssueOperator} className="flex flex-col gap-3 mb-6">
+            <h3 className="text-xl font-bold text-brand-purple drop-shadow-md">
+              Issue Operator Credential
+            </h3>
+            <Input
+              value={holder}
+              onChange={(e) => setHolder(e.target.value)}
+              placeholder="Operator wallet DID"
+            />
+            <Input
+              value={operatorName}
+              onChange={(e) => setOperatorName(e.target.value)}
+              placeholder="Name"
+            />
+            <select
+              value={operatorRole}
+              onChange={(e) => setOperatorRole(e.target.value)}
+              className="bg-background border border-brand-purple rounded p-2 text-muted-foreground"
+            >
+              <option value="viewer">viewer</option>
+              <option value="approver">approver</option>
+              <option value="admin">admin</option>
+            </select>
+            <Button type="submit" className="bg-brand-sky/20 text-brand-sky border border-brand-sky">
+              ISSUE
+            </Button>
+          </form>
+          {operatorVc && (
+            <pre className="mb-6 text-xs overflow-x-auto whitespace-pre-wrap break-all bg-black/40 p-3 rounded border border-brand-purple/50 text-muted-foreground">
+              {JSON.stringify(operatorVc, null, 2)}
+            </pre>
+          )}
+          <Button
+            onClick={handleLogout}
+            className="bg-red-500/20 text-red-500 border border-red-500 hover:bg-red-500/30"
+          >
+            LOGOUT
+          </Button>
+        </>
+      )}
+    </div>
+  );
+};
+
+export default Login;
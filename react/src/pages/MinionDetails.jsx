@@ -1,5 +1,5 @@
 import { useState, useEffect } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 import { useParams, useNavigate } from 'react-router-dom';
 import BooleanBadge from '../components/BooleanBadge';
 import { Button } from '@/components/ui/button';
@@ -16,7 +16,7 @@ const MinionDetails = () => {
   useEffect(() => {
     const fetchMinion = async () => {
       try {
-        const response = await fetch(`${apiUrl}/minions/${id}`);
+        const response = await apiFetch(`${apiUrl}/minions/${id}`);
         if (!response.ok) {
           throw new Error('Failed to fetch minion details');
         }
@@ -1,5 +1,5 @@
 import { useState, useEffect, useRef } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 import { createPortal } from 'react-dom';
 import { useNavigate } from 'react-router-dom';
 import BooleanBadge from '../components/BooleanBadge';
@@ -65,31 +65,45 @@ const TruncatedId = ({ id }) => {
   );
 };
 
+const PAGE_LIMIT = 25;
+
 const Minions = () => {
   const [minions, setMinions] = useState([]);
+  const [total, setTotal] = useState(0);
+  const [page, setPage] = useState(1);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState(null);
   const [filters, setFilters] = useState({
-    id: '',
-    slug: '',
+    participant: '',
     type: '',
     issuedVC: '',
-    savedAt: '',
-    isMe: '',
+    from: '',
+    to: '',
   });
-  const [sortConfig, setSortConfig] = useState({ key: null, direction: 'asc' });
+  const [sortConfig, setSortConfig] = useState({ key: 'saved_at', direction: 'desc' });
   const navigate = useNavigate();
 
   const fetchMinions = async () => {
-    setLoading(true);
     setError(null);
+    const params = new URLSearchParams({
+      page,
+      limit: PAGE_LIMIT,
+      sort: sortConfig.key,
+      order: sortConfig.direction,
+    });
+    if (filters.participant) params.set('participant', filters.participant);
+    if (filters.type) params.set('participant_type', filters.type);
+    if (filters.issuedVC) params.set('is_vc_issued', filters.issuedVC);
+    if (filters.from) params.set('from', `${filters.from}T00:00:00`);
+    if (filters.to) params.set('to', `${filters.to}T23:59:59`);
     try {
-      const response = await fetch(`${apiUrl}/minions/all`);
+      const response = await apiFetch(`${apiUrl}/minions/all?${params}`);
       if (!response.ok) {
         throw new Error('Failed to fetch minions');
       }
       const data = await response.json();
-      setMinions(data);
+      setMinions(data.items);
+      setTotal(data.total);
       setLoading(false);
     } catch (err) {
       console.error('Error fetching minions:', err);
@@ -99,8 +113,9 @@ const Minions = () => {
   };
 
   useEffect(() => {
-    fetchMinions();
-  }, [apiUrl]);
+    const timeout = setTimeout(fetchMinions, 300);
+    return () => clearTimeout(timeout);
+  }, [apiUrl, page, filters, sortConfig]);
 
   const handleRowClick = (id) => {
     navigate(`/minions/${id}`);
@@ -113,6 +128,7 @@ const Minions = () => {
 
   const handleFilterChange = (field, value) => {
     setFilters((prev) => ({ ...prev, [field]: value }));
+    setPage(1);
   };
 
   const handleSort = (key) => {
@@ -121,56 +137,9 @@ const Minions = () => {
       direction = 'desc';
     }
     setSortConfig({ key, direction });
+    setPage(1);
   };
 
-  const getSortValue = (minion, key) => {
-    switch (key) {
-      case 'id':
-        return minion.participant_id;
-      case 'slug':
-        return minion.participant_slug;
-      case 'type':
-        return minion.participant_type;
-      case 'issuedVC':
-        return minion.is_vc_issued ? 'Yes' : 'No';
-      case 'savedAt':
-        return minion.saved_at;
-      case 'isMe':
-        return minion.is_me ? 'Yes' : 'No';
-      default:
-        return '';
-    }
-  };
-
-  const filteredMinions = minions.filter((minion) => {
-    const issuedVCText = minion.is_vc_issued ? 'yes' : 'no';
-    const isMeText = minion.is_me ? 'yes' : 'no';
-
-    return (
-      minion.participant_id.toLowerCase().includes(filters.id.toLowerCase()) &&
-      minion.participant_slug.toLowerCase().includes(filters.slug.toLowerCase()) &&
-      minion.participant_type.toLowerCase().includes(filters.type.toLowerCase()) &&
-      issuedVCText.includes(filters.issuedVC.toLowerCase()) &&
-      minion.saved_at.toLowerCase().includes(filters.savedAt.toLowerCase()) &&
-      isMeText.includes(filters.isMe.toLowerCase())
-    );
-  });
-
-  const sortedMinions = [...filteredMinions].sort((a, b) => {
-    if (!sortConfig.key) return 0;
-
-    const aValue = getSortValue(a, sortConfig.key);
-    const bValue = getSortValue(b, sortConfig.key);
-
-    if (aValue < bValue) {
-      return sortConfig.direction === 'asc' ? -1 : 1;
-    }
-    if (aValue > bValue) {
-      return sortConfig.direction === 'asc' ? 1 : -1;
-    }
-    return 0;
-  });
-
   const getSortIndicator = (key) => {
     if (sortConfig.key !== key) {
       return ' ⇅';
@@ -178,6 +147,8 @@ const Minions = () => {
     return sortConfig.direction === 'asc' ? ' ▲' : ' ▼';
   };
 
+  const pages = Math.max(1, Math.ceil(total / PAGE_LIMIT));
+
   if (loading) return <div className="p-8 text-brand-sky">Loading...</div>;
   if (error) return <GeneralErrorComponent error={error} reset={fetchMinions} />;
 
@@ -189,57 +160,44 @@ const Minions = () => {
           <TableHeader>
             <TableRow className="border-b-brand-sky/30 hover:bg-transparent">
               <TableHead
-                onClick={() => handleSort('id')}
+                onClick={() => handleSort('participant_id')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                ID{getSortIndicator('id')}
+                ID{getSortIndicator('participant_id')}
               </TableHead>
               <TableHead
-                onClick={() => handleSort('slug')}
+                onClick={() => handleSort('participant_slug')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                Slug{getSortIndicator('slug')}
+                Slug{getSortIndicator('participant_slug')}
               </TableHead>
               <TableHead
-                onClick={() => handleSort('type')}
+                onClick={() => handleSort('participant_type')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                Type{getSortIndicator('type')}
+                Type{getSortIndicator('participant_type')}
               </TableHead>
               <TableHead
-                onClick={() => handleSort('issuedVC')}
+                onClick={() => handleSort('is_vc_issued')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                Issued VC{getSortIndicator('issuedVC')}
+                Issued VC{getSortIndicator('is_vc_issued')}
               </TableHead>
               <TableHead
-                onClick={() => handleSort('savedAt')}
+                onClick={() => handleSort('saved_at')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                Saved At{getSortIndicator('savedAt')}
-              </TableHead>
-              <TableHead
-                onClick={() => handleSort('isMe')}
-                className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
-              >
-                Is Me{getSortIndicator('isMe')}
+                Saved At{getSortIndicator('saved_at')}
               </TableHead>
+              <TableHead className="text-brand-sky">Is Me</TableHead>
             </TableRow>
             <TableRow className="bg-brand-blue/30 hover:bg-brand-blue/30 border-none">
+              <TableHead className="p-2" />
               <TableHead className="p-2">
                 <Input
                   placeholder="Filter..."
-                  value={filters.id}
-                  onChange={(e) => handleFilterChange('id', e.target.value)}
-                  onClick={(e) => e.stopPropagation()}
-                  className="h-8"
-                />
-              </TableHead>
-              <TableHead className="p-2">
-                <Input
-                  placeholder="Filter..."
-                  value={filters.slug}
-                  onChange={(e) => handleFilterChange('slug', e.target.value)}
+                  value={filters.participant}
+                  onChange={(e) => handleFilterChange('participant', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
                   className="h-8"
                 />
@@ -254,36 +212,38 @@ const Minions = () => {
                 />
               </TableHead>
               <TableHead className="p-2">
-                <Input
-                  placeholder="Filter..."
+                <select
                   value={filters.issuedVC}
                   onChange={(e) => handleFilterChange('issuedVC', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
-                  className="h-8"
-                />
+                  className="h-8 w-full rounded-md border border-input bg-transparent px-2 text-sm"
+                >
+                  <option value="">Any</option>
+                  <option value="true">Yes</option>
+                  <option value="false">No</option>
+                </select>
               </TableHead>
-              <TableHead className="p-2">
+              <TableHead className="p-2 flex gap-1">
                 <Input
-                  placeholder="Filter..."
-                  value={filters.savedAt}
-                  onChange={(e) => handleFilterChange('savedAt', e.target.value)}
+                  type="date"
+                  value={filters.from}
+                  onChange={(e) => handleFilterChange('from', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
                   className="h-8"
                 />
-              </TableHead>
-              <TableHead className="p-2">
                 <Input
-                  placeholder="Filter..."
-                  value={filters.isMe}
-                  onChange={(e) => handleFilterChange('isMe', e.target.value)}
+                  type="date"
+                  value={filters.to}
+                  onChange={(e) => handleFilterChange('to', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
                   className="h-8"
                 />
               </TableHead>
+              <TableHead className="p-2" />
             </TableRow>
           </TableHeader>
           <TableBody>
-            {sortedMinions.map((minion) => (
+            {minions.map((minion) => (
               <TableRow
                 key={minion.participant_id}
                 onClick={() => handleRowClick(minion.participant_id)}
@@ -307,11 +267,22 @@ const Minions = () => {
             ))}
           </TableBody>
         </Table>
-        {sortedMinions.length === 0 && minions.length > 0 && (
+        {minions.length === 0 && (
           <div className="p-8 text-center text-muted-foreground">
             No minions match the current filters
           </div>
         )}
+        <div className="flex items-center justify-end gap-4 p-4 text-muted-foreground">
+          <span>
+            Page {page} of {pages} ({total} minions)
+          </span>
+          <Button variant="outline" disabled={page <= 1} onClick={() => setPage(page - 1)}>
+            Previous
+          </Button>
+          <Button variant="outline" disabled={page >= pages} onClick={() => setPage(page + 1)}>
+            Next
+          </Button>
+        </div>
       </div>
     </div>
   );
@@ -1,9 +1,10 @@
 import { useState, useEffect } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 import { useParams, useNavigate } from 'react-router-dom';
 import { X509 } from 'jsrsasign';
 import BooleanBadge from '../components/BooleanBadge';
 import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
 import { cn } from '@/lib/utils';
 import { ArrowLeft } from 'lucide-react';
 import QRCode from 'react-qr-code';
@@ -15,18 +16,38 @@ const RequestDetails = () => {
   const [submitting, setSubmitting] = useState(false);
   const [error, setError] = useState(null);
   const [parsedCert, setParsedCert] = useState(null);
+  const [approver, setApprover] = useState(localStorage.getItem('approver') || '');
+  const [reason, setReason] = useState('');
+  const [history, setHistory] = useState([]);
+  const [callbacks, setCallbacks] = useState([]);
+  const [credentials, setCredentials] = useState([]);
   const navigate = useNavigate();
 
   useEffect(() => {
     const fetchRequest = async () => {
       try {
-        const response = await fetch(`${apiUrl}/approver/${id}`);
+        const response = await apiFetch(`${apiUrl}/approver/${id}`);
         if (!response.ok) {
           throw new Error('Failed to fetch request details');
         }
         const data = await response.json();
         setRequest(data);
 
+        const historyResponse = await apiFetch(`${apiUrl}/approver/${id}/history`);
+        if (historyResponse.ok) {
+          setHistory(await historyResponse.json());
+        }
+
+        const credentialsResponse = await apiFetch(`${apiUrl}/approver/${id}/credentials`);
+        if (credentialsResponse.ok) {
+          setCredentials(await credentialsResponse.json());
+        }
+
+        const callbacksResponse = await apiFetch(`${apiUrl}/approver/${id}/callbacks`);
+        if (callbacksResponse.ok) {
+          setCallbacks(await callbacksResponse.json());
+        }
+
         if (data.cert) {
           try {
             // The user said the cert is "plano" (cleaned). We need to wrap it to parse it as PEM.
@@ -60,19 +81,28 @@ const RequestDetails = () => {
   const handleDecision = async (approve) => {
     setSubmitting(true);
     try {
-      const response = await fetch(`${apiUrl}/approver/${id}`, {
+      const response = await apiFetch(`${apiUrl}/approver/${id}`, {
         method: 'POST',
         headers: {
           'Content-Type': 'application/json',
         },
-        body: JSON.stringify({ approve }),
+        body: JSON.stringify({
+          approve,
+          approver: approver || undefined,
+          reason: reason || undefined,
+        }),
       });
 
       if (!response.ok) {
         throw new Error('Failed to submit decision');
       }
 
-      // Refresh data or navigate back
+      if (approver) localStorage.setItem('approver', approver);
+      const tally = await response.json();
+      if (tally.status === 'Pending') {
+        alert(`Vote recorded (${tally.approvals}/${tally.required} approvals)`);
+        return;
+      }
       alert(`Request ${approve ? 'Approved' : 'Rejected'} successfully!`);
       navigate('/requests');
     } catch (err) {
@@ -83,6 +113,22 @@ const RequestDetails = () => {
     }
   };
 
+  const handleRedeliver = async (callbackId) => {
+    try {
+      const response = await apiFetch(`${apiUrl}/approver/${id}/callbacks/${callbackId}/redeliver`, {
+        method: 'POST',
+      });
+      if (!response.ok) {
+        throw new Error('Failed to redeliver callback');
+      }
+      const updated = await response.json();
+      setCallbacks((prev) => prev.map((cb) => (cb.id === updated.id ? updated : cb)));
+    } catch (err) {
+      console.error('Error redelivering callback:', err);
+      alert('Error: ' + err.message);
+    }
+  };
+
   if (loading) return <div className="p-8 text-brand-sky">Loading...</div>;
   if (error) return <div className="p-8 text-danger">Error: {error}</div>;
   if (!request) return <div className="p-8 text-muted-foreground">Request not found</div>;
@@ -144,6 +190,12 @@ const RequestDetails = () => {
             <strong className="text-brand-sky">VC Type:</strong>{' '}
             <span className="text-brand-purple">{request.vc_type}</span>
           </p>
+          {credentials.length > 1 && (
+            <p>
+              <strong className="text-brand-sky">Grant Credentials:</strong>{' '}
+              <span className="text-brand-purple">{credentials.join(', ')}</span>
+            </p>
+          )}
           <p>
             <strong className="text-brand-sky">Interact Methods:</strong>{' '}
             <span className="text-muted-foreground">{request.interact_method.join(', ')}</span>
@@ -231,8 +283,70 @@ const RequestDetails = () => {
         </div>
       )}
 
+      {history.length > 0 && (
+        <div className="rounded-lg border border-brand-purple bg-background/60 p-6 shadow-lg shadow-brand-purple/20 text-left mb-6 text-muted-foreground">
+          <h3 className="text-xl font-bold text-brand-purple drop-shadow-md mb-4">History</h3>
+          <ul className="space-y-2">
+            {history.map((event) => (
+              <li key={event.id}>
+                <span className="text-brand-sky">{new Date(event.created_at).toLocaleString()}</span>{' '}
+                <strong className="text-brand-purple">{event.event.replace('_', ' ')}</strong>
+                {event.actor && <span> by {event.actor}</span>}
+                {event.reason && <span>: {event.reason}</span>}
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+
+      {callbacks.length > 0 && (
+        <div className="rounded-lg border border-brand-purple bg-background/60 p-6 shadow-lg shadow-brand-purple/20 text-left mb-6 text-muted-foreground">
+          <h3 className="text-xl font-bold text-brand-purple drop-shadow-md mb-4">Callbacks</h3>
+          <ul className="space-y-3">
+            {callbacks.map((cb) => (
+              <li key={cb.id} className="flex items-start justify-between gap-4">
+                <div>
+                  <strong className="text-brand-purple">{cb.status}</strong>{' '}
+                  <span>after {cb.attempts} attempt(s) to {cb.uri}</span>
+                  {cb.status === 'Pending' && (
+                    <div className="text-brand-sky text-sm">
+                      Next attempt: {new Date(cb.next_attempt_at).toLocaleString()}
+                    </div>
+                  )}
+                  {cb.delivered_at && (
+                    <div className="text-brand-sky text-sm">
+                      Delivered: {new Date(cb.delivered_at).toLocaleString()}
+                    </div>
+                  )}
+                  {cb.last_error && <div className="text-danger text-sm">{cb.last_error}</div>}
+                </div>
+                {cb.status !== 'Delivered' && (
+                  <Button
+                    onClick={() => handleRedeliver(cb.id)}
+                    className="bg-brand-sky/20 text-brand-sky border border-brand-sky hover:bg-brand-sky/30"
+                  >
+                    Redeliver
+                  </Button>
+                )}
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+
       {showDecisionButtons && request.status === 'Pending' && (
         <div className="flex gap-4 mt-6">
+          <Input
+            value={approver}
+            onChange={(e) => setApprover(e.target.value)}
+            placeholder="Approver"
+            className="max-w-xs"
+          />
+          <Input
+            value={reason}
+            onChange={(e) => setReason(e.target.value)}
+            placeholder="Reason"
+          />
           <Button
             onClick={() => handleDecision(true)}
             disabled={submitting}
@@ -1,5 +1,5 @@
 import { useState, useEffect } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 import { useNavigate } from 'react-router-dom';
 import {
   Table,
@@ -10,6 +10,7 @@ import {
   TableRow,
 } from '@/components/ui/table';
 import { Input } from '@/components/ui/input';
+import { Button } from '@/components/ui/button';
 import { cn } from '@/lib/utils';
 import { GeneralErrorComponent } from '@/components/GeneralErrorComponent';
 
@@ -44,30 +45,45 @@ const StatusBadge = ({ status, isVcIssued }) => {
   );
 };
 
+const PAGE_LIMIT = 25;
+
 const Requests = () => {
   const [requests, setRequests] = useState([]);
+  const [total, setTotal] = useState(0);
+  const [page, setPage] = useState(1);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState(null);
   const [filters, setFilters] = useState({
-    id: '',
-    slug: '',
+    participant: '',
     vcType: '',
-    interactMethod: '',
     status: '',
-    createdAt: '',
+    from: '',
+    to: '',
   });
-  const [sortConfig, setSortConfig] = useState({ key: null, direction: 'asc' });
+  const [sortConfig, setSortConfig] = useState({ key: 'created_at', direction: 'desc' });
   const navigate = useNavigate();
 
   useEffect(() => {
     const fetchRequests = async () => {
+      const params = new URLSearchParams({
+        page,
+        limit: PAGE_LIMIT,
+        sort: sortConfig.key,
+        order: sortConfig.direction,
+      });
+      if (filters.participant) params.set('participant', filters.participant);
+      if (filters.vcType) params.set('vc_type', filters.vcType);
+      if (filters.status) params.set('status', filters.status);
+      if (filters.from) params.set('from', `${filters.from}T00:00:00`);
+      if (filters.to) params.set('to', `${filters.to}T23:59:59`);
       try {
-        const response = await fetch(`${apiUrl}/approver/all`);
+        const response = await apiFetch(`${apiUrl}/approver/all?${params}`);
         if (!response.ok) {
           throw new Error('Failed to fetch requests');
         }
         const data = await response.json();
-        setRequests(data);
+        setRequests(data.items);
+        setTotal(data.total);
         setLoading(false);
       } catch (err) {
         console.error('Error fetching requests:', err);
@@ -76,8 +92,9 @@ const Requests = () => {
       }
     };
 
-    fetchRequests();
-  }, [apiUrl]);
+    const timeout = setTimeout(fetchRequests, 300);
+    return () => clearTimeout(timeout);
+  }, [apiUrl, page, filters, sortConfig]);
 
   const handleRowClick = (id) => {
     navigate(`/requests/${id}`);
@@ -85,6 +102,7 @@ const Requests = () => {
 
   const handleFilterChange = (field, value) => {
     setFilters((prev) => ({ ...prev, [field]: value }));
+    setPage(1);
   };
 
   const handleSort = (key) => {
@@ -93,53 +111,9 @@ const Requests = () => {
       direction = 'desc';
     }
     setSortConfig({ key, direction });
+    setPage(1);
   };
 
-  const getSortValue = (req, key) => {
-    switch (key) {
-      case 'id':
-        return req.id;
-      case 'slug':
-        return req.participant_slug;
-      case 'vcType':
-        return req.vc_type;
-      case 'interactMethod':
-        return req.interact_method.join(', ');
-      case 'status':
-        return req.status;
-      case 'createdAt':
-        return req.created_at;
-      default:
-        return '';
-    }
-  };
-
-  const filteredRequests = requests.filter((req) => {
-    return (
-      req.id.toLowerCase().includes(filters.id.toLowerCase()) &&
-      req.participant_slug.toLowerCase().includes(filters.slug.toLowerCase()) &&
-      req.vc_type.toLowerCase().includes(filters.vcType.toLowerCase()) &&
-      req.interact_method.join(', ').toLowerCase().includes(filters.interactMethod.toLowerCase()) &&
-      req.status.toLowerCase().includes(filters.status.toLowerCase()) &&
-      req.created_at.toLowerCase().includes(filters.createdAt.toLowerCase())
-    );
-  });
-
-  const sortedRequests = [...filteredRequests].sort((a, b) => {
-    if (!sortConfig.key) return 0;
-
-    const aValue = getSortValue(a, sortConfig.key);
-    const bValue = getSortValue(b, sortConfig.key);
-
-    if (aValue < bValue) {
-      return sortConfig.direction === 'asc' ? -1 : 1;
-    }
-    if (aValue > bValue) {
-      return sortConfig.direction === 'asc' ? 1 : -1;
-    }
-    return 0;
-  });
-
   const getSortIndicator = (key) => {
     if (sortConfig.key !== key) {
       return ' ⇅';
@@ -147,6 +121,8 @@ const Requests = () => {
     return sortConfig.direction === 'asc' ? ' ▲' : ' ▼';
   };
 
+  const pages = Math.max(1, Math.ceil(total / PAGE_LIMIT));
+
   if (loading) return <div className="p-8 text-brand-sky">Loading...</div>;
   if (error) return <GeneralErrorComponent error={error} reset={() => window.location.reload()} />;
 
@@ -164,23 +140,18 @@ const Requests = () => {
                 ID{getSortIndicator('id')}
               </TableHead>
               <TableHead
-                onClick={() => handleSort('slug')}
-                className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
-              >
-                Slug{getSortIndicator('slug')}
-              </TableHead>
-              <TableHead
-                onClick={() => handleSort('vcType')}
+                onClick={() => handleSort('participant_slug')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                VC Type{getSortIndicator('vcType')}
+                Slug{getSortIndicator('participant_slug')}
               </TableHead>
               <TableHead
-                onClick={() => handleSort('interactMethod')}
+                onClick={() => handleSort('vc_type')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                Interact Method{getSortIndicator('interactMethod')}
+                VC Type{getSortIndicator('vc_type')}
               </TableHead>
+              <TableHead className="text-brand-sky">Interact Method</TableHead>
               <TableHead
                 onClick={() => handleSort('status')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
@@ -188,27 +159,19 @@ const Requests = () => {
                 Status{getSortIndicator('status')}
               </TableHead>
               <TableHead
-                onClick={() => handleSort('createdAt')}
+                onClick={() => handleSort('created_at')}
                 className="cursor-pointer text-brand-sky hover:text-brand-sky/80"
               >
-                Created At{getSortIndicator('createdAt')}
+                Created At{getSortIndicator('created_at')}
               </TableHead>
             </TableRow>
             <TableRow className="bg-brand-blue/30 hover:bg-brand-blue/30 border-none">
+              <TableHead className="p-2" />
               <TableHead className="p-2">
                 <Input
                   placeholder="Filter..."
-                  value={filters.id}
-                  onChange={(e) => handleFilterChange('id', e.target.value)}
-                  onClick={(e) => e.stopPropagation()}
-                  className="h-8"
-                />
-              </TableHead>
-              <TableHead className="p-2">
-                <Input
-                  placeholder="Filter..."
-                  value={filters.slug}
-                  onChange={(e) => handleFilterChange('slug', e.target.value)}
+                  value={filters.participant}
+                  onChange={(e) => handleFilterChange('participant', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
                   className="h-8"
                 />
@@ -222,29 +185,28 @@ const Requests = () => {
                   className="h-8"
                 />
               </TableHead>
+              <TableHead className="p-2" />
               <TableHead className="p-2">
                 <Input
                   placeholder="Filter..."
-                  value={filters.interactMethod}
-                  onChange={(e) => handleFilterChange('interactMethod', e.target.value)}
+                  value={filters.status}
+                  onChange={(e) => handleFilterChange('status', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
                   className="h-8"
                 />
               </TableHead>
-              <TableHead className="p-2">
+              <TableHead className="p-2 flex gap-1">
                 <Input
-                  placeholder="Filter..."
-                  value={filters.status}
-                  onChange={(e) => handleFilterChange('status', e.target.value)}
+                  type="date"
+                  value={filters.from}
+                  onChange={(e) => handleFilterChange('from', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
                   className="h-8"
                 />
-              </TableHead>
-              <TableHead className="p-2">
                 <Input
-                  placeholder="Filter..."
-                  value={filters.createdAt}
-                  onChange={(e) => handleFilterChange('createdAt', e.target.value)}
+                  type="date"
+                  value={filters.to}
+                  onChange={(e) => handleFilterChange('to', e.target.value)}
                   onClick={(e) => e.stopPropagation()}
                   className="h-8"
                 />
@@ -252,7 +214,7 @@ const Requests = () => {
             </TableRow>
           </TableHeader>
           <TableBody>
-            {sortedRequests.map((req) => (
+            {requests.map((req) => (
               <TableRow
                 key={req.id}
                 onClick={() => handleRowClick(req.id)}
@@ -270,11 +232,22 @@ const Requests = () => {
             ))}
           </TableBody>
         </Table>
-        {sortedRequests.length === 0 && requests.length > 0 && (
+        {requests.length === 0 && (
           <div className="p-8 text-center text-muted-foreground">
             No requests match the current filters
           </div>
         )}
+        <div className="flex items-center justify-end gap-4 p-4 text-muted-foreground">
+          <span>
+            Page {page} of {pages} ({total} requests)
+          </span>
+          <Button variant="outline" disabled={page <= 1} onClick={() => setPage(page - 1)}>
+            Previous
+          </Button>
+          <Button variant="outline" disabled={page >= pages} onClick={() => setPage(page + 1)}>
+            Next
+          </Button>
+        </div>
       </div>
     </div>
   );
@@ -1,5 +1,5 @@
 import { useState, useEffect } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 import { Outlet, useNavigate, useLocation } from 'react-router-dom';
 import { Button } from '@/components/ui/button';
 import { cn } from '@/lib/utils';
@@ -45,7 +45,7 @@ const Wallet = () => {
     setError(null);
 
     try {
-      const response = await fetch(`${apiUrl}/wallet/link`, {
+      const response = await apiFetch(`${apiUrl}/wallet/link`, {
         method: 'POST',
       });
 
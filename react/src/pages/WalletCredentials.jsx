@@ -1,5 +1,5 @@
 import { useState, useEffect } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 
 const WalletCredentials = () => {
   const [credentials, setCredentials] = useState(null);
@@ -9,7 +9,7 @@ const WalletCredentials = () => {
   useEffect(() => {
     const fetchCredentials = async () => {
       try {
-        const response = await fetch(`${apiUrl}/wallet/vcs`);
+        const response = await apiFetch(`${apiUrl}/wallet/vcs`);
         if (!response.ok) {
           throw new Error('Failed to fetch credentials');
         }
@@ -1,5 +1,5 @@
 import { useState, useEffect } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 
 const WalletDID = () => {
   const [didDocument, setDidDocument] = useState(null);
@@ -9,7 +9,7 @@ const WalletDID = () => {
   useEffect(() => {
     const fetchDID = async () => {
       try {
-        const response = await fetch(`${apiUrl}/wallet/did.json`);
+        const response = await apiFetch(`${apiUrl}/wallet/did.json`);
         if (!response.ok) {
           throw new Error('Failed to fetch DID document');
         }
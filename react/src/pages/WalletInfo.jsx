@@ -1,5 +1,5 @@
 import { useState, useEffect } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 import BooleanBadge from '../components/BooleanBadge';
 import {
   Table,
@@ -18,7 +18,7 @@ const WalletInfo = () => {
   useEffect(() => {
     const fetchWalletInfo = async () => {
       try {
-        const response = await fetch(`${apiUrl}/wallet/info`);
+        const response = await apiFetch(`${apiUrl}/wallet/info`);
         if (!response.ok) {
           throw new Error('Failed to fetch wallet info');
         }
@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import { VITE_API_SERVER_URL as apiUrl } from '@/lib/api';
+import { VITE_API_SERVER_URL as apiUrl, apiFetch } from '@/lib/api';
 import { Input } from '@/components/ui/input';
 import { Button } from '@/components/ui/button';
 import { Label } from '@/components/ui/label';
@@ -19,7 +19,7 @@ const WalletOidc4vci = () => {
     setError(null);
 
     try {
-      const res = await fetch(`${apiUrl}/wallet/oidc4vci`, {
+      const res = await apiFetch(`${apiUrl}/wallet/oidc4vci`, {
         method: 'POST',
         headers: {
           'Content-Type': 'application/json',
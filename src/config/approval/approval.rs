@@ -0,0 +1,97 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use ymir::errors::Errors;
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalOutcome {
+    Approve,
+    Reject,
+    #[default]
+    ManualReview
+}
+
+impl FromStr for ApprovalOutcome {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "approve" => Ok(Self::Approve),
+            "reject" => Ok(Self::Reject),
+            "manual_review" => Ok(Self::ManualReview),
+            _ => Err(Errors::parse(format!("Invalid approval outcome '{}'", s), None))
+        }
+    }
+}
+
+impl fmt::Display for ApprovalOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ApprovalOutcome::Approve => "approve",
+            ApprovalOutcome::Reject => "reject",
+            ApprovalOutcome::ManualReview => "manual_review"
+        };
+        write!(f, "{s}")
+    }
+}
+
+// UTC window; `from` after `to` spans midnight
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TimeWindow {
+    // Three letter weekdays (`Mon`, `Tue`...), any day when empty
+    #[serde(default)]
+    pub days: Vec<String>,
+    // `HH:MM`
+    pub from: String,
+    pub to: String
+}
+
+// Every condition that is set must hold for the rule to match
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ApprovalRule {
+    pub name: String,
+    pub outcome: ApprovalOutcome,
+    #[serde(default)]
+    pub vc_types: Vec<String>,
+    // Participant slugs, `*` matches any run of characters
+    #[serde(default)]
+    pub participants: Vec<String>,
+    // Certificate subject attributes by short name (`O`, `C`...) or dotted OID
+    #[serde(default)]
+    pub cert_subject: HashMap<String, String>,
+    // Whether the participant must (or must not) have been issued credentials before
+    pub known_participant: Option<bool>,
+    pub window: Option<TimeWindow>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ApprovalConfig {
+    #[serde(default)]
+    pub rules: Vec<ApprovalRule>,
+    // Outcome when no rule matches
+    #[serde(default)]
+    pub default: ApprovalOutcome,
+    // Distinct approvers needed per vc type, one when missing
+    #[serde(default)]
+    pub quorum: HashMap<String, usize>
+}
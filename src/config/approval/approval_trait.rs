@@ -0,0 +1,27 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::{ApprovalConfig, ApprovalOutcome, ApprovalRule};
+
+pub trait ApprovalConfigTrait {
+    fn approval_config(&self) -> &ApprovalConfig;
+    fn get_rules(&self) -> &[ApprovalRule] { &self.approval_config().rules }
+    fn get_default_outcome(&self) -> &ApprovalOutcome { &self.approval_config().default }
+    fn get_quorum(&self, vc_type: &str) -> usize {
+        self.approval_config().quorum.get(vc_type).copied().unwrap_or(1).max(1)
+    }
+}
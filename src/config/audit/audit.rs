@@ -0,0 +1,34 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+// Entries are signed with the authority key (`VAULT_APP_PRIV_KEY`)
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct AuditConfig {
+    #[serde(default = "AuditConfig::default_alg")]
+    pub alg: String,
+    pub kid: Option<String>
+}
+
+impl AuditConfig {
+    fn default_alg() -> String { "RS256".to_string() }
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self { Self { alg: Self::default_alg(), kid: None } }
+}
@@ -0,0 +1,129 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use ymir::errors::Errors;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminRole {
+    Viewer,
+    Approver,
+    Admin
+}
+
+impl FromStr for AdminRole {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "viewer" => Ok(Self::Viewer),
+            "approver" => Ok(Self::Approver),
+            "admin" => Ok(Self::Admin),
+            _ => Err(Errors::parse(format!("Invalid admin role '{}'", s), None))
+        }
+    }
+}
+
+impl fmt::Display for AdminRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            AdminRole::Viewer => "viewer",
+            AdminRole::Approver => "approver",
+            AdminRole::Admin => "admin"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ApiKey {
+    pub name: String,
+    pub key: String,
+    pub role: AdminRole
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OidcConfig {
+    pub issuer: String,
+    pub audience: String,
+    // Local JWKS file, handy for testing without an identity provider
+    pub jwks_file: Option<String>,
+    pub jwks_uri: Option<String>,
+    #[serde(default = "OidcConfig::default_roles_claim")]
+    pub roles_claim: String,
+    // Identity provider roles mapped to admin roles, names like "approver" map to themselves
+    #[serde(default)]
+    pub roles: HashMap<String, AdminRole>
+}
+
+impl OidcConfig {
+    fn default_roles_claim() -> String { "roles".to_string() }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WalletLoginConfig {
+    #[serde(default = "WalletLoginConfig::default_vc_type")]
+    pub vc_type: String,
+    // Trusted issuers of operator credentials, the authority's own wallet DID when empty
+    #[serde(default)]
+    pub issuers: Vec<String>,
+    // Seconds an admin session lasts
+    #[serde(default = "WalletLoginConfig::default_session_ttl")]
+    pub session_ttl: i64,
+    // Keeps sessions valid across restarts and replicas, random when unset
+    pub session_secret: Option<String>
+}
+
+impl WalletLoginConfig {
+    fn default_vc_type() -> String { "OperatorCredential".to_string() }
+    fn default_session_ttl() -> i64 { 28800 }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AuthConfig {
+    // On unless turned off explicitly, so a missing section never opens the admin api
+    #[serde(default = "AuthConfig::default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub api_keys: Vec<ApiKey>,
+    pub oidc: Option<OidcConfig>,
+    pub wallet_login: Option<WalletLoginConfig>,
+    // Empty allows no cross-origin callers
+    #[serde(default)]
+    pub cors_origins: Vec<String>
+}
+
+impl AuthConfig {
+    fn default_enabled() -> bool { true }
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            api_keys: vec![],
+            oidc: None,
+            wallet_login: None,
+            cors_origins: vec![]
+        }
+    }
+}
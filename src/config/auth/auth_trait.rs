@@ -0,0 +1,38 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use subtle::ConstantTimeEq;
+
+use super::{ApiKey, AuthConfig, OidcConfig, WalletLoginConfig};
+
+pub trait AuthConfigTrait {
+    fn auth_config(&self) -> &AuthConfig;
+    fn is_auth_enabled(&self) -> bool { self.auth_config().enabled }
+    fn get_api_key(&self, key: &str) -> Option<&ApiKey> {
+        // Every key is compared in constant time so timing reveals neither key nor match
+        self.auth_config().api_keys.iter().fold(None, |found, api_key| {
+            let matches: bool = api_key.key.as_bytes().ct_eq(key.as_bytes()).into();
+            found.or(matches.then_some(api_key))
+        })
+    }
+    fn get_oidc(&self) -> Option<&OidcConfig> { self.auth_config().oidc.as_ref() }
+    fn get_wallet_login(&self) -> Option<&WalletLoginConfig> {
+        self.auth_config().wallet_login.as_ref()
+    }
+    fn is_wallet_login_active(&self) -> bool { self.auth_config().wallet_login.is_some() }
+    fn get_cors_origins(&self) -> &[String] { &self.auth_config().cors_origins }
+}
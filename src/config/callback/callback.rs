@@ -0,0 +1,51 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CallbackConfig {
+    // Deliveries tried before a callback is dead-lettered
+    #[serde(default = "CallbackConfig::default_max_attempts")]
+    pub max_attempts: i32,
+    // Seconds before the first retry, doubled on every failure
+    #[serde(default = "CallbackConfig::default_base_delay")]
+    pub base_delay: i64,
+    #[serde(default = "CallbackConfig::default_max_delay")]
+    pub max_delay: i64,
+    // Seconds between outbox sweeps
+    #[serde(default = "CallbackConfig::default_interval")]
+    pub interval: u64
+}
+
+impl CallbackConfig {
+    fn default_max_attempts() -> i32 { 8 }
+    fn default_base_delay() -> i64 { 10 }
+    fn default_max_delay() -> i64 { 3600 }
+    fn default_interval() -> u64 { 5 }
+}
+
+impl Default for CallbackConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: Self::default_max_attempts(),
+            base_delay: Self::default_base_delay(),
+            max_delay: Self::default_max_delay(),
+            interval: Self::default_interval()
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::CallbackConfig;
+
+pub trait CallbackConfigTrait {
+    fn callback_config(&self) -> &CallbackConfig;
+    fn get_max_attempts(&self) -> i32 { self.callback_config().max_attempts.max(1) }
+    fn get_outbox_interval(&self) -> u64 { self.callback_config().interval.max(1) }
+    // Delay before retry number `attempts`
+    fn get_backoff(&self, attempts: i32) -> i64 {
+        let config = self.callback_config();
+        let factor = 2i64.saturating_pow(attempts.saturating_sub(1).clamp(0, 30) as u32);
+        config.base_delay.saturating_mul(factor).min(config.max_delay)
+    }
+}
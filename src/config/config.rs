@@ -32,7 +32,24 @@ use ymir::errors::{Errors, Outcome};
 use ymir::utils::read;
 
 use super::CoreConfigTrait;
+use crate::config::approval::{ApprovalConfig, ApprovalConfigTrait};
+use crate::config::audit::{AuditConfig, AuditConfigTrait};
+use crate::config::auth::{AuthConfig, AuthConfigTrait};
+use crate::config::callback::{CallbackConfig, CallbackConfigTrait};
+use crate::config::encryption::{EncryptionConfig, EncryptionConfigTrait};
+use crate::config::gaia::{GaiaConfig, GaiaConfigTrait};
+use crate::config::jar::{JarConfig, JarConfigTrait};
+use crate::config::metrics::{MetricsConfig, MetricsConfigTrait};
+use crate::config::presentation::{PresentationConfig, PresentationConfigTrait};
+use crate::config::proofing::{ProofingConfig, ProofingConfigTrait};
+use crate::config::rate_limit::{RateLimitConfig, RateLimitConfigTrait};
 use crate::config::role::{AuthorityRole, RoleConfigTrait};
+use crate::config::session::{SessionConfig, SessionConfigTrait};
+use crate::config::status::{StatusConfig, StatusConfigTrait};
+use crate::config::telemetry::{TelemetryConfig, TelemetryConfigTrait};
+use crate::config::tokens::{TokenConfig, TokenConfigTrait};
+use crate::config::trust::{TrustConfig, TrustConfigTrait};
+use crate::config::verification::{VerificationConfig, VerificationConfigTrait};
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct CoreApplicationConfig {
@@ -45,6 +62,36 @@ pub struct CoreApplicationConfig {
     issue_config: IssueConfig,
     vc_config: VcConfig,
     verify_req_config: VerifyReqConfig,
+    gaia_config: Option<GaiaConfig>,
+    #[serde(default)]
+    presentation_config: PresentationConfig,
+    #[serde(default)]
+    trust_config: TrustConfig,
+    #[serde(default)]
+    status_config: StatusConfig,
+    #[serde(default)]
+    session_config: SessionConfig,
+    #[serde(default)]
+    token_config: TokenConfig,
+    #[serde(default)]
+    proofing_config: ProofingConfig,
+    #[serde(default)]
+    approval_config: ApprovalConfig,
+    #[serde(default)]
+    callback_config: CallbackConfig,
+    #[serde(default)]
+    auth_config: AuthConfig,
+    #[serde(default)]
+    rate_limit_config: RateLimitConfig,
+    #[serde(default)]
+    audit_config: AuditConfig,
+    #[serde(default)]
+    metrics_config: MetricsConfig,
+    #[serde(default)]
+    telemetry_config: TelemetryConfig,
+    verification_config: Option<VerificationConfig>,
+    jar_config: Option<JarConfig>,
+    encryption_config: Option<EncryptionConfig>,
     role: AuthorityRole,
     is_react: bool
 }
@@ -98,6 +145,82 @@ impl WalletConfigTrait for CoreApplicationConfig {
     }
 }
 
+impl GaiaConfigTrait for CoreApplicationConfig {
+    fn gaia_config(&self) -> &GaiaConfig {
+        self.gaia_config.as_ref().expect("Module gaia is not active")
+    }
+}
+
+impl PresentationConfigTrait for CoreApplicationConfig {
+    fn presentation_config(&self) -> &PresentationConfig { &self.presentation_config }
+}
+
+impl TrustConfigTrait for CoreApplicationConfig {
+    fn trust_config(&self) -> &TrustConfig { &self.trust_config }
+}
+
+impl SessionConfigTrait for CoreApplicationConfig {
+    fn session_config(&self) -> &SessionConfig { &self.session_config }
+}
+
+impl TokenConfigTrait for CoreApplicationConfig {
+    fn token_config(&self) -> &TokenConfig { &self.token_config }
+}
+
+impl ProofingConfigTrait for CoreApplicationConfig {
+    fn proofing_config(&self) -> &ProofingConfig { &self.proofing_config }
+}
+
+impl ApprovalConfigTrait for CoreApplicationConfig {
+    fn approval_config(&self) -> &ApprovalConfig { &self.approval_config }
+}
+
+impl CallbackConfigTrait for CoreApplicationConfig {
+    fn callback_config(&self) -> &CallbackConfig { &self.callback_config }
+}
+
+impl AuthConfigTrait for CoreApplicationConfig {
+    fn auth_config(&self) -> &AuthConfig { &self.auth_config }
+}
+
+impl RateLimitConfigTrait for CoreApplicationConfig {
+    fn rate_limit_config(&self) -> &RateLimitConfig { &self.rate_limit_config }
+}
+
+impl AuditConfigTrait for CoreApplicationConfig {
+    fn audit_config(&self) -> &AuditConfig { &self.audit_config }
+}
+
+impl MetricsConfigTrait for CoreApplicationConfig {
+    fn metrics_config(&self) -> &MetricsConfig { &self.metrics_config }
+}
+
+impl TelemetryConfigTrait for CoreApplicationConfig {
+    fn telemetry_config(&self) -> &TelemetryConfig { &self.telemetry_config }
+}
+
+impl StatusConfigTrait for CoreApplicationConfig {
+    fn status_config(&self) -> &StatusConfig { &self.status_config }
+}
+
+impl VerificationConfigTrait for CoreApplicationConfig {
+    fn verification_config(&self) -> &VerificationConfig {
+        self.verification_config.as_ref().expect("Module verification is not active")
+    }
+}
+
+impl JarConfigTrait for CoreApplicationConfig {
+    fn jar_config(&self) -> &JarConfig {
+        self.jar_config.as_ref().expect("Module jar is not active")
+    }
+}
+
+impl EncryptionConfigTrait for CoreApplicationConfig {
+    fn encryption_config(&self) -> &EncryptionConfig {
+        self.encryption_config.as_ref().expect("Module encryption is not active")
+    }
+}
+
 impl RoleConfigTrait for CoreApplicationConfig {
     fn get_role(&self) -> &AuthorityRole { &self.role }
 }
@@ -106,4 +229,10 @@ impl CoreConfigTrait for CoreApplicationConfig {
     fn is_wallet_active(&self) -> bool { self.wallet_config.is_some() }
 
     fn is_react(&self) -> bool { self.is_react }
+
+    fn is_verification_active(&self) -> bool { self.verification_config.is_some() }
+
+    fn is_jar_active(&self) -> bool { self.jar_config.is_some() }
+
+    fn is_encryption_active(&self) -> bool { self.encryption_config.is_some() }
 }
@@ -20,7 +20,14 @@ use ymir::config::traits::{
     IssueConfigTrait, VcConfigTrait, VerifyReqConfigTrait, WalletConfigTrait
 };
 
+use crate::config::audit::AuditConfigTrait;
+use crate::config::auth::AuthConfigTrait;
+use crate::config::metrics::MetricsConfigTrait;
+use crate::config::proofing::ProofingConfigTrait;
+use crate::config::rate_limit::RateLimitConfigTrait;
 use crate::config::role::RoleConfigTrait;
+use crate::config::status::StatusConfigTrait;
+use crate::config::telemetry::TelemetryConfigTrait;
 
 pub trait CoreConfigTrait:
     HostsConfigTrait
@@ -33,10 +40,20 @@ pub trait CoreConfigTrait:
     + VerifyReqConfigTrait
     + WalletConfigTrait
     + RoleConfigTrait
+    + StatusConfigTrait
+    + AuthConfigTrait
+    + ProofingConfigTrait
+    + RateLimitConfigTrait
+    + AuditConfigTrait
+    + MetricsConfigTrait
+    + TelemetryConfigTrait
     + Send
     + Sync
     + 'static
 {
     fn is_wallet_active(&self) -> bool;
     fn is_react(&self) -> bool;
+    fn is_verification_active(&self) -> bool;
+    fn is_jar_active(&self) -> bool;
+    fn is_encryption_active(&self) -> bool;
 }
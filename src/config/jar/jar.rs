@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub enum ClientIdScheme {
+    #[serde(rename = "did")]
+    Did,
+    #[serde(rename = "x509_san_dns")]
+    X509SanDns
+}
+
+impl fmt::Display for ClientIdScheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ClientIdScheme::Did => "did",
+            ClientIdScheme::X509SanDns => "x509_san_dns"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct JarConfig {
+    pub client_id_scheme: ClientIdScheme,
+    pub client_id: String,
+    pub kid: Option<String>,
+    #[serde(default = "JarConfig::default_alg")]
+    pub alg: String,
+    #[serde(default = "JarConfig::default_ttl")]
+    pub ttl: i64
+}
+
+impl JarConfig {
+    fn default_alg() -> String { "RS256".to_string() }
+    fn default_ttl() -> i64 { 300 }
+}
@@ -0,0 +1,36 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+// Served outside the api path, scrapers are expected to reach it from the internal network
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MetricsConfig {
+    #[serde(default = "MetricsConfig::default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "MetricsConfig::default_path")]
+    pub path: String
+}
+
+impl MetricsConfig {
+    fn default_enabled() -> bool { true }
+    fn default_path() -> String { "/metrics".to_string() }
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self { Self { enabled: Self::default_enabled(), path: Self::default_path() } }
+}
@@ -0,0 +1,24 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::MetricsConfig;
+
+pub trait MetricsConfigTrait {
+    fn metrics_config(&self) -> &MetricsConfig;
+    fn is_metrics_enabled(&self) -> bool { self.metrics_config().enabled }
+    fn get_metrics_path(&self) -> &str { &self.metrics_config().path }
+}
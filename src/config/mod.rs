@@ -15,10 +15,27 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod approval;
+pub mod audit;
+pub mod auth;
+pub mod callback;
 mod config;
 mod config_trait;
+pub mod encryption;
+pub mod gaia;
+pub mod jar;
+pub mod metrics;
 mod parse_from;
+pub mod presentation;
+pub mod proofing;
+pub mod rate_limit;
 pub mod role;
+pub mod session;
+pub mod status;
+pub mod telemetry;
+pub mod tokens;
+pub mod trust;
+pub mod verification;
 
 pub use config::CoreApplicationConfig;
 pub use config_trait::CoreConfigTrait;
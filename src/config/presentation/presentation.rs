@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct PresentationConfig {
+    #[serde(default)]
+    pub use_cases: Vec<PresentationUseCase>,
+    // Requests for configured use cases carry `dcql_query` instead of a presentation definition
+    #[serde(default)]
+    pub dcql: bool
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct PresentationUseCase {
+    pub id: String,
+    #[serde(default)]
+    pub vc_types: Vec<String>,
+    #[serde(default)]
+    pub default: bool,
+    pub credentials: Vec<CredentialRequirement>
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CredentialRequirement {
+    pub id: String,
+    pub vc_type: String,
+    #[serde(default = "CredentialRequirement::default_format")]
+    pub format: String,
+    #[serde(default)]
+    pub issuers: Vec<String>,
+    #[serde(default)]
+    pub claims: Vec<ClaimRequirement>
+}
+
+impl CredentialRequirement {
+    fn default_format() -> String { "jwt_vc_json".to_string() }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ClaimRequirement {
+    pub path: Vec<String>,
+    #[serde(default)]
+    pub values: Vec<Value>
+}
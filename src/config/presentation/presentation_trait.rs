@@ -0,0 +1,30 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::{PresentationConfig, PresentationUseCase};
+
+pub trait PresentationConfigTrait {
+    fn presentation_config(&self) -> &PresentationConfig;
+    fn get_use_case(&self, vc_type: &str) -> Option<&PresentationUseCase> {
+        let use_cases = &self.presentation_config().use_cases;
+        use_cases
+            .iter()
+            .find(|use_case| use_case.vc_types.iter().any(|t| t == vc_type))
+            .or_else(|| use_cases.iter().find(|use_case| use_case.default))
+    }
+    fn is_dcql_enabled(&self) -> bool { self.presentation_config().dcql }
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct ProofingConfig {
+    // Reject grant requests whose client key has no supported proofing method
+    #[serde(default)]
+    pub required: bool,
+    // Header where the TLS terminating proxy forwards the client certificate
+    #[serde(default = "ProofingConfig::default_mtls_header")]
+    pub mtls_header: String,
+    // Proxies allowed to send that header, it is dropped from every other peer
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
+    // Allowed clock skew, in seconds, for the `created` signature parameter
+    #[serde(default = "ProofingConfig::default_max_skew")]
+    pub max_skew: i64
+}
+
+impl ProofingConfig {
+    fn default_mtls_header() -> String { "x-client-cert".to_string() }
+    fn default_max_skew() -> i64 { 300 }
+}
+
+impl Default for ProofingConfig {
+    fn default() -> Self {
+        Self {
+            required: false,
+            mtls_header: Self::default_mtls_header(),
+            trusted_proxies: Vec::new(),
+            max_skew: Self::default_max_skew()
+        }
+    }
+}
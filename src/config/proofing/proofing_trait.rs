@@ -0,0 +1,30 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::net::IpAddr;
+
+use super::ProofingConfig;
+
+pub trait ProofingConfigTrait {
+    fn proofing_config(&self) -> &ProofingConfig;
+    fn is_proofing_required(&self) -> bool { self.proofing_config().required }
+    fn get_mtls_header(&self) -> &str { &self.proofing_config().mtls_header }
+    fn is_trusted_proxy(&self, ip: IpAddr) -> bool {
+        self.proofing_config().trusted_proxies.contains(&ip)
+    }
+    fn get_max_skew(&self) -> i64 { self.proofing_config().max_skew }
+}
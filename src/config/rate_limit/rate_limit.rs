@@ -0,0 +1,95 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+pub struct RateQuota {
+    // Requests allowed at once
+    pub burst: u32,
+    // Requests refilled every minute
+    pub per_minute: u32
+}
+
+impl RateQuota {
+    pub fn new(burst: u32, per_minute: u32) -> Self { Self { burst, per_minute } }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RateBucket {
+    // Path prefix below the api path, e.g. "/gate/access"
+    pub path: String,
+    pub per_ip: Option<RateQuota>,
+    // Keyed by the GNAP client key, the OAuth client or pre-authorized code, or the last path
+    // segment when the body carries neither
+    pub per_client: Option<RateQuota>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RateLimitConfig {
+    #[serde(default = "RateLimitConfig::default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "RateLimitConfig::default_buckets")]
+    pub buckets: Vec<RateBucket>,
+    // Pending requests a participant may have before new grant requests are refused
+    #[serde(default = "RateLimitConfig::default_max_pending")]
+    pub max_pending: Option<u64>,
+    // Seconds a participant over `max_pending` is told to wait
+    #[serde(default = "RateLimitConfig::default_pending_retry_after")]
+    pub pending_retry_after: u64,
+    // Reverse proxies in front of us, each appending to X-Forwarded-For; the client is the
+    // entry this many hops from the right. Zero ignores the header, clients could spoof it
+    #[serde(default)]
+    pub proxy_hops: usize
+}
+
+impl RateLimitConfig {
+    fn default_enabled() -> bool { true }
+    fn default_buckets() -> Vec<RateBucket> {
+        vec![
+            RateBucket {
+                path: "/gate/access".to_string(),
+                per_ip: Some(RateQuota::new(20, 60)),
+                per_client: Some(RateQuota::new(5, 10))
+            },
+            RateBucket {
+                path: "/issuer/token".to_string(),
+                per_ip: Some(RateQuota::new(30, 120)),
+                per_client: Some(RateQuota::new(5, 20))
+            },
+            RateBucket {
+                path: "/verifier/verify".to_string(),
+                per_ip: Some(RateQuota::new(30, 120)),
+                per_client: Some(RateQuota::new(5, 20))
+            },
+        ]
+    }
+    fn default_max_pending() -> Option<u64> { Some(20) }
+    fn default_pending_retry_after() -> u64 { 60 }
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            buckets: Self::default_buckets(),
+            max_pending: Self::default_max_pending(),
+            pending_retry_after: Self::default_pending_retry_after(),
+            proxy_hops: 0
+        }
+    }
+}
@@ -0,0 +1,29 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::{RateBucket, RateLimitConfig};
+
+pub trait RateLimitConfigTrait {
+    fn rate_limit_config(&self) -> &RateLimitConfig;
+    fn is_rate_limit_enabled(&self) -> bool { self.rate_limit_config().enabled }
+    fn get_rate_bucket(&self, path: &str) -> Option<&RateBucket> {
+        self.rate_limit_config().buckets.iter().find(|bucket| path.starts_with(&bucket.path))
+    }
+    fn get_max_pending(&self) -> Option<u64> { self.rate_limit_config().max_pending }
+    fn get_pending_retry_after(&self) -> u64 { self.rate_limit_config().pending_retry_after }
+    fn get_proxy_hops(&self) -> usize { self.rate_limit_config().proxy_hops }
+}
@@ -30,7 +30,8 @@ pub enum AuthorityRole {
     ClearingHouse,
     ClearingHouseProxy,
     DataSpaceAuthority,
-    EcoAuthority
+    EcoAuthority,
+    GaiaAuthority
 }
 
 impl FromStr for AuthorityRole {
@@ -43,6 +44,8 @@ impl FromStr for AuthorityRole {
             "DataSpaceAuthority" => Ok(Self::DataSpaceAuthority),
             "DataspaceAuthority" => Ok(Self::DataSpaceAuthority),
             "EcoAuthority" => Ok(Self::EcoAuthority),
+            "GaiaAuthority" => Ok(Self::GaiaAuthority),
+            "GaiaXAuthority" => Ok(Self::GaiaAuthority),
             _ => Err(Errors::parse("Invalid Authority Role", None))
         }
     }
@@ -55,7 +58,8 @@ impl fmt::Display for AuthorityRole {
             AuthorityRole::ClearingHouse => "ClearingHouse",
             AuthorityRole::ClearingHouseProxy => "ClearingHouseProxy",
             AuthorityRole::DataSpaceAuthority => "DataSpaceAuthority",
-            AuthorityRole::EcoAuthority => "EcoAuthority"
+            AuthorityRole::EcoAuthority => "EcoAuthority",
+            AuthorityRole::GaiaAuthority => "GaiaAuthority"
         };
 
         write!(f, "{s}")
@@ -83,6 +87,15 @@ impl AuthorityRole {
                     VcType::DataspaceParticipant,
                 ]
             }
+            AuthorityRole::GaiaAuthority => {
+                vec![
+                    VcType::LegalRegistrationNumber(LegalRegistrationNumberTypes::VatId),
+                    VcType::LegalRegistrationNumber(LegalRegistrationNumberTypes::LeiCode),
+                    VcType::LegalRegistrationNumber(LegalRegistrationNumberTypes::Eori),
+                    VcType::LegalRegistrationNumber(LegalRegistrationNumberTypes::Euid),
+                    VcType::LegalRegistrationNumber(LegalRegistrationNumberTypes::TaxId),
+                ]
+            }
         }
     }
 }
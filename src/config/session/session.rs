@@ -0,0 +1,48 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SessionConfig {
+    #[serde(default = "SessionConfig::default_interaction_ttl")]
+    pub interaction_ttl: i64,
+    #[serde(default = "SessionConfig::default_verification_ttl")]
+    pub verification_ttl: i64,
+    #[serde(default = "SessionConfig::default_issuance_ttl")]
+    pub issuance_ttl: i64,
+    #[serde(default = "SessionConfig::default_cleanup_interval")]
+    pub cleanup_interval: u64
+}
+
+impl SessionConfig {
+    fn default_interaction_ttl() -> i64 { 86400 }
+    fn default_verification_ttl() -> i64 { 600 }
+    fn default_issuance_ttl() -> i64 { 3600 }
+    fn default_cleanup_interval() -> u64 { 60 }
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            interaction_ttl: Self::default_interaction_ttl(),
+            verification_ttl: Self::default_verification_ttl(),
+            issuance_ttl: Self::default_issuance_ttl(),
+            cleanup_interval: Self::default_cleanup_interval()
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct StatusConfig {
+    #[serde(default = "StatusConfig::default_ttl")]
+    pub default_ttl: i64,
+    pub local_dir: Option<String>,
+    // Hosts status lists and issuer keys may be fetched from, any public https host if empty
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>
+}
+
+impl StatusConfig {
+    fn default_ttl() -> i64 { 300 }
+}
+
+impl Default for StatusConfig {
+    fn default() -> Self {
+        Self { default_ttl: Self::default_ttl(), local_dir: None, allowed_hosts: Vec::new() }
+    }
+}
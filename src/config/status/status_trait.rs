@@ -0,0 +1,25 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::StatusConfig;
+
+pub trait StatusConfigTrait {
+    fn status_config(&self) -> &StatusConfig;
+    fn get_default_ttl(&self) -> i64 { self.status_config().default_ttl }
+    fn get_local_status_dir(&self) -> Option<&str> { self.status_config().local_dir.as_deref() }
+    fn get_status_allowed_hosts(&self) -> &[String] { &self.status_config().allowed_hosts }
+}
@@ -0,0 +1,49 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+// Spans are exported as OTLP over HTTP (protobuf), `endpoint` is the full traces url
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct TelemetryConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "TelemetryConfig::default_endpoint")]
+    pub endpoint: String,
+    #[serde(default = "TelemetryConfig::default_service_name")]
+    pub service_name: String,
+    // Share of new traces kept, requests carrying a `traceparent` follow the caller's choice
+    #[serde(default = "TelemetryConfig::default_sample_ratio")]
+    pub sample_ratio: f64
+}
+
+impl TelemetryConfig {
+    fn default_endpoint() -> String { "http://localhost:4318/v1/traces".to_string() }
+    fn default_service_name() -> String { "heimdall".to_string() }
+    fn default_sample_ratio() -> f64 { 1.0 }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: Self::default_endpoint(),
+            service_name: Self::default_service_name(),
+            sample_ratio: Self::default_sample_ratio()
+        }
+    }
+}
@@ -0,0 +1,26 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::TelemetryConfig;
+
+pub trait TelemetryConfigTrait {
+    fn telemetry_config(&self) -> &TelemetryConfig;
+    fn is_telemetry_enabled(&self) -> bool { self.telemetry_config().enabled }
+    fn get_otlp_endpoint(&self) -> &str { &self.telemetry_config().endpoint }
+    fn get_service_name(&self) -> &str { &self.telemetry_config().service_name }
+    fn get_sample_ratio(&self) -> f64 { self.telemetry_config().sample_ratio.clamp(0.0, 1.0) }
+}
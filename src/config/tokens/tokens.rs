@@ -0,0 +1,45 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct TokenConfig {
+    #[serde(default = "TokenConfig::default_ttl")]
+    pub ttl: i64,
+    // Seconds clients are asked to wait between continuation polls
+    #[serde(default = "TokenConfig::default_continue_wait")]
+    pub continue_wait: u64,
+    // Bearer keys allowed to register resource servers
+    #[serde(default)]
+    pub registration_keys: Vec<String>
+}
+
+impl TokenConfig {
+    fn default_ttl() -> i64 { 3600 }
+    fn default_continue_wait() -> u64 { 5 }
+}
+
+impl Default for TokenConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Self::default_ttl(),
+            continue_wait: Self::default_continue_wait(),
+            registration_keys: Vec::new()
+        }
+    }
+}
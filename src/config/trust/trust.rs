@@ -0,0 +1,41 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct TrustConfig {
+    #[serde(default)]
+    pub policies: Vec<TrustPolicy>,
+    // Credentials no policy applies to are rejected unless this is set or no policy exists
+    #[serde(default)]
+    pub allow_unmatched: bool
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct TrustPolicy {
+    pub vc_type: String,
+    #[serde(default)]
+    pub trusted_issuers: Vec<String>,
+    pub max_age: Option<i64>,
+    #[serde(default)]
+    pub require_holder_binding: bool
+}
+
+impl TrustPolicy {
+    pub fn applies_to_all(&self) -> bool { self.vc_type == "*" }
+}
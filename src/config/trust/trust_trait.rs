@@ -0,0 +1,27 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use super::{TrustConfig, TrustPolicy};
+
+pub trait TrustConfigTrait {
+    fn trust_config(&self) -> &TrustConfig;
+    fn get_trust_policies(&self) -> &[TrustPolicy] { &self.trust_config().policies }
+    fn allows_unmatched(&self) -> bool {
+        // Without policies there is nothing to enforce, so deployments without the section work
+        self.trust_config().allow_unmatched || self.trust_config().policies.is_empty()
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use subtle::{Choice, ConstantTimeEq};
+
+use super::VerificationConfig;
+
+pub trait VerificationConfigTrait {
+    fn verification_config(&self) -> &VerificationConfig;
+    fn is_api_key_valid(&self, key: &str) -> bool {
+        // Every key is compared in constant time so timing reveals neither key nor match
+        let keys = &self.verification_config().api_keys;
+        let found = keys.iter().fold(Choice::from(0), |found, k| {
+            found | k.as_bytes().ct_eq(key.as_bytes())
+        });
+        found.into()
+    }
+}
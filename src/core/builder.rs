@@ -27,20 +27,49 @@ use ymir::services::wallet::walt_id::config::WaltIdConfig;
 use ymir::services::wallet::walt_id::WaltIdService;
 use ymir::services::wallet::WalletTrait;
 
+use crate::config::approval::ApprovalConfigTrait;
+use crate::config::audit::AuditConfigTrait;
+use crate::config::auth::AuthConfigTrait;
+use crate::config::callback::CallbackConfigTrait;
+use crate::config::encryption::EncryptionConfigTrait;
+use crate::config::gaia::GaiaConfigTrait;
+use crate::config::presentation::PresentationConfigTrait;
 use crate::config::role::{AuthorityRole, RoleConfigTrait};
+use crate::config::session::SessionConfigTrait;
+use crate::config::status::StatusConfigTrait;
+use crate::config::trust::TrustConfigTrait;
+use crate::config::verification::VerificationConfigTrait;
 use crate::config::{CoreApplicationConfig, CoreConfigTrait};
 use crate::core::Core;
+use crate::services::approval::{ApprovalService, ApprovalTrait};
+use crate::services::audit::{AuditService, AuditTrait};
+use crate::services::auth::{AuthService, AuthTrait};
+use crate::services::encryption::{ResponseEncryptionService, ResponseEncryptionTrait};
+use crate::services::expiry::{ExpiryService, ExpiryTrait};
+use crate::services::gaia::{GaiaService, GaiaTrait};
 use crate::services::gatekeeper::gnap::{config::GnapConfig, GnapService};
+use crate::services::jar::{JarService, JarServiceConfig, JarTrait};
+use crate::services::metrics::{MetricsService, MetricsTrait};
 use crate::services::notifications::{NotificationService, NotificationsTrait};
+use crate::services::outbox::{OutboxService, OutboxTrait};
+use crate::services::presentation::{PresentationService, PresentationTrait};
+use crate::services::proofing::{ProofService, ProofServiceConfig, ProofTrait};
 use crate::services::repo::RepoForSql;
 use crate::services::repo::RepoTrait;
+use crate::services::status::{StatusService, StatusTrait};
+use crate::services::tokens::{TokenService, TokenServiceConfig, TokenTrait};
+use crate::services::trust::{TrustService, TrustTrait};
 use crate::services::vcs_builder::dataspace_authority::{
     config::DataSpaceAuthorityConfig, DataSpaceAuthorityVcBuilder
 };
+use crate::services::vcs_builder::gaia_authority::{
+    GaiaAuthorityConfig, GaiaAuthorityVcBuilder
+};
 use crate::services::vcs_builder::legal_authority::{
     LegalAuthorityConfig, LegalAuthorityVcBuilder
 };
 use crate::services::vcs_builder::{EcoAuthorityBuilder, VcBuilderTrait};
+use crate::services::verification::{VerificationService, VerificationTrait};
 
 pub struct CoreBuilder {
     core: Core
@@ -78,6 +107,10 @@ impl CoreBuilder {
 
                 Arc::new(EcoAuthorityBuilder::new(legal, dp))
             }
+            AuthorityRole::GaiaAuthority => {
+                let config = GaiaAuthorityConfig::from(config.clone());
+                Arc::new(GaiaAuthorityVcBuilder::new(config))
+            }
         };
 
         // ===== CONFIG DERIVATIONS =====
@@ -92,16 +125,38 @@ impl CoreBuilder {
         let db_connection = vault.get_db_connection(&config).await;
         let repo: Arc<dyn RepoTrait> = Arc::new(RepoForSql::new(db_connection));
 
+        // Calls made inside ymir with this client, wallet ones included, are not traced
         let client = Arc::new(ClientService::default());
 
         let gatekeeper = Arc::new(GnapService::new(gnap_config, client.clone()));
         let issuer =
             Arc::new(BasicIssuerService::new(issuer_config, client.clone(), vault.clone()));
         let verifier = Arc::new(BasicVerifierService::new(client.clone(), verifier_config));
+        let presentation: Arc<dyn PresentationTrait> =
+            Arc::new(PresentationService::new(config.presentation_config().clone()));
+        let trust: Arc<dyn TrustTrait> =
+            Arc::new(TrustService::new(config.trust_config().clone()));
+        let status: Arc<dyn StatusTrait> =
+            Arc::new(StatusService::new(config.status_config().clone(), client.clone()));
+        let expiry: Arc<dyn ExpiryTrait> =
+            Arc::new(ExpiryService::new(config.session_config().clone()));
+        let tokens: Arc<dyn TokenTrait> =
+            Arc::new(TokenService::new(TokenServiceConfig::from(config.clone())));
+        let proofing: Arc<dyn ProofTrait> =
+            Arc::new(ProofService::new(ProofServiceConfig::from(config.clone())));
+        let approval: Arc<dyn ApprovalTrait> =
+            Arc::new(ApprovalService::new(config.approval_config().clone()));
+        let outbox: Arc<dyn OutboxTrait> =
+            Arc::new(OutboxService::new(config.callback_config().clone(), client.clone()));
+        let auth: Arc<dyn AuthTrait> =
+            Arc::new(AuthService::new(config.auth_config().clone(), client.clone()));
+        let audit: Arc<dyn AuditTrait> =
+            Arc::new(AuditService::new(config.audit_config().clone(), vault.clone()));
+        let metrics: Arc<dyn MetricsTrait> = Arc::new(MetricsService::default());
 
         let wallet: Option<Arc<dyn WalletTrait>> = if config.is_wallet_active() {
             let walt_config = WaltIdConfig::from(config.clone());
-            Some(Arc::new(WaltIdService::new(walt_config, client.clone(), vault)))
+            Some(Arc::new(WaltIdService::new(walt_config, client.clone(), vault.clone())))
         } else {
             None
         };
@@ -109,12 +164,58 @@ impl CoreBuilder {
         let notifier: Option<Arc<dyn NotificationsTrait>> =
             if config.is_react() { Some(Arc::new(NotificationService::new())) } else { None };
 
+        let gaia: Option<Arc<dyn GaiaTrait>> = match role {
+            AuthorityRole::GaiaAuthority => {
+                let terms = config.get_terms_and_conditions().to_string();
+                Some(Arc::new(GaiaService::new(terms)))
+            }
+            _ => None
+        };
+
+        let verification: Option<Arc<dyn VerificationTrait>> = if config.is_verification_active() {
+            let verification_config = config.verification_config().clone();
+            Some(Arc::new(VerificationService::new(verification_config)))
+        } else {
+            None
+        };
+
+        let jar: Option<Arc<dyn JarTrait>> = if config.is_jar_active() {
+            let jar_config = JarServiceConfig::from(config.clone());
+            Some(Arc::new(JarService::new(jar_config, vault.clone())))
+        } else {
+            None
+        };
+
+        let encryption: Option<Arc<dyn ResponseEncryptionTrait>> =
+            match config.is_encryption_active() {
+                true => {
+                    let encryption_config = config.encryption_config().clone();
+                    Some(Arc::new(ResponseEncryptionService::new(encryption_config)))
+                }
+                false => None
+            };
+
         let core = Core::new(
             wallet,
             notifier,
+            gaia,
+            verification,
+            jar,
+            encryption,
             gatekeeper,
             issuer,
             verifier,
+            presentation,
+            trust,
+            status,
+            expiry,
+            tokens,
+            proofing,
+            approval,
+            outbox,
+            auth,
+            audit,
+            metrics,
             vc_builder,
             repo,
             core_config
@@ -25,20 +25,51 @@ use ymir::services::wallet::WalletTrait;
 
 use crate::config::CoreConfigTrait;
 use crate::core::traits::{
-    CoreApproverTrait, CoreGatekeeperTrait, CoreIssuerTrait, CoreMinionTrait, CoreReactTrait,
-    CoreTrait, CoreVerifierTrait
+    CoreApproverTrait, CoreAuditTrait, CoreExpiryTrait, CoreGaiaTrait, CoreGatekeeperTrait,
+    CoreIssuerTrait, CoreLoginTrait, CoreMetricsTrait, CoreMinionTrait, CoreOutboxTrait,
+    CoreReactTrait, CoreTrait, CoreVerificationTrait, CoreVerifierTrait
 };
+use crate::services::approval::ApprovalTrait;
+use crate::services::audit::AuditTrait;
+use crate::services::auth::AuthTrait;
+use crate::services::encryption::ResponseEncryptionTrait;
+use crate::services::expiry::ExpiryTrait;
+use crate::services::gaia::GaiaTrait;
 use crate::services::gatekeeper::GateKeeperTrait;
+use crate::services::jar::JarTrait;
+use crate::services::metrics::MetricsTrait;
 use crate::services::notifications::NotificationsTrait;
+use crate::services::outbox::OutboxTrait;
+use crate::services::presentation::PresentationTrait;
+use crate::services::proofing::ProofTrait;
 use crate::services::repo::RepoTrait;
+use crate::services::status::StatusTrait;
+use crate::services::tokens::TokenTrait;
+use crate::services::trust::TrustTrait;
 use crate::services::vcs_builder::VcBuilderTrait;
+use crate::services::verification::VerificationTrait;
 
 pub struct Core {
     wallet: Option<Arc<dyn WalletTrait>>,
     notifier: Option<Arc<dyn NotificationsTrait>>,
+    gaia: Option<Arc<dyn GaiaTrait>>,
+    verification: Option<Arc<dyn VerificationTrait>>,
+    jar: Option<Arc<dyn JarTrait>>,
+    encryption: Option<Arc<dyn ResponseEncryptionTrait>>,
     gatekeeper: Arc<dyn GateKeeperTrait>,
     issuer: Arc<dyn IssuerTrait>,
     verifier: Arc<dyn VerifierTrait>,
+    presentation: Arc<dyn PresentationTrait>,
+    trust: Arc<dyn TrustTrait>,
+    status: Arc<dyn StatusTrait>,
+    expiry: Arc<dyn ExpiryTrait>,
+    tokens: Arc<dyn TokenTrait>,
+    proofing: Arc<dyn ProofTrait>,
+    approval: Arc<dyn ApprovalTrait>,
+    outbox: Arc<dyn OutboxTrait>,
+    auth: Arc<dyn AuthTrait>,
+    audit: Arc<dyn AuditTrait>,
+    metrics: Arc<dyn MetricsTrait>,
     vc_builder: Arc<dyn VcBuilderTrait>,
     repo: Arc<dyn RepoTrait>,
     config: Arc<dyn CoreConfigTrait>
@@ -48,14 +79,53 @@ impl Core {
     pub fn new(
         wallet: Option<Arc<dyn WalletTrait>>,
         notifier: Option<Arc<dyn NotificationsTrait>>,
+        gaia: Option<Arc<dyn GaiaTrait>>,
+        verification: Option<Arc<dyn VerificationTrait>>,
+        jar: Option<Arc<dyn JarTrait>>,
+        encryption: Option<Arc<dyn ResponseEncryptionTrait>>,
         gatekeeper: Arc<dyn GateKeeperTrait>,
         issuer: Arc<dyn IssuerTrait>,
         verifier: Arc<dyn VerifierTrait>,
+        presentation: Arc<dyn PresentationTrait>,
+        trust: Arc<dyn TrustTrait>,
+        status: Arc<dyn StatusTrait>,
+        expiry: Arc<dyn ExpiryTrait>,
+        tokens: Arc<dyn TokenTrait>,
+        proofing: Arc<dyn ProofTrait>,
+        approval: Arc<dyn ApprovalTrait>,
+        outbox: Arc<dyn OutboxTrait>,
+        auth: Arc<dyn AuthTrait>,
+        audit: Arc<dyn AuditTrait>,
+        metrics: Arc<dyn MetricsTrait>,
         vc_builder: Arc<dyn VcBuilderTrait>,
         repo: Arc<dyn RepoTrait>,
         config: Arc<dyn CoreConfigTrait>
     ) -> Self {
-        Self { wallet, gatekeeper, issuer, verifier, vc_builder, repo, config, notifier }
+        Self {
+            wallet,
+            gatekeeper,
+            issuer,
+            verifier,
+            presentation,
+            trust,
+            status,
+            expiry,
+            tokens,
+            proofing,
+            approval,
+            outbox,
+            auth,
+            audit,
+            metrics,
+            vc_builder,
+            repo,
+            config,
+            notifier,
+            gaia,
+            verification,
+            jar,
+            encryption
+        }
     }
 }
 
@@ -76,35 +146,84 @@ impl CoreMinionTrait for Core {
 impl CoreVerifierTrait for Core {
     fn verifier(&self) -> Arc<dyn VerifierTrait> { self.verifier.clone() }
 
+    fn presentation(&self) -> Arc<dyn PresentationTrait> { self.presentation.clone() }
+
+    fn trust(&self) -> Arc<dyn TrustTrait> { self.trust.clone() }
+
+    fn status(&self) -> Arc<dyn StatusTrait> { self.status.clone() }
+
+    fn jar(&self) -> Option<Arc<dyn JarTrait>> { self.jar.clone() }
+
+    fn encryption(&self) -> Option<Arc<dyn ResponseEncryptionTrait>> { self.encryption.clone() }
+
+    fn expiry(&self) -> Arc<dyn ExpiryTrait> { self.expiry.clone() }
+
     fn repo(&self) -> Arc<dyn RepoTrait> { self.repo.clone() }
+
+    fn metrics(&self) -> Arc<dyn MetricsTrait> { self.metrics.clone() }
 }
 
 impl CoreIssuerTrait for Core {
     fn issuer(&self) -> Arc<dyn IssuerTrait> { self.issuer.clone() }
-    fn repo(&self) -> Arc<dyn RepoTrait> { self.repo.clone() }
     fn vc_builder(&self) -> Arc<dyn VcBuilderTrait> { self.vc_builder.clone() }
 
     fn wallet(&self) -> Option<Arc<dyn WalletTrait>> { self.wallet.clone() }
 }
 
+impl CoreAuditTrait for Core {
+    fn audit(&self) -> Arc<dyn AuditTrait> { self.audit.clone() }
+}
+
+impl CoreOutboxTrait for Core {
+    fn outbox(&self) -> Arc<dyn OutboxTrait> { self.outbox.clone() }
+}
+
 impl CoreApproverTrait for Core {
     fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait> { self.gatekeeper.clone() }
 
-    fn repo(&self) -> Arc<dyn RepoTrait> { self.repo.clone() }
+    fn approval(&self) -> Arc<dyn ApprovalTrait> { self.approval.clone() }
 }
 
 impl CoreGatekeeperTrait for Core {
     fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait> { self.gatekeeper.clone() }
 
-    fn verifier(&self) -> Arc<dyn VerifierTrait> { self.verifier.clone() }
-
     fn issuer(&self) -> Arc<dyn IssuerTrait> { self.issuer.clone() }
 
-    fn repo(&self) -> Arc<dyn RepoTrait> { self.repo.clone() }
-
     fn vc_builder(&self) -> Arc<dyn VcBuilderTrait> { self.vc_builder.clone() }
 
     fn notifier(&self) -> Option<Arc<dyn NotificationsTrait>> { self.notifier.as_ref().cloned() }
+
+    fn tokens(&self) -> Arc<dyn TokenTrait> { self.tokens.clone() }
+
+    fn proofing(&self) -> Arc<dyn ProofTrait> { self.proofing.clone() }
+
+    fn approval(&self) -> Arc<dyn ApprovalTrait> { self.approval.clone() }
+}
+
+impl CoreGaiaTrait for Core {
+    fn gaia(&self) -> Arc<dyn GaiaTrait> {
+        self.gaia.as_ref().cloned().expect("Gaia module is required for this operation but is not active in the current configuration")
+    }
+
+    fn issuer(&self) -> Arc<dyn IssuerTrait> { self.issuer.clone() }
+}
+
+impl CoreVerificationTrait for Core {
+    fn verification(&self) -> Arc<dyn VerificationTrait> {
+        self.verification.as_ref().cloned().expect("Verification module is required for this operation but is not active in the current configuration")
+    }
+}
+
+impl CoreExpiryTrait for Core {}
+
+impl CoreMetricsTrait for Core {}
+
+impl CoreLoginTrait for Core {
+    fn auth(&self) -> Arc<dyn AuthTrait> { self.auth.clone() }
+
+    fn issuer(&self) -> Arc<dyn IssuerTrait> { self.issuer.clone() }
+
+    fn wallet(&self) -> Option<Arc<dyn WalletTrait>> { self.wallet.clone() }
 }
 
 impl CoreWalletTrait for Core {
@@ -18,29 +18,138 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde_json::json;
 use ymir::data::entities::vc_request;
-use ymir::errors::Outcome;
-use ymir::types::vcs::vc_decision_approval::VcDecisionApproval;
+use ymir::errors::{BadFormat, Errors, Outcome};
 
+use super::{CoreAuditTrait, CoreOutboxTrait};
+use crate::data::entities::{approval_decision, approval_vote, request_event, verification_trust};
+use crate::services::approval::types::{ApprovalTally, ApprovalVote, RequestEvent};
+use crate::services::approval::ApprovalTrait;
+use crate::services::audit::types::{AuditAction, AuditEvent};
 use crate::services::gatekeeper::GateKeeperTrait;
-use crate::services::repo::RepoTrait;
+use crate::services::metrics::types::ApprovalSource;
+use crate::services::repo::types::{Page, RequestQuery};
 
 #[async_trait]
-pub trait CoreApproverTrait: Send + Sync + 'static {
+pub trait CoreApproverTrait: CoreOutboxTrait + CoreAuditTrait {
     fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait>;
-    fn repo(&self) -> Arc<dyn RepoTrait>;
-    async fn get_all(&self) -> Outcome<Vec<vc_request::Model>> {
-        self.repo().request().get_all(None, None).await
+    fn approval(&self) -> Arc<dyn ApprovalTrait>;
+    async fn get_all(&self, query: RequestQuery) -> Outcome<Page<vc_request::Model>> {
+        self.repo().listings().requests(&query).await
     }
     async fn get_by_id(&self, id: String) -> Outcome<vc_request::Model> {
         self.repo().request().get_by_id(&id).await
     }
-    async fn manage_req(&self, id: String, payload: VcDecisionApproval) -> Outcome<()> {
+    async fn get_trust(&self, id: String) -> Outcome<verification_trust::Model> {
+        self.repo().trust().get_by_id(&id).await
+    }
+    async fn get_decision(&self, id: String) -> Outcome<approval_decision::Model> {
+        self.repo().decisions().get_by_id(&id).await
+    }
+    async fn get_votes(&self, id: String) -> Outcome<Vec<approval_vote::Model>> {
+        self.repo().votes().get_by_request(&id).await
+    }
+    async fn get_history(&self, id: String) -> Outcome<Vec<request_event::Model>> {
+        self.repo().request().get_by_id(&id).await?;
+        self.repo().history().get_by_request(&id).await
+    }
+    async fn get_credentials(&self, id: String) -> Outcome<Vec<String>> {
+        let req_model = self.repo().request().get_by_id(&id).await?;
+        self.grant_vc_types(&req_model).await
+    }
+    // `actor` is the authenticated admin, the body's approver is only trusted without one
+    async fn manage_req(
+        &self,
+        id: String,
+        payload: ApprovalVote,
+        actor: Option<String>
+    ) -> Outcome<ApprovalTally> {
         let mut req_model = self.repo().request().get_by_id(&id).await?;
+        if req_model.status != "Pending" {
+            return Err(Errors::security(
+                format!("Request is no longer pending ({})", req_model.status),
+                None
+            ));
+        }
+        let required = self.approval().quorum(&self.grant_vc_types(&req_model).await?);
+
+        // With admin auth on, votes are cast by the authenticated principal
+        let approver = match (actor.clone(), payload.approver.clone()) {
+            (Some(actor), Some(approver)) if actor != approver => {
+                return Err(Errors::security(
+                    format!("Cannot vote as '{}' while authenticated as '{}'", approver, actor),
+                    None
+                ));
+            }
+            (Some(actor), _) => Some(actor),
+            (None, approver) => approver
+        };
+
+        let approvals = match approver.clone() {
+            Some(approver) => {
+                let votes = self.repo().votes().get_by_request(&id).await?;
+                if votes.iter().any(|vote| vote.approver == approver) {
+                    return Err(Errors::security(
+                        format!("'{}' has already voted on this request", approver),
+                        None
+                    ));
+                }
+                let n_vote = approval_vote::NewModel {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    request_id: id.clone(),
+                    approver,
+                    approve: payload.approve
+                };
+                // The (request, approver) index refuses a concurrent duplicate
+                self.repo().votes().create(n_vote).await?;
+                // Counted after the write so concurrent votes see each other
+                let votes = self.repo().votes().get_by_request(&id).await?;
+                votes.iter().filter(|vote| vote.approve).count()
+            }
+            None if required > 1 => {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    format!("Missing field approver, '{}' needs a quorum", req_model.vc_type),
+                    None
+                ));
+            }
+            None => usize::from(payload.approve)
+        };
+
+        let actor = actor.or_else(|| approver.clone());
+        let details = json!({ "approver": approver, "reason": payload.reason });
+
+        // Any rejection vetoes the request, approvals wait for the quorum
+        if payload.approve && approvals < required {
+            let history = self.repo().history();
+            history.record(&id, &RequestEvent::Voted, approver, payload.reason).await?;
+            let event = AuditEvent::new(AuditAction::RequestVoted, actor, Some(&id));
+            self.record_audit(event.with_details(details)).await?;
+            return Ok(ApprovalTally { status: req_model.status, approvals, required });
+        }
+
         let int_model = self.repo().interaction().get_by_id(&id).await?;
         let body =
             self.gatekeeper().apprv_dny_req(payload.approve, &mut req_model, &int_model).await?;
-        self.repo().request().update(req_model).await?;
-        self.gatekeeper().notify_minion(&int_model, body).await
+        // The decision and its callback are stored together so a crash cannot lose the callback
+        let n_callback = self.outbox().start(&int_model, body);
+        let callbacks = self.repo().callbacks();
+        let saved = callbacks.create_with_request(req_model.clone(), "Pending", n_callback).await?;
+        let Some(callback) = saved else {
+            // A concurrent vote already decided it, this one is only recorded
+            let req_model = self.repo().request().get_by_id(&id).await?;
+            return Ok(ApprovalTally { status: req_model.status, approvals, required });
+        };
+        let (event, action) = match payload.approve {
+            true => (RequestEvent::Approved, AuditAction::RequestApproved),
+            false => (RequestEvent::Rejected, AuditAction::RequestRejected)
+        };
+        self.repo().history().record(&id, &event, approver, payload.reason).await?;
+        let event = AuditEvent::new(action, actor, Some(&id));
+        self.record_audit(event.with_details(details)).await?;
+        self.metrics().request_decided(payload.approve, &ApprovalSource::Manual);
+        self.deliver_callback(callback).await?;
+        Ok(ApprovalTally { status: req_model.status, approvals, required })
     }
 }
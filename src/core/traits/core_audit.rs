@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::warn;
+use ymir::errors::Outcome;
+
+use super::CoreVerifierTrait;
+use crate::data::entities::audit_entry;
+use crate::services::audit::types::{AuditEvent, AuditQuery, AuditReport};
+use crate::services::audit::AuditTrait;
+
+const APPEND_ATTEMPTS: u32 = 5;
+const VERIFY_PAGE: u64 = 500;
+
+#[async_trait]
+pub trait CoreAuditTrait: CoreVerifierTrait {
+    fn audit(&self) -> Arc<dyn AuditTrait>;
+    // A failed append is returned so no decision goes unaudited without the caller knowing
+    async fn record_audit(&self, event: AuditEvent) -> Outcome<()> {
+        let mut attempt = 1;
+        loop {
+            let last = self.repo().audit().last().await?;
+            let mut n_model = self.audit().start(last.as_ref(), &event)?;
+            self.audit().sign(&mut n_model).await?;
+            // Concurrent appends race for the same sequence number, the loser retries on top
+            match self.repo().audit().create(n_model).await {
+                Ok(_) => return Ok(()),
+                Err(e) if attempt < APPEND_ATTEMPTS => {
+                    warn!("Retrying audit append for {}: {}", event.action, e);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e)
+            }
+        }
+    }
+    async fn get_audit(&self, query: AuditQuery) -> Outcome<Vec<audit_entry::Model>> {
+        let limit = query.limit.unwrap_or(VERIFY_PAGE).clamp(1, 5000);
+        self.repo().audit().get_after(query.after.unwrap_or(0), limit).await
+    }
+    async fn verify_audit(&self) -> Outcome<AuditReport> {
+        let key = self.audit().verifying_key().await?;
+        let mut report = AuditReport::default();
+        loop {
+            let after = report.last_seq.unwrap_or(0);
+            let entries = self.repo().audit().get_after(after, VERIFY_PAGE).await?;
+            if entries.is_empty() {
+                return Ok(report);
+            }
+            for entry in &entries {
+                if !self.audit().verify_entry(&mut report, entry, &key) {
+                    return Ok(report);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::info;
+use ymir::errors::Outcome;
+use ymir::types::gnap::RejectedCallbackBody;
+use ymir::utils::parse_to_value;
+
+use super::{CoreGatekeeperTrait, CoreVerificationTrait};
+use crate::data::entities::session_deadline;
+use crate::services::approval::types::RequestEvent;
+use crate::services::auth::types::LoginStatus;
+use crate::services::expiry::types::SessionOrigin;
+
+#[async_trait]
+pub trait CoreExpiryTrait: CoreGatekeeperTrait + CoreVerificationTrait {
+    async fn expire_sessions(&self) -> Outcome<()> {
+        let now = Utc::now().naive_utc();
+        let expired = self.repo().deadlines().get_expired(now).await?;
+        for mut model in expired {
+            info!("Expiring abandoned session {}", model.id);
+            model.expired_at = Some(now);
+            let model = self.repo().deadlines().update(model).await?;
+            if let Err(e) = self.discard_response_key(&model).await {
+                e.log();
+            }
+            if let Err(e) = self.close_expired(&model).await {
+                e.log();
+            }
+        }
+        Ok(())
+    }
+    // Keys for encrypted responses are kept until the verification succeeds or expires
+    async fn discard_response_key(&self, model: &session_deadline::Model) -> Outcome<()> {
+        let (Some(_), Some(_)) = (self.encryption(), model.verification_until) else {
+            return Ok(());
+        };
+        self.repo().response_keys().delete(&model.id).await
+    }
+    async fn close_expired(&self, model: &session_deadline::Model) -> Outcome<()> {
+        let repo = self.repo();
+        match SessionOrigin::from_str(&model.origin)? {
+            SessionOrigin::Request => {
+                let mut req_model = repo.request().get_by_id(&model.id).await?;
+                req_model.status = "Expired".to_string();
+                let req_model = repo.request().update(req_model).await?;
+                repo.history().record(&req_model.id, &RequestEvent::Expired, None, None).await?;
+                if req_model.interact_method.is_empty() {
+                    return Ok(());
+                }
+                let int_model = repo.interaction().get_by_id(&model.id).await?;
+                if int_model.method == "push" {
+                    let body = RejectedCallbackBody { rejected: "Request expired".to_string() };
+                    self.send_callback(&int_model, parse_to_value(&body)?).await?;
+                }
+                Ok(())
+            }
+            SessionOrigin::Gaia => {
+                let mut participant = repo.gaia().get_by_id(&model.id).await?;
+                participant.status = "Expired".to_string();
+                repo.gaia().update(participant).await?;
+                Ok(())
+            }
+            SessionOrigin::Standalone => {
+                let mut session = repo.sessions().get_by_id(&model.id).await?;
+                self.verification().expire(&mut session);
+                let session = repo.sessions().update(session).await?;
+                self.notify_session(&session).await
+            }
+            SessionOrigin::Login => {
+                let mut login = repo.logins().get_by_id(&model.id).await?;
+                login.status = LoginStatus::Expired.to_string();
+                repo.logins().update(login).await?;
+                Ok(())
+            }
+        }
+    }
+}
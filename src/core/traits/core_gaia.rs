@@ -18,30 +18,109 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use ymir::errors::Outcome;
+use serde_json::json;
+use tracing::info;
+use ymir::data::entities::vc_request;
+use ymir::errors::{Errors, Outcome};
 use ymir::services::issuer::IssuerTrait;
-use ymir::services::verifier::VerifierTrait;
+use ymir::utils::parse_to_value;
 
-use crate::services::repo::RepoTrait;
+use super::CoreAuditTrait;
+use crate::data::entities::gaia_participant;
+use crate::services::audit::types::{AuditAction, AuditEvent};
+use crate::services::expiry::types::{SessionOrigin, SessionPhase};
+use crate::services::gaia::types::{
+    GaiaCredentialRef, GaiaOnboardRequest, GaiaOnboardResponse, GaiaReview, GaiaTerms
+};
+use crate::services::gaia::GaiaTrait;
 
 #[async_trait]
-pub trait GaiaCoreTrait: Send + Sync + 'static {
-    fn verifier(&self) -> Arc<dyn VerifierTrait>;
-    fn repo(&self) -> Arc<dyn RepoTrait>;
+pub trait CoreGaiaTrait: CoreAuditTrait {
+    fn gaia(&self) -> Arc<dyn GaiaTrait>;
     fn issuer(&self) -> Arc<dyn IssuerTrait>;
-    async fn manage_req(&self) -> Outcome<String> {
-        let id = uuid::Uuid::new_v4().to_string();
-        let model = self.verifier().start_vp(&id)?;
-        let model = self.repo().verification().create(model).await?;
-        Ok(self.verifier().generate_verification_uri(&model))
+    async fn manage_req(&self, payload: GaiaOnboardRequest) -> Outcome<GaiaOnboardResponse> {
+        let n_model = self.gaia().start(&payload)?;
+        let model = self.repo().gaia().create(n_model).await?;
+        let n_ver_model = self.verifier().start_vp(&model.id)?;
+        let ver_model = self.repo().verification().create(n_ver_model).await?;
+        self.open_deadline(&model.id, &SessionOrigin::Gaia, &SessionPhase::Verification).await?;
+        let uri = self.verification_uri(&ver_model).await?;
+        Ok(GaiaOnboardResponse { id: model.id, uri })
     }
-    async fn verify(&self, state: String, vp_token: String) -> Outcome<String> {
-        let mut ver_model = self.repo().verification().get_by_state(&state).await?;
-        let result = self.verifier().verify_all(&mut ver_model, &vp_token).await;
-        let _int_model = self.repo().interaction().get_by_id(&ver_model.id).await?;
-        result?;
+    fn get_terms(&self) -> GaiaTerms { self.gaia().terms() }
+    async fn get_participant(&self, id: String) -> Outcome<gaia_participant::Model> {
+        self.repo().gaia().get_by_id(&id).await
+    }
+    async fn is_gaia_session(&self, state: &str) -> Outcome<bool> {
+        let ver_model = self.repo().verification().get_by_state(state).await?;
+        Ok(self.repo().gaia().find_by_id(&ver_model.id).await?.is_some())
+    }
+    async fn verify_participant(&self, state: String, vp_token: String) -> Outcome<Option<String>> {
+        let mut ver_model = self.active_verification(&state).await?;
+        let mut model = self.repo().gaia().get_by_id(&ver_model.id).await?;
+        self.verify_presentation(&mut ver_model, &vp_token).await?;
+        self.gaia().validate_presentation(&mut model, &vp_token)?;
+        // Only an accepted presentation closes the verification, a rejected one can be retried
         self.repo().verification().update(ver_model).await?;
-        // let uri = self.issuer().
-        todo!()
+        // Name and addresses are self-declared, so nothing is issued before an approver reviews
+        self.repo().gaia().update(model).await?;
+        Ok(None)
+    }
+    async fn review_participant(
+        &self,
+        id: String,
+        payload: GaiaReview,
+        actor: Option<String>
+    ) -> Outcome<gaia_participant::Model> {
+        let mut model = self.repo().gaia().get_by_id(&id).await?;
+        if model.status != "Verified" {
+            return Err(Errors::security(
+                format!("Participant is not awaiting review ({})", model.status),
+                None
+            ));
+        }
+        model.reviewer = actor.clone();
+        let details = json!({ "legal_name": model.legal_name, "reason": payload.reason });
+
+        if !payload.approve {
+            model.status = "Rejected".to_string();
+            let model = self.repo().gaia().update(model).await?;
+            let event = AuditEvent::new(AuditAction::ParticipantRejected, actor, Some(&id));
+            self.record_audit(event.with_details(details)).await?;
+            return Ok(model);
+        }
+
+        let credential_data = self.gaia().gather_data(&model)?;
+        let mut requests = vec![];
+        let mut credentials = vec![];
+        for vc_type in self.gaia().credentials() {
+            let n_req_model = self.gaia().start_request(&model, &vc_type);
+            let vc_uri = self.issuer().generate_issuing_uri(&n_req_model.id, None);
+            info!(vc_uri);
+            credentials.push(GaiaCredentialRef {
+                vc_type: vc_type.to_string(),
+                id: n_req_model.id.clone(),
+                uri: vc_uri.clone()
+            });
+            requests.push((n_req_model, vc_uri));
+        }
+        model.credentials = parse_to_value(&credentials)?;
+        model.status = "Approved".to_string();
+
+        let issuer = self.issuer();
+        let prepare = move |req_model: &vc_request::Model| issuer.start_vci(req_model);
+        let gaia = self.repo().gaia();
+        let saved = gaia.approve(model, requests, &credential_data, actor.clone(), &prepare).await?;
+        let model = saved.ok_or_else(|| {
+            Errors::security("Participant was reviewed by someone else meanwhile", None)
+        })?;
+        for credential in &credentials {
+            self.open_deadline(&credential.id, &SessionOrigin::Request, &SessionPhase::Issuance)
+                .await?;
+        }
+
+        let event = AuditEvent::new(AuditAction::ParticipantApproved, actor, Some(&id));
+        self.record_audit(event.with_details(details)).await?;
+        Ok(model)
     }
 }
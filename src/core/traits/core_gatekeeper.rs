@@ -19,62 +19,182 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use serde_json::{json, Value};
 use tracing::info;
+use ymir::data::entities::{recv_interaction, vc_request};
 use ymir::errors::{BadFormat, Errors, Outcome};
 use ymir::services::issuer::IssuerTrait;
-use ymir::services::verifier::VerifierTrait;
-use ymir::types::gnap::grant_request::{GrantRequest, InteractStart};
+use ymir::types::gnap::grant_request::InteractStart;
 use ymir::types::gnap::grant_response::GrantResponse;
 use ymir::types::gnap::RefBody;
 use ymir::types::vcs::VcType;
+use ymir::utils::parse_to_value;
 
+use super::{CoreAuditTrait, CoreOutboxTrait};
+use crate::config::approval::ApprovalOutcome;
+use crate::data::entities::{approval_decision, gnap_grant, interaction_start};
+use crate::services::approval::types::RequestEvent;
+use crate::services::approval::ApprovalTrait;
+use crate::services::audit::types::{AuditAction, AuditEvent};
+use crate::services::expiry::types::{SessionOrigin, SessionPhase};
+use crate::services::gatekeeper::types::{
+    Continuation, GnapGrantRequest, GnapResponse, GrantCredential, GrantModification,
+    InteractionMode, InteractionStatus
+};
 use crate::services::gatekeeper::GateKeeperTrait;
+use crate::services::metrics::types::ApprovalSource;
 use crate::services::notifications::NotificationsTrait;
-use crate::services::repo::RepoTrait;
+use crate::services::proofing::types::SignedRequest;
+use crate::services::proofing::ProofTrait;
+use crate::services::rate_limit::types::PendingOwner;
+use crate::services::tokens::types::{
+    IntrospectionRequest, IntrospectionResponse, ResourceServerRequest, ResourceServerResponse
+};
+use crate::services::tokens::TokenTrait;
 use crate::services::vcs_builder::VcBuilderTrait;
 
 #[async_trait]
-pub trait CoreGatekeeperTrait: Send + Sync + 'static {
+pub trait CoreGatekeeperTrait: CoreOutboxTrait + CoreAuditTrait {
     fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait>;
-    fn verifier(&self) -> Arc<dyn VerifierTrait>;
     fn issuer(&self) -> Arc<dyn IssuerTrait>;
-    fn repo(&self) -> Arc<dyn RepoTrait>;
     fn vc_builder(&self) -> Arc<dyn VcBuilderTrait>;
     fn notifier(&self) -> Option<Arc<dyn NotificationsTrait>>;
-    async fn manage_req(&self, payload: GrantRequest) -> Result<GrantResponse, GrantResponse> {
-        self.manage_ok_req(&payload).await.map_err(|e| {
+    fn tokens(&self) -> Arc<dyn TokenTrait>;
+    fn proofing(&self) -> Arc<dyn ProofTrait>;
+    fn approval(&self) -> Arc<dyn ApprovalTrait>;
+    async fn manage_req(
+        &self,
+        payload: GnapGrantRequest,
+        signed: SignedRequest
+    ) -> Result<GnapResponse, GnapResponse> {
+        self.manage_ok_req(&payload, &signed).await.map_err(|e| {
             e.log();
-            GrantResponse::error(e.to_string())
+            GnapResponse::error(e.to_string())
         })
     }
-    async fn manage_ok_req(&self, payload: &GrantRequest) -> Outcome<GrantResponse> {
-        let (n_req_mod, n_int_model) = self.gatekeeper().start(payload)?;
+    async fn manage_ok_req(
+        &self,
+        payload: &GnapGrantRequest,
+        signed: &SignedRequest
+    ) -> Outcome<GnapResponse> {
+        let key = self.proofing().bind(&parse_to_value(&payload.request.client.key)?, signed)?;
+        let (n_req_mod, n_int_model) = self.gatekeeper().start(&payload.request)?;
+        let access = self.tokens().grant_access(payload.access.clone());
+        let credentials = parse_to_value(&self.gatekeeper().grant_credentials(&access)?)?;
 
         let req_model = self.repo().request().create(n_req_mod).await?;
+        self.repo().history().record(&req_model.id, &RequestEvent::Created, None, None).await?;
+        let actor = Some(req_model.participant_slug.clone());
+        let event = AuditEvent::new(AuditAction::GrantRequested, actor, Some(&req_model.id))
+            .with_details(json!({ "vc_type": req_model.vc_type }));
+        self.record_audit(event).await?;
 
         if let Some(notifier) = self.notifier() {
             notifier.notify(&req_model);
         }
 
-        let int_model = self.repo().interaction().create(n_int_model).await?;
+        let mut int_model = self.repo().interaction().create(n_int_model).await?;
+        let server_nonce = self.gatekeeper().seal_finish(&mut int_model)?;
+        let int_model = self.repo().interaction().update(int_model).await?;
+
+        let n_grant = gnap_grant::NewModel {
+            id: int_model.id.clone(),
+            access,
+            key,
+            credentials: Some(credentials),
+            client_ip: signed.client_ip.map(|ip| ip.to_string())
+        };
+        self.repo().grants().create(n_grant).await?;
 
         let iss_model = self.issuer().start_vci(&req_model);
 
         let _iss_model = self.repo().issuing().create(iss_model).await?;
 
+        self.open_deadline(&int_model.id, &SessionOrigin::Request, &SessionPhase::Interaction)
+            .await?;
+
+        let callback = self.auto_approve(req_model.clone(), &int_model).await?;
+        let response = self.start_grant_interaction(&int_model).await?;
+
+        // The minion only learns the continuation from this response, so the outbox calls back
+        if let Some(body) = callback {
+            self.queue_callback(&int_model, body).await?;
+        }
+        Ok(response.with_finish(server_nonce))
+    }
+    async fn auto_approve(
+        &self,
+        mut req_model: vc_request::Model,
+        int_model: &recv_interaction::Model
+    ) -> Outcome<Option<Value>> {
+        let known = self.repo().listings().has_issued(&req_model.participant_slug).await?;
+        let grant = self.repo().grants().get_by_id(&req_model.id).await?;
+        let vc_types = GrantCredential::vc_types(&req_model.vc_type, grant.credentials.as_ref())?;
+        // A certificate only speaks for the client when the grant is bound to it over mTLS
+        let cert = grant.key.as_ref().and_then(|key| self.proofing().bound_cert(key));
+        let decision =
+            self.approval().evaluate(&req_model, &vc_types, cert.as_ref(), known, Utc::now());
+        info!("Approval policy outcome for request {}: {}", req_model.id, decision.outcome);
+        self.metrics().grant_requested(&req_model.vc_type, &decision.outcome);
+
+        let n_decision = approval_decision::NewModel {
+            id: req_model.id.clone(),
+            outcome: decision.outcome.to_string(),
+            rule: decision.rule.clone()
+        };
+        self.repo().decisions().create(n_decision).await?;
+
+        let approve = match decision.outcome {
+            ApprovalOutcome::Approve => true,
+            ApprovalOutcome::Reject => false,
+            ApprovalOutcome::ManualReview => return Ok(None)
+        };
+        let body = self.gatekeeper().apprv_dny_req(approve, &mut req_model, int_model).await?;
+        self.repo().request().update(req_model).await?;
+        self.metrics().request_decided(approve, &ApprovalSource::Policy);
+        let (event, action) = match approve {
+            true => (RequestEvent::Approved, AuditAction::RequestApproved),
+            false => (RequestEvent::Rejected, AuditAction::RequestRejected)
+        };
+        let actor = Some("approval_policy".to_string());
+        let audit = AuditEvent::new(action, actor.clone(), Some(&int_model.id))
+            .with_details(json!({ "rule": decision.rule }));
+        self.repo().history().record(&int_model.id, &event, actor, decision.rule).await?;
+        self.record_audit(audit).await?;
+
+        if !approve {
+            let now = Utc::now().naive_utc();
+            self.repo().deadlines().expire(&int_model.id, &SessionOrigin::Request, now).await?;
+            return Err(Errors::unauthorized("Request was rejected by the approval policy", None));
+        }
+        // Other interaction modes still go through the holder before anything is sent back
+        let cross_user = int_model.start.contains(&InteractStart::CrossUser.to_string());
+        Ok(cross_user.then_some(body))
+    }
+    async fn start_grant_interaction(
+        &self,
+        int_model: &recv_interaction::Model
+    ) -> Outcome<GnapResponse> {
         if int_model.start.contains(&InteractStart::Oidc4VP.to_string()) {
             let n_ver_model = self.verifier().start_vp(&int_model.id)?;
 
             let ver_model = self.repo().verification().create(n_ver_model).await?;
 
-            let uri = self.verifier().generate_verification_uri(&ver_model);
+            self.open_deadline(&int_model.id, &SessionOrigin::Request, &SessionPhase::Verification)
+                .await?;
+
+            let uri = self.verification_uri(&ver_model).await?;
 
-            let response = GrantResponse::new(&InteractStart::Oidc4VP, &int_model, Some(&uri));
+            let response = GrantResponse::new(&InteractStart::Oidc4VP, int_model, Some(&uri));
 
-            return Ok(response);
+            return GnapResponse::from_grant(&response);
+        }
+        if !InteractionMode::select(&int_model.start).is_empty() {
+            return self.start_hosted_interaction(int_model).await;
         }
         if int_model.start.contains(&InteractStart::CrossUser.to_string()) {
-            return self.gatekeeper().manage_cross_user(&int_model);
+            return GnapResponse::from_grant(&self.gatekeeper().manage_cross_user(int_model)?);
         }
         let method = int_model.start.first().ok_or_else(|| {
             Errors::format(BadFormat::Received, "Missing field interact method", None)
@@ -85,19 +205,301 @@ pub trait CoreGatekeeperTrait: Send + Sync + 'static {
             None
         ))
     }
+    async fn start_hosted_interaction(
+        &self,
+        int_model: &recv_interaction::Model
+    ) -> Outcome<GnapResponse> {
+        let n_ver_model = self.verifier().start_vp(&int_model.id)?;
+        let ver_model = self.repo().verification().create(n_ver_model).await?;
+        self.open_deadline(&int_model.id, &SessionOrigin::Request, &SessionPhase::Verification)
+            .await?;
+        let uri = self.verification_uri(&ver_model).await?;
+
+        let n_start_model = self.gatekeeper().start_interaction(int_model, &uri)?;
+        let start_model = self.repo().starts().create(n_start_model).await?;
+        let expires_in = self.expiry().ttl(&SessionPhase::Verification);
+        self.gatekeeper().interaction_response(int_model, &start_model, &uri, expires_in)
+    }
+    async fn pending_interaction(&self, id: &str) -> Outcome<interaction_start::Model> {
+        self.ensure_active(id, &SessionPhase::Interaction).await?;
+        let start_model = self.repo().starts().get_by_id(id).await?;
+        if start_model.status != "Pending" {
+            return Err(Errors::security("Interaction has already finished", None));
+        }
+        Ok(start_model)
+    }
+    async fn get_interaction_page(&self, id: String) -> Outcome<String> {
+        let start_model = self.repo().starts().get_by_id(&id).await?;
+        if start_model.status == "Pending" {
+            self.ensure_active(&id, &SessionPhase::Interaction).await?;
+            self.ensure_active(&id, &SessionPhase::Verification).await?;
+        }
+        let req_model = self.repo().request().get_by_id(&id).await?;
+        Ok(self.gatekeeper().interaction_page(&req_model, &start_model))
+    }
+    async fn get_interaction_status(&self, id: String) -> Outcome<InteractionStatus> {
+        let start_model = self.repo().starts().get_by_id(&id).await?;
+        Ok(InteractionStatus { status: start_model.status, redirect: start_model.finish_uri })
+    }
+    async fn deny_interaction(&self, id: String) -> Outcome<String> {
+        let mut start_model = self.pending_interaction(&id).await?;
+        let mut req_model = self.repo().request().get_by_id(&id).await?;
+        let int_model = self.repo().interaction().get_by_id(&id).await?;
+
+        let body = self.gatekeeper().apprv_dny_req(false, &mut req_model, &int_model).await?;
+        self.repo().request().update(req_model).await?;
+        let reason = Some("Denied by the end user".to_string());
+        self.repo().history().record(&id, &RequestEvent::Rejected, None, reason).await?;
+        start_model.status = "Denied".to_string();
+        self.repo().starts().update(start_model).await?;
+        self.repo().deadlines().close(&id, &SessionPhase::Interaction).await?;
+        self.repo().deadlines().close(&id, &SessionPhase::Verification).await?;
+
+        if int_model.method == "push" {
+            self.send_callback(&int_model, body).await?;
+        }
+        Ok(self.gatekeeper().interaction_uri(&id))
+    }
+    async fn resolve_user_code(&self, code: String) -> Outcome<String> {
+        let code = code.to_uppercase().replace(['-', ' '], "");
+        let start_model = self.repo().starts().get_by_user_code(&code).await?;
+        let start_model = self.pending_interaction(&start_model.id).await?;
+        Ok(self.gatekeeper().interaction_uri(&start_model.id))
+    }
+    fn device_page(&self, error: Option<&str>) -> String {
+        self.gatekeeper().device_page(error)
+    }
+    // Continuations must come from the key that started a bound grant (RFC 9635 §5)
+    async fn verify_binding(&self, id: &str, signed: &SignedRequest) -> Outcome<()> {
+        let grant = self.repo().grants().find_by_id(id).await?;
+        match grant.and_then(|grant| grant.key) {
+            Some(key) => self.proofing().verify(&key, signed),
+            None => Ok(())
+        }
+    }
+    async fn active_grant(
+        &self,
+        cont_id: &str,
+        token: &str,
+        signed: &SignedRequest
+    ) -> Outcome<(recv_interaction::Model, vc_request::Model)> {
+        let int_model = self.repo().interaction().get_by_cont_id(cont_id).await?;
+        self.gatekeeper().validate_cont_token(&int_model, token)?;
+        self.verify_binding(&int_model.id, signed).await?;
+        let req_model = self.repo().request().get_by_id(&int_model.id).await?;
+        if !matches!(req_model.status.as_str(), "Pending" | "Approved") {
+            return Err(Errors::security(
+                format!("Grant is no longer active ({})", req_model.status),
+                None
+            ));
+        }
+        Ok((int_model, req_model))
+    }
+    async fn continuation(
+        &self,
+        mut int_model: recv_interaction::Model,
+        cont_id: &str,
+        req_model: &vc_request::Model
+    ) -> Outcome<GnapResponse> {
+        let wait = match req_model.vc_uri {
+            Some(_) => None,
+            None => Some(self.tokens().continue_wait())
+        };
+        let continuation = self.gatekeeper().rotate_continuation(&mut int_model, cont_id, wait);
+        self.repo().interaction().update(int_model).await?;
+
+        let mut response = GnapResponse { continue_: Some(continuation), ..Default::default() };
+        if let Some(vc_uri) = &req_model.vc_uri {
+            response.extra.insert("vc_uri".to_string(), vc_uri.clone().into());
+            let grant = self.repo().grants().find_by_id(&req_model.id).await?;
+            let credentials =
+                GrantCredential::list(grant.as_ref().and_then(|grant| grant.credentials.as_ref()))?;
+            if credentials.len() > 1 {
+                response.extra.insert("credentials".to_string(), parse_to_value(&credentials)?);
+            }
+        }
+        Ok(response)
+    }
+    async fn poll_cont_req(
+        &self,
+        cont_id: String,
+        token: String,
+        signed: SignedRequest
+    ) -> Outcome<GnapResponse> {
+        let (int_model, req_model) = self.active_grant(&cont_id, &token, &signed).await?;
+        self.continuation(int_model, &cont_id, &req_model).await
+    }
+    async fn modify_cont_req(
+        &self,
+        cont_id: String,
+        payload: GrantModification,
+        token: String,
+        signed: SignedRequest
+    ) -> Outcome<GnapResponse> {
+        let (int_model, mut req_model) = self.active_grant(&cont_id, &token, &signed).await?;
+        let Some(access_token) = payload.access_token else {
+            return self.continuation(int_model, &cont_id, &req_model).await;
+        };
+        // Decisions were taken on the old access, so only undecided grants can change it
+        if req_model.status != "Pending" {
+            return Err(Errors::security(
+                format!("Grant can no longer be modified ({})", req_model.status),
+                None
+            ));
+        }
+        let access = self.tokens().grant_access(access_token.access);
+        let credentials = self.gatekeeper().grant_credentials(&access)?;
+        let mut grant = self.repo().grants().get_by_id(&int_model.id).await?;
+        let previous = req_model.vc_type.clone();
+        if let Some(primary) = credentials.first() {
+            req_model.vc_type = primary.vc_type.clone();
+            req_model = self.repo().request().update(req_model).await?;
+            grant.credentials = Some(parse_to_value(&credentials)?);
+        }
+        grant.access = access;
+        self.repo().grants().update(grant).await?;
+
+        let dropped = self.repo().votes().delete_by_request(&req_model.id).await?;
+        self.repo().decisions().delete(&req_model.id).await?;
+        let actor = Some(req_model.participant_slug.clone());
+        let reason = Some(format!("Access changed from {} to {}", previous, req_model.vc_type));
+        let history = self.repo().history();
+        history.record(&req_model.id, &RequestEvent::Modified, actor.clone(), reason).await?;
+        let event = AuditEvent::new(AuditAction::GrantModified, actor, Some(&req_model.id))
+            .with_details(json!({
+                "previous": previous,
+                "vc_types": credentials.iter().map(|c| &c.vc_type).collect::<Vec<_>>(),
+                "dropped_votes": dropped
+            }));
+        self.record_audit(event).await?;
+
+        // The new access goes through the approval policy again
+        let callback = self.auto_approve(req_model.clone(), &int_model).await?;
+        let req_model = self.repo().request().get_by_id(&req_model.id).await?;
+        let response = self.continuation(int_model.clone(), &cont_id, &req_model).await?;
+        if let Some(body) = callback {
+            self.queue_callback(&int_model, body).await?;
+        }
+        Ok(response)
+    }
+    async fn revoke_cont_req(
+        &self,
+        cont_id: String,
+        token: String,
+        signed: SignedRequest
+    ) -> Outcome<()> {
+        let (mut int_model, _) = self.active_grant(&cont_id, &token, &signed).await?;
+        let now = Utc::now().naive_utc();
+        info!("Revoking grant {}", int_model.id);
+
+        self.cancel_request(&int_model.id, now).await?;
+        let grant = self.repo().grants().find_by_id(&int_model.id).await?;
+        for credential in
+            GrantCredential::list(grant.as_ref().and_then(|grant| grant.credentials.as_ref()))?
+        {
+            match credential.id {
+                Some(id) if id != int_model.id => self.cancel_request(&id, now).await?,
+                _ => {}
+            }
+        }
+
+        for mut token in self.repo().tokens().get_by_grant(&int_model.id).await? {
+            token.revoked_at = Some(now);
+            self.repo().tokens().update(token).await?;
+        }
+
+        // Rotate without handing out the new token so the continuation can't be reused
+        self.gatekeeper().rotate_continuation(&mut int_model, &cont_id, None);
+        let int_model = self.repo().interaction().update(int_model).await?;
+        let event = AuditEvent::new(AuditAction::GrantRevoked, None, Some(&int_model.id));
+        self.record_audit(event).await
+    }
+    async fn cancel_request(&self, id: &str, now: NaiveDateTime) -> Outcome<()> {
+        let mut req_model = self.repo().request().get_by_id(id).await?;
+        // Credentials already in the holder's wallet are left as they are
+        if req_model.is_vc_issued {
+            return Ok(());
+        }
+        req_model.status = "Cancelled".to_string();
+        self.repo().request().update(req_model).await?;
+        self.repo().history().record(id, &RequestEvent::Cancelled, None, None).await?;
+
+        let mut iss_model = self.repo().issuing().get_by_id(id).await?;
+        iss_model.uri = None;
+        iss_model.credential_data = None;
+        self.repo().issuing().update(iss_model).await?;
+        self.repo().deadlines().expire(id, &SessionOrigin::Request, now).await
+    }
+    async fn issue_linked_credentials(
+        &self,
+        req_model: &vc_request::Model,
+        grant: &mut gnap_grant::Model
+    ) -> Outcome<()> {
+        let mut credentials = GrantCredential::list(grant.credentials.as_ref())?;
+        for credential in credentials.iter_mut() {
+            if credential.vc_type == req_model.vc_type {
+                credential.id = Some(req_model.id.clone());
+                credential.uri = req_model.vc_uri.clone();
+                continue;
+            }
+            if credential.id.is_some() {
+                continue;
+            }
+            let vc_type = VcType::from_str(&credential.vc_type)?;
+            let n_req_model = self.gatekeeper().start_linked_request(req_model, &vc_type);
+            let mut linked = self.repo().request().create(n_req_model).await?;
+            let reason = Some(format!("Linked to grant {}", req_model.id));
+            self.repo().history().record(&linked.id, &RequestEvent::Created, None, reason).await?;
+
+            let iss_model = self.issuer().start_vci(&linked);
+            let mut iss_model = self.repo().issuing().create(iss_model).await?;
+
+            let credential_data = self.vc_builder().gather_data(&linked)?;
+            let vc_uri = self.issuer().generate_issuing_uri(&linked.id, None);
+            info!(vc_uri);
+
+            linked.vc_uri = Some(vc_uri.clone());
+            linked.status = "Approved".to_string();
+            iss_model.uri = Some(vc_uri.clone());
+            iss_model.credential_data = Some(credential_data);
+
+            let linked = self.repo().request().update(linked).await?;
+            // The grant's approval and quorum already covered this type
+            let reason = Some(format!("Approved with grant {}", req_model.id));
+            self.repo().history().record(&linked.id, &RequestEvent::Approved, None, reason).await?;
+            self.repo().issuing().update(iss_model).await?;
+            self.open_deadline(&linked.id, &SessionOrigin::Request, &SessionPhase::Issuance)
+                .await?;
+
+            credential.id = Some(linked.id);
+            credential.uri = Some(vc_uri);
+        }
+        grant.credentials = Some(parse_to_value(&credentials)?);
+        Ok(())
+    }
     async fn manage_cont_req(
         &self,
         cont_id: String,
         payload: RefBody,
-        token: String
-    ) -> Outcome<String> {
+        token: String,
+        signed: SignedRequest
+    ) -> Outcome<Continuation> {
         let int_model = self.repo().interaction().get_by_cont_id(&cont_id).await?;
+        self.ensure_active(&int_model.id, &SessionPhase::Interaction).await?;
         let mut iss_model = self.repo().issuing().get_by_id(&int_model.id).await?;
         let mut req_model = self.repo().request().get_by_id(&int_model.id).await?;
 
         let vc_type = VcType::from_str(&req_model.vc_type)?;
 
         self.gatekeeper().validate_cont_req(&int_model, &payload.interact_ref, &token)?;
+        self.verify_binding(&int_model.id, &signed).await?;
+        // Cancelled, expired or rejected requests, and those still awaiting a decision, get nothing
+        if req_model.status != "Approved" {
+            return Err(Errors::security(
+                format!("Request is not approved ({})", req_model.status),
+                None
+            ));
+        }
         self.gatekeeper().validate_vc_to_issue(&vc_type)?;
 
         let credential_data = self.vc_builder().gather_data(&req_model)?;
@@ -108,9 +510,66 @@ pub trait CoreGatekeeperTrait: Send + Sync + 'static {
         iss_model.uri = Some(vc_uri.clone());
         iss_model.credential_data = Some(credential_data);
 
-        let _req_model = self.repo().request().update(req_model).await?;
+        let req_model = self.repo().request().update(req_model).await?;
         let _iss_model = self.repo().issuing().update(iss_model).await?;
 
-        Ok(vc_uri)
+        self.repo().deadlines().close(&int_model.id, &SessionPhase::Interaction).await?;
+        self.open_deadline(&int_model.id, &SessionOrigin::Request, &SessionPhase::Issuance).await?;
+
+        let grant = match self.repo().grants().find_by_id(&int_model.id).await? {
+            Some(mut grant) => {
+                self.issue_linked_credentials(&req_model, &mut grant).await?;
+                Some(self.repo().grants().update(grant).await?)
+            }
+            None => None
+        };
+        let mut response = self.continuation(int_model, &cont_id, &req_model).await?;
+        // Grants started before access rights were recorded only carry the VC offer
+        if let Some(grant) = grant {
+            let (n_token, value) = self.tokens().issue(&grant)?;
+            let token = self.repo().tokens().create(n_token).await?;
+            response.access_token = Some(self.tokens().token_response(&token, &value)?);
+        }
+
+        Ok(Continuation { vc_uri, response })
+    }
+    async fn register_resource_server(
+        &self,
+        key: String,
+        payload: ResourceServerRequest
+    ) -> Outcome<ResourceServerResponse> {
+        self.tokens().authorize_registration(&key)?;
+        let (n_model, secret) = self.tokens().register(&payload)?;
+        let model = self.repo().resource_servers().create(n_model).await?;
+        Ok(ResourceServerResponse { id: model.id, secret })
+    }
+    async fn introspect(
+        &self,
+        secret: String,
+        payload: IntrospectionRequest
+    ) -> Outcome<IntrospectionResponse> {
+        let secret_hash = self.tokens().hash_secret(&secret);
+        let resource_server = self
+            .repo()
+            .resource_servers()
+            .find_by_secret_hash(&secret_hash)
+            .await?
+            .ok_or_else(|| Errors::unauthorized("Unknown resource server", None))?;
+        let value_hash = self.tokens().hash_secret(&payload.access_token);
+        let token = self.repo().tokens().find_by_value_hash(&value_hash).await?;
+        let mut response = self.tokens().introspect(token.as_ref(), &resource_server, &payload)?;
+        if let (true, Some(token)) = (response.active, token) {
+            let grant = self.repo().grants().find_by_id(&token.grant_id).await?;
+            response.key = grant.and_then(|grant| grant.key);
+        }
+        Ok(response)
+    }
+    async fn count_pending(&self, owner: &PendingOwner) -> Outcome<u64> {
+        match owner {
+            PendingOwner::Participant(slug) => self.repo().listings().count_pending(slug).await,
+            PendingOwner::Address(ip) => {
+                self.repo().listings().count_pending_from(&ip.to_string()).await
+            }
+        }
     }
 }
@@ -18,6 +18,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use serde_json::json;
 use ymir::errors::Outcome;
 use ymir::services::issuer::IssuerTrait;
 use ymir::services::wallet::WalletTrait;
@@ -26,19 +27,28 @@ use ymir::types::issuing::{
     VCCredOffer, WellKnownJwks
 };
 
-use crate::services::repo::RepoTrait;
+use super::CoreAuditTrait;
+use crate::services::approval::types::RequestEvent;
+use crate::services::audit::types::{AuditAction, AuditEvent};
+use crate::services::expiry::types::SessionPhase;
 use crate::services::vcs_builder::VcBuilderTrait;
 
 #[async_trait]
-pub trait CoreIssuerTrait: Send + Sync + 'static {
+pub trait CoreIssuerTrait: CoreAuditTrait {
     fn issuer(&self) -> Arc<dyn IssuerTrait>;
-    fn repo(&self) -> Arc<dyn RepoTrait>;
     fn vc_builder(&self) -> Arc<dyn VcBuilderTrait>;
     fn wallet(&self) -> Option<Arc<dyn WalletTrait>>;
+    async fn ensure_issuance(&self, id: &str) -> Outcome<()> {
+        match self.repo().deadlines().find_by_id(id).await? {
+            Some(model) => self.expiry().ensure_active(&model, &SessionPhase::Issuance),
+            None => Ok(())
+        }
+    }
     async fn get_cred_offer_data(&self, id: &str) -> Outcome<VCCredOffer> {
         let mut model = self.repo().issuing().get_by_id(&id).await?;
 
         let data = self.issuer().get_cred_offer_data(&model, None)?;
+        self.repo().history().record(id, &RequestEvent::OfferFetched, None, None).await?;
 
         if model.step {
             model.step = false;
@@ -61,14 +71,17 @@ pub trait CoreIssuerTrait: Send + Sync + 'static {
     async fn get_token(&self, payload: TokenRequest) -> Outcome<IssuingToken> {
         let model =
             self.repo().issuing().get_by_pre_auth_code(&payload.pre_authorized_code).await?;
+        self.ensure_issuance(&model.id).await?;
 
         self.issuer().validate_token_req(&model, &payload)?;
+        self.repo().history().record(&model.id, &RequestEvent::TokenIssued, None, None).await?;
 
         Ok(self.issuer().get_token(&model))
     }
 
     async fn get_credential(&self, payload: CredentialRequest, token: String) -> Outcome<GiveVC> {
         let mut iss_model = self.repo().issuing().get_by_token(&token).await?;
+        self.ensure_issuance(&iss_model.id).await?;
 
         let did =
             if let Some(wallet) = self.wallet() { Some(wallet.get_did().await?) } else { None };
@@ -79,15 +92,26 @@ pub trait CoreIssuerTrait: Send + Sync + 'static {
         let data = self.issuer().issue_cred(&claims, did.as_deref()).await?;
 
         let mut req_model = self.repo().request().get_by_id(&iss_model.id).await?;
-        let int_model = self.repo().interaction().get_by_id(&iss_model.id).await?;
         let iss_model = self.repo().issuing().update(iss_model).await?;
 
-        let minion = self.issuer().end(&req_model, &int_model, &iss_model)?;
-        self.repo().minions().force_create(minion).await?;
+        // Requests started outside GNAP (e.g. Gaia-X onboarding) have no interaction to close
+        if !req_model.interact_method.is_empty() {
+            let int_model = self.repo().interaction().get_by_id(&iss_model.id).await?;
+            let minion = self.issuer().end(&req_model, &int_model, &iss_model)?;
+            self.repo().minions().force_create(minion).await?;
+        }
 
         req_model.is_vc_issued = true;
         req_model.status = "Finalized".to_string();
-        self.repo().request().update(req_model).await?;
+        let req_model = self.repo().request().update(req_model).await?;
+        self.repo().deadlines().close(&req_model.id, &SessionPhase::Issuance).await?;
+        let history = self.repo().history();
+        history.record(&req_model.id, &RequestEvent::CredentialIssued, None, None).await?;
+        let actor = Some(req_model.participant_slug.clone());
+        let event = AuditEvent::new(AuditAction::CredentialIssued, actor, Some(&req_model.id))
+            .with_details(json!({ "vc_type": req_model.vc_type }));
+        self.record_audit(event).await?;
+        self.metrics().credential_issued(&req_model.vc_type);
 
         Ok(data)
     }
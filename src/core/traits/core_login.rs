@@ -0,0 +1,106 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::json;
+use ymir::errors::{Errors, Outcome};
+use ymir::services::issuer::IssuerTrait;
+use ymir::services::wallet::WalletTrait;
+use ymir::types::issuing::GiveVC;
+
+use super::CoreAuditTrait;
+use crate::services::audit::types::{AuditAction, AuditEvent};
+use crate::services::auth::types::{LoginPoll, LoginResponse, LoginStatus, OperatorRequest};
+use crate::services::auth::AuthTrait;
+use crate::services::expiry::types::{SessionOrigin, SessionPhase};
+
+#[async_trait]
+pub trait CoreLoginTrait: CoreAuditTrait {
+    fn auth(&self) -> Arc<dyn AuthTrait>;
+    fn issuer(&self) -> Arc<dyn IssuerTrait>;
+    fn wallet(&self) -> Option<Arc<dyn WalletTrait>>;
+    async fn start_login(&self) -> Outcome<LoginResponse> {
+        let (n_model, nonce) = self.auth().start_login()?;
+        let model = self.repo().logins().create(n_model).await?;
+        let n_ver_model = self.verifier().start_vp(&model.id)?;
+        let ver_model = self.repo().verification().create(n_ver_model).await?;
+        self.open_deadline(&model.id, &SessionOrigin::Login, &SessionPhase::Verification).await?;
+        let uri = self.verification_uri(&ver_model).await?;
+        Ok(LoginResponse { id: model.id, uri, nonce })
+    }
+    async fn poll_login(&self, id: String, nonce: Option<String>) -> Outcome<LoginPoll> {
+        let mut model = self.repo().logins().get_by_id(&id).await?;
+        self.auth().check_poll(&model, nonce.as_deref())?;
+        if model.status != LoginStatus::Verified.to_string() {
+            return Ok(LoginPoll { status: model.status, error: model.error, session: None });
+        }
+        let session = self.auth().issue_session(&mut model)?;
+        let model = self.repo().logins().update(model).await?;
+        let actor = Some(session.subject.clone());
+        let event = AuditEvent::new(AuditAction::AdminLogin, actor, Some(&model.id))
+            .with_details(json!({ "role": session.role, "vc_type": model.vc_type }));
+        self.record_audit(event).await?;
+        Ok(LoginPoll { status: model.status, error: None, session: Some(session) })
+    }
+    async fn is_login_session(&self, state: &str) -> Outcome<bool> {
+        let ver_model = self.repo().verification().get_by_state(state).await?;
+        Ok(self.repo().logins().find_by_id(&ver_model.id).await?.is_some())
+    }
+    async fn verify_login(&self, state: String, vp_token: String) -> Outcome<Option<String>> {
+        let mut ver_model = self.active_verification(&state).await?;
+        let result = self.verify_presentation(&mut ver_model, &vp_token).await;
+        let mut model = self.repo().logins().get_by_id(&ver_model.id).await?;
+
+        let issuers = self.operator_issuers().await?;
+        let result =
+            result.and_then(|_| self.auth().complete_login(&mut model, &vp_token, &issuers));
+        match &result {
+            Ok(_) => {
+                self.repo().verification().update(ver_model).await?;
+            }
+            Err(e) => self.auth().reject_login(&mut model, e)
+        }
+        self.repo().logins().update(model).await?;
+        result.map(|_| None)
+    }
+    async fn operator_issuers(&self) -> Outcome<Vec<String>> {
+        let issuers = self.auth().operator_issuers();
+        match (issuers.is_empty(), self.wallet()) {
+            (true, Some(wallet)) => Ok(vec![wallet.get_did().await?]),
+            _ => Ok(issuers)
+        }
+    }
+    // Operator credentials are signed with the authority's own wallet DID
+    async fn issue_operator(
+        &self,
+        payload: OperatorRequest,
+        actor: Option<String>
+    ) -> Outcome<GiveVC> {
+        let wallet = self.wallet().ok_or_else(|| {
+            Errors::not_active("Operator credentials need the authority wallet", None)
+        })?;
+        let did = wallet.get_did().await?;
+        let claims = self.auth().operator_claims(&payload, &did)?;
+        let vc = self.issuer().issue_cred(&claims, Some(did.as_str())).await?;
+        let event = AuditEvent::new(AuditAction::OperatorIssued, actor, Some(&payload.holder))
+            .with_details(json!({ "role": payload.role, "name": payload.name }));
+        self.record_audit(event).await?;
+        Ok(vc)
+    }
+}
@@ -21,13 +21,14 @@ use async_trait::async_trait;
 use ymir::data::entities::minions::Model;
 use ymir::errors::Outcome;
 
+use crate::services::repo::types::{MinionQuery, Page};
 use crate::services::repo::RepoTrait;
 
 #[async_trait]
 pub trait CoreMinionTrait: Send + Sync + 'static {
     fn repo(&self) -> Arc<dyn RepoTrait>;
-    async fn get_all(&self) -> Outcome<Vec<Model>> {
-        self.repo().minions().get_all(None, None).await
+    async fn get_all(&self, query: MinionQuery) -> Outcome<Page<Model>> {
+        self.repo().listings().minions(&query).await
     }
     async fn get_by_id(&self, id: String) -> Outcome<Model> {
         self.repo().minions().get_by_id(&id).await
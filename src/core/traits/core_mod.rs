@@ -21,7 +21,8 @@ use async_trait::async_trait;
 use ymir::core_traits::CoreWalletTrait;
 
 use super::{
-    CoreApproverTrait, CoreGatekeeperTrait, CoreIssuerTrait, CoreMinionTrait, CoreReactTrait,
+    CoreApproverTrait, CoreExpiryTrait, CoreGaiaTrait, CoreGatekeeperTrait, CoreIssuerTrait,
+    CoreLoginTrait, CoreMetricsTrait, CoreMinionTrait, CoreReactTrait, CoreVerificationTrait,
     CoreVerifierTrait
 };
 use crate::config::CoreConfigTrait;
@@ -35,6 +36,11 @@ pub trait CoreTrait:
     + CoreWalletTrait
     + CoreMinionTrait
     + CoreReactTrait
+    + CoreGaiaTrait
+    + CoreVerificationTrait
+    + CoreExpiryTrait
+    + CoreLoginTrait
+    + CoreMetricsTrait
     + Send
     + Sync
     + 'static
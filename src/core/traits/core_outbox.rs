@@ -0,0 +1,98 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::Value;
+use ymir::data::entities::recv_interaction;
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+use super::CoreVerifierTrait;
+use crate::data::entities::callback_outbox;
+use crate::services::approval::types::RequestEvent;
+use crate::services::outbox::types::CallbackStatus;
+use crate::services::outbox::OutboxTrait;
+
+// Minion callbacks and session webhooks go through a persistent outbox so a receiver being down
+// loses nothing
+#[async_trait]
+pub trait CoreOutboxTrait: CoreVerifierTrait {
+    fn outbox(&self) -> Arc<dyn OutboxTrait>;
+    async fn queue_callback(
+        &self,
+        int_model: &recv_interaction::Model,
+        body: Value
+    ) -> Outcome<callback_outbox::Model> {
+        let n_model = self.outbox().start(int_model, body);
+        self.repo().callbacks().create(n_model).await
+    }
+    async fn send_callback(
+        &self,
+        int_model: &recv_interaction::Model,
+        body: Value
+    ) -> Outcome<callback_outbox::Model> {
+        let model = self.queue_callback(int_model, body).await?;
+        self.deliver_callback(model).await
+    }
+    async fn deliver_callback(
+        &self,
+        mut model: callback_outbox::Model
+    ) -> Outcome<callback_outbox::Model> {
+        self.outbox().deliver(&mut model).await;
+        if model.status != CallbackStatus::Delivered.to_string() {
+            self.metrics().callback_failed(&model.status);
+        }
+        // Standalone session webhooks have no request history to record the failure in
+        let webhook = model.signature.is_some();
+        if model.status == CallbackStatus::Dead.to_string() && !webhook {
+            let reason = model.last_error.clone();
+            let event = RequestEvent::CallbackFailed;
+            self.repo().history().record(&model.request_id, &event, None, reason).await?;
+        }
+        self.repo().callbacks().update(model).await
+    }
+    async fn deliver_callbacks(&self) -> Outcome<()> {
+        let now = Utc::now().naive_utc();
+        for model in self.repo().callbacks().get_due(now, 100).await? {
+            if let Err(e) = self.deliver_callback(model).await {
+                e.log();
+            }
+        }
+        Ok(())
+    }
+    async fn get_callbacks(&self, id: String) -> Outcome<Vec<callback_outbox::Model>> {
+        self.repo().callbacks().get_by_request(&id).await
+    }
+    async fn redeliver_callback(
+        &self,
+        id: String,
+        callback_id: String
+    ) -> Outcome<callback_outbox::Model> {
+        let mut model = self.repo().callbacks().get_by_id(&callback_id).await?;
+        if model.request_id != id {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Callback '{}' does not belong to request '{}'", callback_id, id),
+                None
+            ));
+        }
+        self.outbox().reset(&mut model);
+        self.deliver_callback(model).await
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ymir::errors::Outcome;
+
+use super::CoreOutboxTrait;
+use crate::data::entities::verification_session;
+use crate::services::expiry::types::{SessionOrigin, SessionPhase};
+use crate::services::verification::types::{SessionRequest, SessionResponse};
+use crate::services::verification::VerificationTrait;
+
+#[async_trait]
+pub trait CoreVerificationTrait: CoreOutboxTrait {
+    fn verification(&self) -> Arc<dyn VerificationTrait>;
+    async fn create_session(
+        &self,
+        token: String,
+        payload: SessionRequest
+    ) -> Outcome<SessionResponse> {
+        self.verification().authorize(&token)?;
+        let n_model = self.verification().start(&payload)?;
+        let model = self.repo().sessions().create(n_model).await?;
+        let n_ver_model = self.verifier().start_vp(&model.id)?;
+        let ver_model = self.repo().verification().create(n_ver_model).await?;
+        self.open_deadline(&model.id, &SessionOrigin::Standalone, &SessionPhase::Verification)
+            .await?;
+        let uri = self.verification_uri(&ver_model).await?;
+        Ok(SessionResponse { id: model.id, uri })
+    }
+    async fn get_session(
+        &self,
+        token: String,
+        id: String
+    ) -> Outcome<verification_session::Model> {
+        self.verification().authorize(&token)?;
+        self.repo().sessions().get_by_id(&id).await
+    }
+    async fn is_standalone_session(&self, state: &str) -> Outcome<bool> {
+        let ver_model = self.repo().verification().get_by_state(state).await?;
+        Ok(self.repo().sessions().find_by_id(&ver_model.id).await?.is_some())
+    }
+    async fn verify_session(&self, state: String, vp_token: String) -> Outcome<Option<String>> {
+        let mut ver_model = self.active_verification(&state).await?;
+        let result = self.verify_presentation(&mut ver_model, &vp_token).await;
+        let mut model = self.repo().sessions().get_by_id(&ver_model.id).await?;
+
+        let result = result.and_then(|_| self.verification().complete(&mut model, &vp_token));
+        match &result {
+            Ok(_) => {
+                self.repo().verification().update(ver_model).await?;
+            }
+            Err(e) => self.verification().reject(&mut model, e)
+        }
+
+        let model = self.repo().sessions().update(model).await?;
+        if result.is_ok() {
+            if let Err(e) = self.notify_session(&model).await {
+                e.log();
+            }
+        }
+        result.map(|_| None)
+    }
+    async fn notify_session(&self, model: &verification_session::Model) -> Outcome<()> {
+        let Some(n_model) = self.verification().webhook(model)? else {
+            return Ok(());
+        };
+        let model = self.repo().callbacks().create(n_model).await?;
+        self.deliver_callback(model).await?;
+        Ok(())
+    }
+}
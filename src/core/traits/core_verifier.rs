@@ -18,26 +18,225 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use ymir::errors::Outcome;
+use serde_json::Value;
+use ymir::data::entities::{recv_verification, vc_request};
+use ymir::errors::{BadFormat, Errors, Outcome};
 use ymir::services::verifier::VerifierTrait;
-use ymir::types::vcs::VPDef;
+use ymir::utils::parse_to_value;
 
+use crate::data::entities::{response_key, verification_trust};
+use crate::services::approval::types::RequestEvent;
+use crate::services::encryption::types::ResponsePayload;
+use crate::services::encryption::ResponseEncryptionTrait;
+use crate::services::expiry::types::{SessionOrigin, SessionPhase};
+use crate::services::expiry::ExpiryTrait;
+use crate::services::gaia::types::GaiaVcType;
+use crate::services::gatekeeper::types::GrantCredential;
+use crate::services::jar::JarTrait;
+use crate::services::metrics::types::VerificationFailure;
+use crate::services::metrics::MetricsTrait;
+use crate::services::presentation::types::{DcqlQuery, VpDefinition};
+use crate::services::presentation::PresentationTrait;
 use crate::services::repo::RepoTrait;
+use crate::services::status::StatusTrait;
+use crate::services::trust::TrustTrait;
+use crate::utils::single_presentation;
 
 #[async_trait]
 pub trait CoreVerifierTrait: Send + Sync + 'static {
     fn verifier(&self) -> Arc<dyn VerifierTrait>;
+    fn presentation(&self) -> Arc<dyn PresentationTrait>;
+    fn trust(&self) -> Arc<dyn TrustTrait>;
+    fn status(&self) -> Arc<dyn StatusTrait>;
+    fn jar(&self) -> Option<Arc<dyn JarTrait>>;
+    fn encryption(&self) -> Option<Arc<dyn ResponseEncryptionTrait>>;
+    fn expiry(&self) -> Arc<dyn ExpiryTrait>;
     fn repo(&self) -> Arc<dyn RepoTrait>;
-    async fn get_vp_def(&self, state: String) -> Outcome<VPDef> {
-        let ver_model = self.repo().verification().get_by_state(&state).await?;
-        self.verifier().generate_vpd(&ver_model)
+    fn metrics(&self) -> Arc<dyn MetricsTrait>;
+    async fn open_deadline(
+        &self,
+        id: &str,
+        origin: &SessionOrigin,
+        phase: &SessionPhase
+    ) -> Outcome<()> {
+        let until = self.expiry().deadline(phase);
+        self.repo().deadlines().open(id, origin, phase, until).await?;
+        Ok(())
+    }
+    async fn ensure_active(&self, id: &str, phase: &SessionPhase) -> Outcome<()> {
+        match self.repo().deadlines().find_by_id(id).await? {
+            Some(model) => self.expiry().ensure_active(&model, phase),
+            None => Ok(())
+        }
+    }
+    async fn active_verification(&self, state: &str) -> Outcome<recv_verification::Model> {
+        let ver_model = self.repo().verification().get_by_state(state).await?;
+        self.ensure_active(&ver_model.id, &SessionPhase::Verification).await?;
+        Ok(ver_model)
+    }
+    async fn authorization_request(
+        &self,
+        ver_model: &recv_verification::Model
+    ) -> Outcome<String> {
+        let mut uri = self.verifier().generate_verification_uri(ver_model);
+        if self.presentation().uses_dcql() {
+            let vc_types = self.requested_vc_types(&ver_model.id).await?;
+            if let Some(use_case) = self.presentation().select(&vc_types) {
+                uri = self.presentation().with_dcql_query(&uri, &use_case)?;
+            }
+        }
+        let Some(encryption) = self.encryption() else {
+            return Ok(uri);
+        };
+        // The same key is published every time the request is fetched
+        let key = match self.repo().response_keys().find_by_id(&ver_model.id).await? {
+            Some(model) => model.key,
+            None => {
+                let key = encryption.generate_key()?;
+                let n_model = response_key::NewModel { id: ver_model.id.clone(), key };
+                self.repo().response_keys().create(n_model).await?.key
+            }
+        };
+        encryption.prepare(&ver_model.state, &key, &uri)
+    }
+    async fn verification_uri(&self, ver_model: &recv_verification::Model) -> Outcome<String> {
+        let uri = self.authorization_request(ver_model).await?;
+        match self.jar() {
+            Some(jar) => jar.request_reference(&uri, &ver_model.state),
+            None => Ok(uri)
+        }
+    }
+    async fn get_request_object(&self, state: String) -> Outcome<String> {
+        let jar = self
+            .jar()
+            .ok_or_else(|| Errors::not_active("Signed request objects are not enabled", None))?;
+        let ver_model = self.active_verification(&state).await?;
+        let uri = self.authorization_request(&ver_model).await?;
+        jar.sign_request(&uri).await
+    }
+    async fn open_response(&self, state: &str, payload: ResponsePayload) -> Outcome<String> {
+        match (self.encryption(), payload.response) {
+            (Some(encryption), Some(jwe)) => {
+                let ver_model = self.repo().verification().get_by_state(state).await?;
+                let key = self.repo().response_keys().get_by_id(&ver_model.id).await?;
+                single_presentation(&encryption.decrypt(&key.key, &jwe)?.vp_token)
+            }
+            (Some(_), None) => Err(Errors::security(
+                "Responses to this verifier must be encrypted (direct_post.jwt)",
+                None
+            )),
+            (None, _) => {
+                let vp_token = payload.vp_token.ok_or_else(|| {
+                    Errors::format(BadFormat::Received, "Missing field vp_token", None)
+                })?;
+                single_presentation(&Value::String(vp_token))
+            }
+        }
+    }
+    async fn get_vp_def(&self, state: String) -> Outcome<VpDefinition> {
+        let ver_model = self.active_verification(&state).await?;
+        let vc_types = self.requested_vc_types(&ver_model.id).await?;
+        match self.presentation().select(&vc_types) {
+            Some(use_case) => Ok(VpDefinition::Configured(
+                self.presentation().presentation_definition(&ver_model.id, &use_case)
+            )),
+            None => Ok(VpDefinition::Default(self.verifier().generate_vpd(&ver_model)?))
+        }
+    }
+    async fn get_dcql(&self, state: String) -> Outcome<DcqlQuery> {
+        let ver_model = self.active_verification(&state).await?;
+        let vc_types = self.requested_vc_types(&ver_model.id).await?;
+        let use_case = self.presentation().select(&vc_types).ok_or_else(|| {
+            let msg = format!("No DCQL query configured for '{}'", vc_types.join(", "));
+            Errors::not_active(msg, None)
+        })?;
+        Ok(self.presentation().dcql_query(&use_case))
+    }
+    // Types the presentation is asked for; GNAP grants count every type they request
+    async fn requested_vc_types(&self, id: &str) -> Outcome<Vec<String>> {
+        if let Some(session) = self.repo().sessions().find_by_id(id).await? {
+            return Ok(vec![session.vc_type]);
+        }
+        if let Some(login) = self.repo().logins().find_by_id(id).await? {
+            return Ok(vec![login.vc_type]);
+        }
+        if self.repo().gaia().find_by_id(id).await?.is_some() {
+            return Ok(vec![GaiaVcType::LegalParticipant.to_string()]);
+        }
+        let req_model = self.repo().request().get_by_id(id).await?;
+        self.grant_vc_types(&req_model).await
+    }
+    async fn grant_vc_types(&self, req_model: &vc_request::Model) -> Outcome<Vec<String>> {
+        let grant = self.repo().grants().find_by_id(&req_model.id).await?;
+        let stored = grant.as_ref().and_then(|grant| grant.credentials.as_ref());
+        GrantCredential::vc_types(&req_model.vc_type, stored)
+    }
+    async fn verify_presentation(
+        &self,
+        ver_model: &mut recv_verification::Model,
+        vp_token: &str
+    ) -> Outcome<()> {
+        let failed = |reason: VerificationFailure| {
+            let metrics = self.metrics();
+            move |_: &Errors| metrics.verification_failed(&reason)
+        };
+        self.verifier()
+            .verify_all(ver_model, vp_token)
+            .await
+            .inspect_err(failed(VerificationFailure::Proof))?;
+        self.evaluate_trust(&ver_model.id, vp_token)
+            .await
+            .inspect_err(failed(VerificationFailure::Trust))?;
+        self.status().check(vp_token).await.inspect_err(failed(VerificationFailure::Status))?;
+        let vc_types = self.requested_vc_types(&ver_model.id).await?;
+        if let Some(use_case) = self.presentation().select(&vc_types) {
+            self.presentation()
+                .validate(&use_case, vp_token)
+                .inspect_err(failed(VerificationFailure::Query))?;
+        }
+        self.repo().deadlines().close(&ver_model.id, &SessionPhase::Verification).await?;
+        self.repo().response_keys().delete(&ver_model.id).await
+    }
+    async fn evaluate_trust(&self, id: &str, vp_token: &str) -> Outcome<()> {
+        let evaluation = self.trust().evaluate(vp_token)?;
+        self.repo()
+            .trust()
+            .save(verification_trust::NewModel {
+                id: id.to_string(),
+                passed: evaluation.passed,
+                results: parse_to_value(&evaluation.results)?
+            })
+            .await?;
+
+        if !evaluation.passed {
+            let failures = evaluation
+                .failures()
+                .iter()
+                .map(|r| format!("{} ({}): {}", r.rule, r.credential, r.detail))
+                .collect::<Vec<String>>()
+                .join("; ");
+            return Err(Errors::unauthorized(
+                format!("Presentation rejected by trust policy: {}", failures),
+                None
+            ));
+        }
+        Ok(())
     }
     async fn verify(&self, state: String, vp_token: String) -> Outcome<Option<String>> {
-        let mut ver_model = self.repo().verification().get_by_state(&state).await?;
-        let result = self.verifier().verify_all(&mut ver_model, &vp_token).await;
+        let mut ver_model = self.active_verification(&state).await?;
+        let result = self.verify_presentation(&mut ver_model, &vp_token).await;
         let int_model = self.repo().interaction().get_by_id(&ver_model.id).await?;
         result?;
         self.repo().verification().update(ver_model).await?;
-        self.verifier().end_verification(&int_model).await
+        let redirect = self.verifier().end_verification(&int_model).await?;
+        self.repo().history().record(&int_model.id, &RequestEvent::Verified, None, None).await?;
+
+        // Hosted interaction pages poll this to send the browser on to the finish URI
+        if let Some(mut start_model) = self.repo().starts().find_by_id(&int_model.id).await? {
+            start_model.status = "Verified".to_string();
+            start_model.finish_uri = redirect.clone();
+            self.repo().starts().update(start_model).await?;
+        }
+        Ok(redirect)
     }
 }
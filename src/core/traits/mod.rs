@@ -16,18 +16,31 @@
  */
 
 mod core_approver;
+mod core_audit;
+mod core_expiry;
 mod core_gaia;
 mod core_gatekeeper;
 mod core_issuer;
+mod core_login;
+mod core_metrics;
 mod core_minion;
 mod core_mod;
+mod core_outbox;
 mod core_react;
+mod core_verification;
 mod core_verifier;
 
 pub use core_approver::CoreApproverTrait;
+pub use core_audit::CoreAuditTrait;
+pub use core_expiry::CoreExpiryTrait;
+pub use core_gaia::CoreGaiaTrait;
 pub use core_gatekeeper::CoreGatekeeperTrait;
 pub use core_issuer::CoreIssuerTrait;
+pub use core_login::CoreLoginTrait;
+pub use core_metrics::CoreMetricsTrait;
 pub use core_minion::CoreMinionTrait;
 pub use core_mod::CoreTrait;
+pub use core_outbox::CoreOutboxTrait;
 pub use core_react::CoreReactTrait;
+pub use core_verification::CoreVerificationTrait;
 pub use core_verifier::CoreVerifierTrait;
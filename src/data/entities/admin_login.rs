@@ -0,0 +1,65 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "admin_login")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub vc_type: String,
+    pub status: String,
+    pub subject: Option<String>,
+    pub role: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime,
+    pub ended_at: Option<DateTime>,
+    // Hash of the nonce handed to whoever started the login, required to poll it
+    pub nonce_hash: Option<String>
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub id: String,
+    pub vc_type: String,
+    pub nonce_hash: String
+}
+
+impl From<NewModel> for ActiveModel {
+    fn from(model: NewModel) -> Self {
+        ActiveModel {
+            id: ActiveValue::Set(model.id),
+            vc_type: ActiveValue::Set(model.vc_type),
+            status: ActiveValue::Set("Pending".to_string()),
+            subject: ActiveValue::Set(None),
+            role: ActiveValue::Set(None),
+            error: ActiveValue::Set(None),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ended_at: ActiveValue::Set(None),
+            nonce_hash: ActiveValue::Set(Some(model.nonce_hash))
+        }
+    }
+}
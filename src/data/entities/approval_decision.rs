@@ -0,0 +1,54 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "approval_decision")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub outcome: String,
+    pub rule: Option<String>,
+    pub decided_at: DateTime
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub id: String,
+    pub outcome: String,
+    pub rule: Option<String>
+}
+
+impl From<NewModel> for ActiveModel {
+    fn from(model: NewModel) -> Self {
+        ActiveModel {
+            id: ActiveValue::Set(model.id),
+            outcome: ActiveValue::Set(model.outcome),
+            rule: ActiveValue::Set(model.rule),
+            decided_at: ActiveValue::Set(Utc::now().naive_utc())
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+// Append-only: rows are never updated, each one hashes the previous one
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "audit_entry")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub seq: i64,
+    pub action: String,
+    pub actor: Option<String>,
+    pub subject: Option<String>,
+    // Kept as text so the hashed bytes survive the database
+    #[sea_orm(column_type = "Text", nullable)]
+    pub details: Option<String>,
+    pub created_at: DateTime,
+    pub prev_hash: String,
+    pub hash: String,
+    #[sea_orm(column_type = "Text")]
+    pub signature: String
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// Every field is hashed, so the creation time is set by the audit service
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub seq: i64,
+    pub action: String,
+    pub actor: Option<String>,
+    pub subject: Option<String>,
+    pub details: Option<String>,
+    pub created_at: DateTime,
+    pub prev_hash: String,
+    pub hash: String,
+    pub signature: String
+}
+
+impl From<NewModel> for ActiveModel {
+    fn from(model: NewModel) -> Self {
+        ActiveModel {
+            seq: ActiveValue::Set(model.seq),
+            action: ActiveValue::Set(model.action),
+            actor: ActiveValue::Set(model.actor),
+            subject: ActiveValue::Set(model.subject),
+            details: ActiveValue::Set(model.details),
+            created_at: ActiveValue::Set(model.created_at),
+            prev_hash: ActiveValue::Set(model.prev_hash),
+            hash: ActiveValue::Set(model.hash),
+            signature: ActiveValue::Set(model.signature)
+        }
+    }
+}
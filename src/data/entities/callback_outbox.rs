@@ -0,0 +1,72 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "callback_outbox")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub request_id: String,
+    pub uri: String,
+    pub body: Json,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime,
+    pub last_error: Option<String>,
+    pub created_at: DateTime,
+    pub delivered_at: Option<DateTime>,
+    // Sent as x-heimdall-signature, set for verification webhooks
+    pub signature: Option<String>
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub id: String,
+    pub request_id: String,
+    pub uri: String,
+    pub body: Json,
+    pub signature: Option<String>
+}
+
+impl From<NewModel> for ActiveModel {
+    fn from(model: NewModel) -> Self {
+        let now = Utc::now().naive_utc();
+        ActiveModel {
+            id: ActiveValue::Set(model.id),
+            request_id: ActiveValue::Set(model.request_id),
+            uri: ActiveValue::Set(model.uri),
+            body: ActiveValue::Set(model.body),
+            status: ActiveValue::Set("Pending".to_string()),
+            attempts: ActiveValue::Set(0),
+            next_attempt_at: ActiveValue::Set(now),
+            last_error: ActiveValue::Set(None),
+            created_at: ActiveValue::Set(now),
+            delivered_at: ActiveValue::Set(None),
+            signature: ActiveValue::Set(model.signature)
+        }
+    }
+}
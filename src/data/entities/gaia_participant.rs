@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "gaia_participant")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub legal_name: String,
+    pub headquarter_address: Json,
+    pub legal_address: Json,
+    pub holder_did: Option<String>,
+    pub registration_number: Option<Json>,
+    pub credentials: Json,
+    pub status: String,
+    pub created_at: DateTime,
+    // SHA-256 of the terms and conditions the participant accepted, and when
+    pub terms_hash: Option<String>,
+    pub terms_accepted_at: Option<DateTime>,
+    pub reviewer: Option<String>
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub id: String,
+    pub legal_name: String,
+    pub headquarter_address: Json,
+    pub legal_address: Json,
+    pub terms_hash: String
+}
+
+impl From<NewModel> for ActiveModel {
+    fn from(model: NewModel) -> Self {
+        ActiveModel {
+            id: ActiveValue::Set(model.id),
+            legal_name: ActiveValue::Set(model.legal_name),
+            headquarter_address: ActiveValue::Set(model.headquarter_address),
+            legal_address: ActiveValue::Set(model.legal_address),
+            holder_did: ActiveValue::Set(None),
+            registration_number: ActiveValue::Set(None),
+            credentials: ActiveValue::Set(Json::Array(vec![])),
+            status: ActiveValue::Set("Pending".to_string()),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            terms_hash: ActiveValue::Set(Some(model.terms_hash)),
+            terms_accepted_at: ActiveValue::Set(Some(Utc::now().naive_utc())),
+            reviewer: ActiveValue::Set(None)
+        }
+    }
+}
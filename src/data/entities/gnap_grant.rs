@@ -0,0 +1,63 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "gnap_grant")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub access: Json,
+    // Client key the grant is bound to, when the request was proofed
+    pub key: Option<Json>,
+    // Credentials requested through the grant, see `GrantCredential`
+    pub credentials: Option<Json>,
+    // Address the grant was requested from, caps pending requests of clients without a class
+    pub client_ip: Option<String>,
+    pub created_at: DateTime
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub id: String,
+    pub access: Json,
+    pub key: Option<Json>,
+    pub credentials: Option<Json>,
+    pub client_ip: Option<String>
+}
+
+impl From<NewModel> for ActiveModel {
+    fn from(model: NewModel) -> Self {
+        ActiveModel {
+            id: ActiveValue::Set(model.id),
+            access: ActiveValue::Set(model.access),
+            key: ActiveValue::Set(model.key),
+            credentials: ActiveValue::Set(model.credentials),
+            client_ip: ActiveValue::Set(model.client_ip),
+            created_at: ActiveValue::Set(Utc::now().naive_utc())
+        }
+    }
+}
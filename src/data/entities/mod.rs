@@ -0,0 +1,32 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+pub mod gaia_participant;
+pub mod verification_trust;
+pub mod verification_session;
+pub mod session_deadline;
+pub mod interaction_start;
+pub mod gnap_grant;
+pub mod gnap_token;
+pub mod resource_server;
+pub mod approval_decision;
+pub mod approval_vote;
+pub mod request_event;
+pub mod callback_outbox;
+pub mod admin_login;
+pub mod audit_entry;
+pub mod response_key;
@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::Utc;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "verification_session")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub vc_type: String,
+    pub webhook: Option<String>,
+    pub status: String,
+    pub holder: Option<String>,
+    pub claims: Option<Json>,
+    pub error: Option<String>,
+    pub created_at: DateTime,
+    pub ended_at: Option<DateTime>
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub id: String,
+    pub vc_type: String,
+    pub webhook: Option<String>
+}
+
+impl From<NewModel> for ActiveModel {
+    fn from(model: NewModel) -> Self {
+        ActiveModel {
+            id: ActiveValue::Set(model.id),
+            vc_type: ActiveValue::Set(model.vc_type),
+            webhook: ActiveValue::Set(model.webhook),
+            status: ActiveValue::Set("Pending".to_string()),
+            holder: ActiveValue::Set(None),
+            claims: ActiveValue::Set(None),
+            error: ActiveValue::Set(None),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ended_at: ActiveValue::Set(None)
+        }
+    }
+}
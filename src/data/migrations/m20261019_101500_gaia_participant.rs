@@ -0,0 +1,65 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str { "m20261019_101500_gaia_participant" }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GaiaParticipant::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(GaiaParticipant::Id).string().not_null().primary_key())
+                    .col(ColumnDef::new(GaiaParticipant::LegalName).string().not_null())
+                    .col(ColumnDef::new(GaiaParticipant::HeadquarterAddress).json().not_null())
+                    .col(ColumnDef::new(GaiaParticipant::LegalAddress).json().not_null())
+                    .col(ColumnDef::new(GaiaParticipant::HolderDid).string())
+                    .col(ColumnDef::new(GaiaParticipant::RegistrationNumber).json())
+                    .col(ColumnDef::new(GaiaParticipant::Credentials).json().not_null())
+                    .col(ColumnDef::new(GaiaParticipant::Status).string().not_null())
+                    .col(ColumnDef::new(GaiaParticipant::CreatedAt).date_time().not_null())
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(GaiaParticipant::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GaiaParticipant {
+    Table,
+    Id,
+    LegalName,
+    HeadquarterAddress,
+    LegalAddress,
+    HolderDid,
+    RegistrationNumber,
+    Credentials,
+    Status,
+    CreatedAt
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str { "m20261019_231000_approval_vote" }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ApprovalVote::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(ApprovalVote::Id).string().not_null().primary_key())
+                    .col(ColumnDef::new(ApprovalVote::RequestId).string().not_null())
+                    .col(ColumnDef::new(ApprovalVote::Approver).string().not_null())
+                    .col(ColumnDef::new(ApprovalVote::Approve).boolean().not_null())
+                    .col(ColumnDef::new(ApprovalVote::VotedAt).date_time().not_null())
+                    .index(
+                        Index::create()
+                            .name("idx_approval_vote_request_approver")
+                            .col(ApprovalVote::RequestId)
+                            .col(ApprovalVote::Approver)
+                            .unique()
+                    )
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(ApprovalVote::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ApprovalVote {
+    Table,
+    Id,
+    RequestId,
+    Approver,
+    Approve,
+    VotedAt
+}
@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str { "m20261019_233000_callback_outbox" }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CallbackOutbox::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CallbackOutbox::Id).string().not_null().primary_key())
+                    .col(ColumnDef::new(CallbackOutbox::RequestId).string().not_null())
+                    .col(ColumnDef::new(CallbackOutbox::Uri).string().not_null())
+                    .col(ColumnDef::new(CallbackOutbox::Body).json().not_null())
+                    .col(ColumnDef::new(CallbackOutbox::Status).string().not_null())
+                    .col(ColumnDef::new(CallbackOutbox::Attempts).integer().not_null())
+                    .col(ColumnDef::new(CallbackOutbox::NextAttemptAt).date_time().not_null())
+                    .col(ColumnDef::new(CallbackOutbox::LastError).string())
+                    .col(ColumnDef::new(CallbackOutbox::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(CallbackOutbox::DeliveredAt).date_time())
+                    .index(
+                        Index::create()
+                            .name("idx_callback_outbox_status_next_attempt")
+                            .col(CallbackOutbox::Status)
+                            .col(CallbackOutbox::NextAttemptAt)
+                    )
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(CallbackOutbox::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CallbackOutbox {
+    Table,
+    Id,
+    RequestId,
+    Uri,
+    Body,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastError,
+    CreatedAt,
+    DeliveredAt
+}
@@ -0,0 +1,65 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str { "m20261019_235000_audit_entry" }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditEntry::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AuditEntry::Seq).big_integer().not_null().primary_key())
+                    .col(ColumnDef::new(AuditEntry::Action).string().not_null())
+                    .col(ColumnDef::new(AuditEntry::Actor).string())
+                    .col(ColumnDef::new(AuditEntry::Subject).string())
+                    .col(ColumnDef::new(AuditEntry::Details).text())
+                    .col(ColumnDef::new(AuditEntry::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(AuditEntry::PrevHash).string().not_null())
+                    .col(ColumnDef::new(AuditEntry::Hash).string().not_null())
+                    .col(ColumnDef::new(AuditEntry::Signature).text().not_null())
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(AuditEntry::Table).to_owned()).await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditEntry {
+    Table,
+    Seq,
+    Action,
+    Actor,
+    Subject,
+    Details,
+    CreatedAt,
+    PrevHash,
+    Hash,
+    Signature
+}
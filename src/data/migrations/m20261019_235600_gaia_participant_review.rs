@@ -0,0 +1,69 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str { "m20261019_235600_gaia_participant_review" }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One column per statement, SQLite cannot alter several at once
+        for column in [
+            ColumnDef::new(GaiaParticipant::TermsHash).string().null().to_owned(),
+            ColumnDef::new(GaiaParticipant::TermsAcceptedAt).date_time().null().to_owned(),
+            ColumnDef::new(GaiaParticipant::Reviewer).string().null().to_owned()
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(GaiaParticipant::Table)
+                        .add_column_if_not_exists(column)
+                        .to_owned()
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            GaiaParticipant::TermsHash,
+            GaiaParticipant::TermsAcceptedAt,
+            GaiaParticipant::Reviewer
+        ] {
+            manager
+                .alter_table(
+                    Table::alter().table(GaiaParticipant::Table).drop_column(column).to_owned()
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum GaiaParticipant {
+    Table,
+    TermsHash,
+    TermsAcceptedAt,
+    Reviewer
+}
@@ -0,0 +1,81 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use sea_orm::ConnectionTrait;
+use sea_orm_migration::prelude::*;
+use sha2::{Digest, Sha256};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str { "m20261019_235900_gnap_token_hash" }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GnapToken::Table)
+                    .rename_column(GnapToken::Value, GnapToken::ValueHash)
+                    .to_owned()
+            )
+            .await?;
+
+        // Tokens already issued stay valid, only their values are replaced by their hashes
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let select = Query::select()
+            .columns([GnapToken::Id, GnapToken::ValueHash])
+            .from(GnapToken::Table)
+            .to_owned();
+        for row in db.query_all(backend.build(&select)).await? {
+            let id: String = row.try_get("", "id")?;
+            let value: String = row.try_get("", "value_hash")?;
+            let hash: String =
+                Sha256::digest(value.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect();
+            let update = Query::update()
+                .table(GnapToken::Table)
+                .value(GnapToken::ValueHash, hash)
+                .and_where(Expr::col(GnapToken::Id).eq(id))
+                .to_owned();
+            db.execute(backend.build(&update)).await?;
+        }
+        Ok(())
+    }
+
+    // Hashes cannot be reversed, tokens issued in between stop working
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(GnapToken::Table)
+                    .rename_column(GnapToken::ValueHash, GnapToken::Value)
+                    .to_owned()
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum GnapToken {
+    Table,
+    Id,
+    Value,
+    ValueHash
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+pub mod m20261019_101500_gaia_participant;
+pub mod m20261019_140000_verification_trust;
+pub mod m20261019_160000_verification_session;
+pub mod m20261019_180000_session_deadline;
+pub mod m20261019_200000_interaction_start;
+pub mod m20261019_220000_gnap_grant;
+pub mod m20261019_221000_gnap_token;
+pub mod m20261019_222000_resource_server;
+pub mod m20261019_223000_gnap_grant_key;
+pub mod m20261019_224000_gnap_grant_credentials;
+pub mod m20261019_230000_approval_decision;
+pub mod m20261019_231000_approval_vote;
+pub mod m20261019_232000_request_event;
+pub mod m20261019_233000_callback_outbox;
+pub mod m20261019_234000_admin_login;
+pub mod m20261019_235000_audit_entry;
+pub mod m20261019_235500_gnap_grant_client_ip;
+pub mod m20261019_235600_gaia_participant_review;
+pub mod m20261019_235700_admin_login_nonce;
+pub mod m20261019_235800_callback_outbox_signature;
+pub mod m20261019_235900_gnap_token_hash;
+pub mod m20261019_235950_response_key;
@@ -21,6 +21,20 @@ use ymir::data::migrations::{
     m20250403_094651_recv_verification, m20250403_094651_vc_request
 };
 
+use super::migrations::{
+    m20261019_101500_gaia_participant, m20261019_140000_verification_trust,
+    m20261019_160000_verification_session, m20261019_180000_session_deadline,
+    m20261019_200000_interaction_start, m20261019_220000_gnap_grant, m20261019_221000_gnap_token,
+    m20261019_222000_resource_server, m20261019_223000_gnap_grant_key,
+    m20261019_224000_gnap_grant_credentials, m20261019_230000_approval_decision,
+    m20261019_231000_approval_vote, m20261019_232000_request_event,
+    m20261019_233000_callback_outbox, m20261019_234000_admin_login,
+    m20261019_235000_audit_entry, m20261019_235500_gnap_grant_client_ip,
+    m20261019_235600_gaia_participant_review, m20261019_235700_admin_login_nonce,
+    m20261019_235800_callback_outbox_signature, m20261019_235900_gnap_token_hash,
+    m20261019_235950_response_key
+};
+
 pub struct Migrator;
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
@@ -31,6 +45,28 @@ impl MigratorTrait for Migrator {
             Box::new(m20250403_094651_recv_verification::Migration),
             Box::new(m20250403_094651_issuing::Migration),
             Box::new(m20250403_094651_minions::Migration),
+            Box::new(m20261019_101500_gaia_participant::Migration),
+            Box::new(m20261019_140000_verification_trust::Migration),
+            Box::new(m20261019_160000_verification_session::Migration),
+            Box::new(m20261019_180000_session_deadline::Migration),
+            Box::new(m20261019_200000_interaction_start::Migration),
+            Box::new(m20261019_220000_gnap_grant::Migration),
+            Box::new(m20261019_221000_gnap_token::Migration),
+            Box::new(m20261019_222000_resource_server::Migration),
+            Box::new(m20261019_223000_gnap_grant_key::Migration),
+            Box::new(m20261019_224000_gnap_grant_credentials::Migration),
+            Box::new(m20261019_230000_approval_decision::Migration),
+            Box::new(m20261019_231000_approval_vote::Migration),
+            Box::new(m20261019_232000_request_event::Migration),
+            Box::new(m20261019_233000_callback_outbox::Migration),
+            Box::new(m20261019_234000_admin_login::Migration),
+            Box::new(m20261019_235000_audit_entry::Migration),
+            Box::new(m20261019_235500_gnap_grant_client_ip::Migration),
+            Box::new(m20261019_235600_gaia_participant_review::Migration),
+            Box::new(m20261019_235700_admin_login_nonce::Migration),
+            Box::new(m20261019_235800_callback_outbox_signature::Migration),
+            Box::new(m20261019_235900_gnap_token_hash::Migration),
+            Box::new(m20261019_235950_response_key::Migration),
         ]
     }
 }
@@ -15,6 +15,8 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod entities;
+mod migrations;
 mod migrator;
 
 pub use migrator::Migrator;
@@ -0,0 +1,104 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::http::Method;
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::Router;
+use ymir::errors::{AppResult, Outcome};
+use ymir::utils::extract_bearer_token;
+
+use crate::core::traits::CoreAuditTrait;
+use crate::services::audit::types::{AuditAction, AuditEvent};
+use crate::services::auth::types::RouteGroup;
+use crate::services::auth::AuthTrait;
+
+pub struct AdminGuard {
+    auth: Arc<dyn AuthTrait>,
+    audit: Arc<dyn CoreAuditTrait>,
+    api_path: String
+}
+
+impl AdminGuard {
+    pub fn new(auth: Arc<dyn AuthTrait>, audit: Arc<dyn CoreAuditTrait>, api_path: String) -> Self {
+        Self { auth, audit, api_path }
+    }
+
+    pub fn layer(self, router: Router) -> Router {
+        router.layer(middleware::from_fn_with_state(Arc::new(self), Self::check))
+    }
+
+    async fn check(
+        State(guard): State<Arc<AdminGuard>>,
+        mut req: Request,
+        next: Next
+    ) -> AppResult<Response> {
+        let group = req.uri().path().strip_prefix(&guard.api_path).and_then(RouteGroup::from_path);
+        let Some(group) = group else {
+            return Ok(next.run(req).await);
+        };
+
+        let mut actor = None;
+        if guard.auth.is_enabled() {
+            let token = Self::token(&req, &group)?;
+            let principal = guard.auth.authenticate(&token).await?;
+            let role = group.required_role(req.method(), req.uri().path());
+            guard.auth.authorize(&principal, &role)?;
+            actor = Some(principal.subject.clone());
+            req.extensions_mut().insert(principal);
+        }
+
+        let action = Self::audited(&group, req.method(), req.uri().path());
+        let path = req.uri().path().to_string();
+        let response = next.run(req).await;
+        if let Some(action) = action.filter(|_| response.status().is_success()) {
+            let event = AuditEvent::new(action, actor, Some(&path));
+            guard.audit.record_audit(event).await?;
+        }
+        Ok(response)
+    }
+
+    // Wallet changes go through ymir's router, so they are audited here
+    fn audited(group: &RouteGroup, method: &Method, path: &str) -> Option<AuditAction> {
+        if *group != RouteGroup::Wallet || matches!(*method, Method::GET | Method::HEAD) {
+            return None;
+        }
+        match path.ends_with("/wallet/key") {
+            true => Some(AuditAction::KeyRotated),
+            false => Some(AuditAction::WalletChanged)
+        }
+    }
+
+    fn token(req: &Request, group: &RouteGroup) -> Outcome<String> {
+        // EventSource cannot send headers, so the SSE stream also takes the token in the query
+        if *group == RouteGroup::React {
+            let token = req
+                .uri()
+                .query()
+                .and_then(|query| serde_urlencoded::from_str::<HashMap<String, String>>(query).ok())
+                .and_then(|mut params| params.remove("access_token"));
+            if let Some(token) = token {
+                return Ok(token);
+            }
+        }
+        extract_bearer_token(req.headers().clone())
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use ymir::errors::AppResult;
+
+use crate::core::traits::CoreAuditTrait;
+use crate::data::entities::audit_entry;
+use crate::services::audit::types::{AuditQuery, AuditReport};
+
+pub struct AuditRouter {
+    audit: Arc<dyn CoreAuditTrait>
+}
+
+impl AuditRouter {
+    pub fn new(audit: Arc<dyn CoreAuditTrait>) -> Self { Self { audit } }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/", get(Self::export))
+            .route("/verify", get(Self::verify))
+            .with_state(self.audit)
+    }
+
+    async fn export(
+        State(audit): State<Arc<dyn CoreAuditTrait>>,
+        Query(query): Query<AuditQuery>
+    ) -> AppResult<Json<Vec<audit_entry::Model>>> {
+        Ok(Json(audit.get_audit(query).await?))
+    }
+
+    async fn verify(
+        State(audit): State<Arc<dyn CoreAuditTrait>>
+    ) -> AppResult<Json<AuditReport>> {
+        Ok(Json(audit.verify_audit().await?))
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use ymir::errors::AppResult;
+use ymir::types::issuing::GiveVC;
+use ymir::utils::extract_payload;
+
+use crate::core::traits::CoreLoginTrait;
+use crate::services::auth::types::{AdminPrincipal, LoginPoll, LoginResponse, OperatorRequest};
+
+pub struct AuthRouter {
+    login: Arc<dyn CoreLoginTrait>
+}
+
+impl AuthRouter {
+    pub fn new(login: Arc<dyn CoreLoginTrait>) -> Self { Self { login } }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/login", post(Self::start_login))
+            .route("/login/{id}", get(Self::poll_login))
+            .route("/operators", post(Self::issue_operator))
+            .with_state(self.login)
+    }
+
+    async fn start_login(
+        State(login): State<Arc<dyn CoreLoginTrait>>
+    ) -> AppResult<Json<LoginResponse>> {
+        Ok(Json(login.start_login().await?))
+    }
+
+    async fn poll_login(
+        State(login): State<Arc<dyn CoreLoginTrait>>,
+        Path(id): Path<String>,
+        headers: HeaderMap
+    ) -> AppResult<Json<LoginPoll>> {
+        let nonce = headers.get("x-login-nonce").and_then(|h| h.to_str().ok()).map(str::to_string);
+        Ok(Json(login.poll_login(id, nonce).await?))
+    }
+
+    async fn issue_operator(
+        State(login): State<Arc<dyn CoreLoginTrait>>,
+        principal: Option<Extension<AdminPrincipal>>,
+        payload: Result<Json<OperatorRequest>, JsonRejection>
+    ) -> AppResult<Json<GiveVC>> {
+        let payload = extract_payload(payload)?;
+        let actor = principal.map(|Extension(principal)| principal.subject);
+        Ok(Json(login.issue_operator(payload, actor).await?))
+    }
+}
@@ -0,0 +1,57 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::Router;
+use tracing::warn;
+
+use crate::config::proofing::{ProofingConfig, ProofingConfigTrait};
+
+// Client certificates arrive in a plain header, so only the TLS terminating proxy may set it
+pub struct ClientCertFilter {
+    config: ProofingConfig
+}
+
+impl ClientCertFilter {
+    pub fn new(config: ProofingConfig) -> Self { Self { config } }
+
+    pub fn layer(self, router: Router) -> Router {
+        router.layer(middleware::from_fn_with_state(Arc::new(self), Self::filter))
+    }
+
+    async fn filter(
+        State(filter): State<Arc<ClientCertFilter>>,
+        mut req: Request,
+        next: Next
+    ) -> Response {
+        let peer = req.extensions().get::<ConnectInfo<SocketAddr>>().map(|info| info.0.ip());
+        let trusted = peer.is_some_and(|ip| filter.is_trusted_proxy(ip));
+        if !trusted && req.headers_mut().remove(filter.get_mtls_header()).is_some() {
+            warn!("Dropped a client certificate header sent by {:?}", peer);
+        }
+        next.run(req).await
+    }
+}
+
+impl ProofingConfigTrait for ClientCertFilter {
+    fn proofing_config(&self) -> &ProofingConfig { &self.config }
+}
@@ -18,20 +18,32 @@
 use std::sync::Arc;
 
 use axum::extract::Request;
-use axum::http::StatusCode;
+use axum::http::{HeaderValue, StatusCode};
 use axum::response::IntoResponse;
 use axum::Router;
-use tower_http::cors::CorsLayer;
+use tower_http::cors::{Any, CorsLayer};
+use tower_http::services::ServeDir;
 use tower_http::trace::{DefaultOnResponse, TraceLayer};
-use tracing::{error, info, Level};
+use tracing::{error, info, warn, Level};
 use uuid::Uuid;
 use ymir::http::{HealthRouter, OpenapiRouter, WalletRouter};
 
-use crate::core::traits::CoreTrait;
+use crate::config::auth::AuthConfigTrait;
+use crate::config::metrics::MetricsConfigTrait;
+use crate::config::proofing::ProofingConfigTrait;
+use crate::config::rate_limit::RateLimitConfigTrait;
+use crate::config::role::{AuthorityRole, RoleConfigTrait};
+use crate::core::traits::{
+    CoreGaiaTrait, CoreLoginTrait, CoreTrait, CoreVerificationTrait, CoreVerifierTrait
+};
 use crate::http::builder::RouterBuilder;
 use crate::http::{
-    ApproverRouter, GateKeeperRouter, IssuerRouter, MinionRouter, ReactRouter, VerifierRouter
+    AdminGuard, ApproverRouter, AuditRouter, AuthRouter, ClientCertFilter, GaiaRouter,
+    GateKeeperRouter, IssuerRouter, MetricsRouter, MinionRouter, RateLimiter, ReactRouter,
+    RequestMetrics, VerificationRouter, VerifierRouter
 };
+use crate::services::rate_limit::RateLimitService;
+use crate::utils::join_trace;
 
 pub struct RainbowAuthorityRouter {
     core: Arc<dyn CoreTrait>,
@@ -50,10 +62,31 @@ impl RainbowAuthorityRouter {
             false => None
         };
 
+        let is_gaia = matches!(self.core.config().get_role(), AuthorityRole::GaiaAuthority);
+        let gaia: Option<Arc<dyn CoreGaiaTrait>> =
+            if is_gaia { Some(self.core.clone()) } else { None };
+
+        let sessions: Option<Arc<dyn CoreVerificationTrait>> =
+            match self.core.config().is_verification_active() {
+                true => Some(self.core.clone()),
+                false => None
+            };
+
+        let logins: Option<Arc<dyn CoreLoginTrait>> =
+            match self.core.config().is_wallet_login_active() {
+                true => Some(self.core.clone()),
+                false => None
+            };
+
         let mut router = RouterBuilder::new()
             .gatekeeper(GateKeeperRouter::new(self.core.clone()))
             .issuer(IssuerRouter::new(self.core.clone()))
-            .verifier(VerifierRouter::new(self.core.clone()))
+            .verifier(VerifierRouter::new(
+                self.core.clone(),
+                gaia.clone(),
+                sessions.clone(),
+                logins.clone()
+            ))
             .approver(ApproverRouter::new(self.core.clone()))
             .minion(MinionRouter::new(self.core.clone()))
             .wallet(wallet)
@@ -69,19 +102,86 @@ impl RainbowAuthorityRouter {
             router = router.nest(&mount_path, sse_router);
         }
 
+        if let Some(gaia) = gaia {
+            let gaia_router = GaiaRouter::new(gaia).router();
+            let mount_path = format!("{}/gaia", self.core.config().get_api_version());
+            router = router.nest(&mount_path, gaia_router);
+        }
+
+        if let Some(dir) = self.core.config().get_local_status_dir() {
+            let mount_path = format!("{}/status-lists", self.core.config().get_api_version());
+            router = router.nest_service(&mount_path, ServeDir::new(dir));
+        }
+
+        if let Some(sessions) = sessions {
+            let verification_router = VerificationRouter::new(sessions).router();
+            let mount_path = format!("{}/verification", self.core.config().get_api_version());
+            router = router.nest(&mount_path, verification_router);
+        }
+
+        if let Some(logins) = logins {
+            let auth_router = AuthRouter::new(logins).router();
+            let mount_path = format!("{}/auth", self.core.config().get_api_version());
+            router = router.nest(&mount_path, auth_router);
+        }
+
+        let audit_router = AuditRouter::new(self.core.clone()).router();
+        let mount_path = format!("{}/audit", self.core.config().get_api_version());
+        router = router.nest(&mount_path, audit_router);
+
+        if !self.core.auth().is_enabled() {
+            warn!("Admin authentication is disabled, the admin api is open to anyone");
+        }
+        let api_path = self.core.config().get_api_version();
+        let guard = AdminGuard::new(self.core.auth(), self.core.clone(), api_path.clone());
+        let router = guard.layer(router);
+
+        let config = self.core.config().rate_limit_config().clone();
+        let limiter = Arc::new(RateLimitService::new(config));
+        let limiter = RateLimiter::new(limiter, self.core.clone(), api_path);
+        let router = limiter.layer(router);
+        let mut router = ClientCertFilter::new(self.core.config().proofing_config().clone())
+            .layer(router);
+
+        // Mounted after the layers so scrapes are neither guarded nor counted
+        if self.core.config().is_metrics_enabled() {
+            router = RequestMetrics::new(self.core.metrics()).layer(router);
+            let metrics_path = self.core.config().get_metrics_path().to_string();
+            router = router.nest(&metrics_path, MetricsRouter::new(self.core.clone()).router());
+        }
+
         router
             .fallback(Self::fallback)
             .layer(
                 TraceLayer::new_for_http()
-                    .make_span_with(
-                        |_req: &Request<_>| tracing::info_span!("request", id = %Uuid::new_v4())
-                    )
+                    .make_span_with(|req: &Request<_>| {
+                        let span = tracing::info_span!(
+                            "request",
+                            id = %Uuid::new_v4(),
+                            method = %req.method(),
+                            path = %req.uri().path()
+                        );
+                        join_trace(&span, req.headers());
+                        span
+                    })
                     .on_request(|req: &Request<_>, _span: &tracing::Span| {
                         info!("{} {}", req.method(), req.uri().path());
                     })
                     .on_response(DefaultOnResponse::new().level(Level::TRACE))
             )
-            .layer(CorsLayer::permissive())
+            .layer(self.cors())
+    }
+
+    fn cors(&self) -> CorsLayer {
+        let origins: Vec<HeaderValue> = self
+            .core
+            .config()
+            .get_cors_origins()
+            .iter()
+            .filter_map(|origin| origin.parse().ok())
+            .collect();
+        // Without origins no cross-origin caller is let in, the dashboard is served same-origin
+        CorsLayer::new().allow_origin(origins).allow_methods(Any).allow_headers(Any)
     }
     async fn fallback() -> impl IntoResponse {
         error!("Wrong route");
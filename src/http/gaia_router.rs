@@ -0,0 +1,77 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use ymir::errors::AppResult;
+use ymir::utils::extract_payload;
+
+use crate::core::traits::CoreGaiaTrait;
+use crate::data::entities::gaia_participant::Model;
+use crate::services::auth::types::AdminPrincipal;
+use crate::services::gaia::types::{GaiaOnboardRequest, GaiaOnboardResponse, GaiaReview, GaiaTerms};
+
+pub struct GaiaRouter {
+    gaia: Arc<dyn CoreGaiaTrait>
+}
+
+impl GaiaRouter {
+    pub fn new(gaia: Arc<dyn CoreGaiaTrait>) -> Self { Self { gaia } }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/participant", post(Self::onboard))
+            .route("/terms", get(Self::get_terms))
+            .route("/participant/{id}", get(Self::get_participant))
+            .route("/participant/{id}/review", post(Self::review_participant))
+            .with_state(self.gaia)
+    }
+
+    async fn onboard(
+        State(gaia): State<Arc<dyn CoreGaiaTrait>>,
+        payload: Result<Json<GaiaOnboardRequest>, JsonRejection>
+    ) -> AppResult<Json<GaiaOnboardResponse>> {
+        let payload = extract_payload(payload)?;
+        Ok(Json(gaia.manage_req(payload).await?))
+    }
+
+    async fn get_terms(State(gaia): State<Arc<dyn CoreGaiaTrait>>) -> Json<GaiaTerms> {
+        Json(gaia.get_terms())
+    }
+
+    async fn review_participant(
+        State(gaia): State<Arc<dyn CoreGaiaTrait>>,
+        Path(id): Path<String>,
+        principal: Option<Extension<AdminPrincipal>>,
+        payload: Result<Json<GaiaReview>, JsonRejection>
+    ) -> AppResult<Json<Model>> {
+        let payload = extract_payload(payload)?;
+        let actor = principal.map(|Extension(principal)| principal.subject);
+        Ok(Json(gaia.review_participant(id, payload, actor).await?))
+    }
+
+    async fn get_participant(
+        State(gaia): State<Arc<dyn CoreGaiaTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<Model>> {
+        Ok(Json(gaia.get_participant(id).await?))
+    }
+}
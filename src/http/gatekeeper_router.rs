@@ -17,18 +17,27 @@
 
 use std::sync::Arc;
 
+use axum::body::Bytes;
 use axum::extract::rejection::JsonRejection;
-use axum::extract::{Path, State};
-use axum::http::{HeaderMap, StatusCode};
-use axum::response::IntoResponse;
-use axum::routing::post;
-use axum::{Json, Router};
-use ymir::errors::AppResult;
-use ymir::types::gnap::grant_request::GrantRequest;
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::{header, HeaderMap, Method, StatusCode};
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use serde::de::DeserializeOwned;
+use ymir::errors::{AppResult, BadFormat, Errors, Outcome};
 use ymir::types::gnap::RefBody;
-use ymir::utils::{extract_gnap_token, extract_payload};
+use ymir::utils::{extract_bearer_token, extract_gnap_token, extract_payload};
 
 use crate::core::traits::CoreGatekeeperTrait;
+use crate::services::gatekeeper::types::{
+    DeviceQuery, GnapGrantRequest, GnapResponse, GrantModification, InteractionStatus
+};
+use crate::services::proofing::types::SignedRequest;
+use crate::services::rate_limit::types::ClientIp;
+use crate::services::tokens::types::{
+    IntrospectionRequest, IntrospectionResponse, ResourceServerRequest, ResourceServerResponse
+};
 
 pub struct GateKeeperRouter {
     gatekeeper: Arc<dyn CoreGatekeeperTrait>
@@ -40,17 +49,53 @@ impl GateKeeperRouter {
     pub fn router(self) -> Router {
         Router::new()
             .route("/access", post(Self::access_req))
-            .route("/continue/{id}", post(Self::continue_req))
+            .route(
+                "/continue/{id}",
+                post(Self::continue_req).patch(Self::modify_req).delete(Self::revoke_req)
+            )
+            .route("/interact/{id}", get(Self::interaction_page))
+            .route("/interact/{id}/status", get(Self::interaction_status))
+            .route("/interact/{id}/deny", post(Self::deny_interaction))
+            .route("/device", get(Self::device))
+            .route("/resource-servers", post(Self::register_resource_server))
+            .route("/introspect", post(Self::introspect))
             .with_state(self.gatekeeper)
     }
 
+    // Key proofs cover the raw request, so GNAP bodies are parsed by hand
+    fn signed_request(
+        method: Method,
+        uri: OriginalUri,
+        headers: &HeaderMap,
+        body: &Bytes
+    ) -> SignedRequest {
+        SignedRequest {
+            method: method.to_string(),
+            path: uri.0.path_and_query().map(|p| p.to_string()).unwrap_or_default(),
+            headers: headers.clone(),
+            body: body.clone(),
+            client_ip: None
+        }
+    }
+
+    fn parse_body<T: DeserializeOwned>(body: &Bytes, message: &str) -> Outcome<T> {
+        serde_json::from_slice(body)
+            .map_err(|e| Errors::format(BadFormat::Received, message, Some(Box::new(e))))
+    }
+
     async fn access_req(
         State(gatekeeper): State<Arc<dyn CoreGatekeeperTrait>>,
-        payload: Result<Json<GrantRequest>, JsonRejection>
+        method: Method,
+        uri: OriginalUri,
+        client_ip: Option<Extension<ClientIp>>,
+        headers: HeaderMap,
+        body: Bytes
     ) -> AppResult {
-        let payload = extract_payload(payload)?;
+        let payload = GnapGrantRequest::parse(&body)?;
+        let mut signed = Self::signed_request(method, uri, &headers, &body);
+        signed.client_ip = client_ip.map(|Extension(ClientIp(ip))| ip);
         Ok(gatekeeper
-            .manage_req(payload)
+            .manage_req(payload, signed)
             .await
             .map(Json)
             .map_err(|e| (StatusCode::BAD_REQUEST, Json(e)))
@@ -59,12 +104,115 @@ impl GateKeeperRouter {
 
     async fn continue_req(
         State(authority): State<Arc<dyn CoreGatekeeperTrait>>,
+        method: Method,
+        uri: OriginalUri,
         headers: HeaderMap,
         Path(id): Path<String>,
-        payload: Result<Json<RefBody>, JsonRejection>
-    ) -> AppResult<String> {
+        body: Bytes
+    ) -> AppResult {
+        let signed = Self::signed_request(method, uri, &headers, &body);
+        // Clients that do not ask for JSON keep receiving the bare VC offer URI
+        let wants_json = headers
+            .get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .is_some_and(|accept| accept.contains("application/json"));
         let token = extract_gnap_token(headers)?;
+
+        // An empty continuation is a poll (RFC 9635 §5.2)
+        if body.is_empty() {
+            return Ok(Json(authority.poll_cont_req(id, token, signed).await?).into_response());
+        }
+        let payload: RefBody = Self::parse_body(&body, "Invalid continuation body")?;
+        let continuation = authority.manage_cont_req(id, payload, token, signed).await?;
+        match wants_json {
+            true => Ok(Json(continuation.response).into_response()),
+            false => Ok(continuation.vc_uri.into_response())
+        }
+    }
+
+    async fn modify_req(
+        State(authority): State<Arc<dyn CoreGatekeeperTrait>>,
+        method: Method,
+        uri: OriginalUri,
+        headers: HeaderMap,
+        Path(id): Path<String>,
+        body: Bytes
+    ) -> AppResult<Json<GnapResponse>> {
+        let signed = Self::signed_request(method, uri, &headers, &body);
+        let payload: GrantModification = Self::parse_body(&body, "Invalid grant modification")?;
+        let token = extract_gnap_token(headers)?;
+        Ok(Json(authority.modify_cont_req(id, payload, token, signed).await?))
+    }
+
+    async fn revoke_req(
+        State(authority): State<Arc<dyn CoreGatekeeperTrait>>,
+        method: Method,
+        uri: OriginalUri,
+        headers: HeaderMap,
+        Path(id): Path<String>,
+        body: Bytes
+    ) -> AppResult<StatusCode> {
+        let signed = Self::signed_request(method, uri, &headers, &body);
+        let token = extract_gnap_token(headers)?;
+        authority.revoke_cont_req(id, token, signed).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    async fn register_resource_server(
+        State(gatekeeper): State<Arc<dyn CoreGatekeeperTrait>>,
+        headers: HeaderMap,
+        payload: Result<Json<ResourceServerRequest>, JsonRejection>
+    ) -> AppResult<Json<ResourceServerResponse>> {
+        let key = extract_bearer_token(headers)?;
+        let payload = extract_payload(payload)?;
+        Ok(Json(gatekeeper.register_resource_server(key, payload).await?))
+    }
+
+    async fn introspect(
+        State(gatekeeper): State<Arc<dyn CoreGatekeeperTrait>>,
+        headers: HeaderMap,
+        payload: Result<Json<IntrospectionRequest>, JsonRejection>
+    ) -> AppResult<Json<IntrospectionResponse>> {
+        let secret = extract_bearer_token(headers)?;
         let payload = extract_payload(payload)?;
-        authority.manage_cont_req(id, payload, token).await
+        Ok(Json(gatekeeper.introspect(secret, payload).await?))
+    }
+
+    async fn interaction_page(
+        State(gatekeeper): State<Arc<dyn CoreGatekeeperTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Html<String>> {
+        Ok(Html(gatekeeper.get_interaction_page(id).await?))
+    }
+
+    async fn interaction_status(
+        State(gatekeeper): State<Arc<dyn CoreGatekeeperTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<InteractionStatus>> {
+        Ok(Json(gatekeeper.get_interaction_status(id).await?))
+    }
+
+    async fn deny_interaction(
+        State(gatekeeper): State<Arc<dyn CoreGatekeeperTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Redirect> {
+        Ok(Redirect::to(&gatekeeper.deny_interaction(id).await?))
+    }
+
+    async fn device(
+        State(gatekeeper): State<Arc<dyn CoreGatekeeperTrait>>,
+        Query(query): Query<DeviceQuery>
+    ) -> Response {
+        let Some(code) = query.code else {
+            return Html(gatekeeper.device_page(None)).into_response();
+        };
+        match gatekeeper.resolve_user_code(code).await {
+            Ok(uri) => Redirect::to(&uri).into_response(),
+            Err(e) => {
+                e.log();
+                let page = gatekeeper.device_page(Some("Unknown or expired code"));
+                (StatusCode::NOT_FOUND, Html(page)).into_response()
+            }
+        }
     }
 }
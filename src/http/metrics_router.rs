@@ -0,0 +1,47 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use prometheus::TEXT_FORMAT;
+use ymir::errors::AppResult;
+
+use crate::core::traits::CoreMetricsTrait;
+
+pub struct MetricsRouter {
+    metrics: Arc<dyn CoreMetricsTrait>
+}
+
+impl MetricsRouter {
+    pub fn new(metrics: Arc<dyn CoreMetricsTrait>) -> Self { Self { metrics } }
+
+    pub fn router(self) -> Router {
+        Router::new().route("/", get(Self::scrape)).with_state(self.metrics)
+    }
+
+    async fn scrape(
+        State(metrics): State<Arc<dyn CoreMetricsTrait>>
+    ) -> AppResult<impl IntoResponse> {
+        let body = metrics.render_metrics()?;
+        Ok(([(header::CONTENT_TYPE, TEXT_FORMAT)], body))
+    }
+}
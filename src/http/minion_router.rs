@@ -17,13 +17,14 @@
 
 use std::sync::Arc;
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::routing::get;
 use axum::{Json, Router};
 use ymir::data::entities::minions::Model;
 use ymir::errors::AppResult;
 
 use crate::core::traits::CoreMinionTrait;
+use crate::services::repo::types::{MinionQuery, Page};
 
 pub struct MinionRouter {
     gru: Arc<dyn CoreMinionTrait>
@@ -40,8 +41,11 @@ impl MinionRouter {
             .with_state(self.gru)
     }
 
-    async fn get_all(State(gru): State<Arc<dyn CoreMinionTrait>>) -> AppResult<Json<Vec<Model>>> {
-        Ok(Json(gru.get_all().await?))
+    async fn get_all(
+        State(gru): State<Arc<dyn CoreMinionTrait>>,
+        Query(query): Query<MinionQuery>
+    ) -> AppResult<Json<Page<Model>>> {
+        Ok(Json(gru.get_all(query).await?))
     }
 
     async fn get_by_id(
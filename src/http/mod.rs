@@ -15,20 +15,38 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+mod admin_guard;
+mod audit_router;
+mod auth_router;
 mod builder;
+mod client_cert;
 mod core_router;
+mod gaia_router;
 mod gatekeeper_router;
 mod issuer_router;
+mod metrics_router;
 mod minion_router;
+mod rate_limiter;
 pub mod react_router;
+mod request_metrics;
 mod vcs_router;
+mod verification_router;
 mod verifier_router;
 
+pub use admin_guard::AdminGuard;
+pub use audit_router::AuditRouter;
+pub use auth_router::AuthRouter;
 pub use builder::RouterBuilder;
+pub use client_cert::ClientCertFilter;
 pub use core_router::RainbowAuthorityRouter;
+pub use gaia_router::GaiaRouter;
 pub use gatekeeper_router::GateKeeperRouter;
 pub use issuer_router::IssuerRouter;
+pub use metrics_router::MetricsRouter;
 pub use minion_router::MinionRouter;
+pub use rate_limiter::RateLimiter;
 pub use react_router::ReactRouter;
+pub use request_metrics::RequestMetrics;
 pub use vcs_router::ApproverRouter;
+pub use verification_router::VerificationRouter;
 pub use verifier_router::VerifierRouter;
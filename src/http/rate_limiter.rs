@@ -0,0 +1,139 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use axum::body::{to_bytes, Body, Bytes};
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::{Json, Router};
+use serde_json::json;
+use tracing::warn;
+use ymir::errors::AppResult;
+
+use crate::config::rate_limit::RateLimitConfigTrait;
+use crate::core::traits::CoreGatekeeperTrait;
+use crate::services::rate_limit::types::{ClientIp, RateDecision};
+use crate::services::rate_limit::RateLimitTrait;
+
+// Bodies of the limited endpoints are small, larger ones are refused before parsing
+const MAX_BODY: usize = 1024 * 1024;
+
+pub struct RateLimiter {
+    limits: Arc<dyn RateLimitTrait>,
+    gatekeeper: Arc<dyn CoreGatekeeperTrait>,
+    api_path: String
+}
+
+impl RateLimiter {
+    pub fn new(
+        limits: Arc<dyn RateLimitTrait>,
+        gatekeeper: Arc<dyn CoreGatekeeperTrait>,
+        api_path: String
+    ) -> Self {
+        Self { limits, gatekeeper, api_path }
+    }
+
+    pub fn layer(self, router: Router) -> Router {
+        router.layer(middleware::from_fn_with_state(Arc::new(self), Self::check))
+    }
+
+    async fn check(
+        State(rate): State<Arc<RateLimiter>>,
+        mut req: Request,
+        next: Next
+    ) -> AppResult<Response> {
+        let ip = rate.client_ip(&req);
+        if let Some(ip) = ip {
+            req.extensions_mut().insert(ClientIp(ip));
+        }
+        let path = match req.uri().path().strip_prefix(&rate.api_path) {
+            Some(path) if rate.limits.is_rate_limit_enabled() => path.to_string(),
+            _ => return Ok(next.run(req).await)
+        };
+        if rate.limits.get_rate_bucket(&path).is_none() && !rate.limits.reads_body(&path) {
+            return Ok(next.run(req).await);
+        }
+
+        let (req, body) = match rate.limits.reads_body(&path) {
+            true => {
+                let (parts, body) = req.into_parts();
+                let Ok(body) = to_bytes(body, MAX_BODY).await else {
+                    return Ok(StatusCode::PAYLOAD_TOO_LARGE.into_response());
+                };
+                (Request::from_parts(parts, Body::from(body.clone())), body)
+            }
+            false => (req, Bytes::new())
+        };
+
+        let client = rate.limits.client_key(&path, &body);
+        if let RateDecision::Limited(retry_after) =
+            rate.limits.check(&path, ip, client.as_deref())
+        {
+            warn!("Rate limit hit on {} by {:?}", path, ip);
+            return Ok(Self::too_many(retry_after, "Too many requests"));
+        }
+
+        if let (Some(owner), Some(max)) =
+            (rate.limits.pending_owner(&path, &body, ip), rate.limits.get_max_pending())
+        {
+            if rate.gatekeeper.count_pending(&owner).await? >= max {
+                warn!("Pending requests of {} reached {}", owner, max);
+                let retry_after = rate.limits.get_pending_retry_after();
+                return Ok(Self::too_many(retry_after, "Too many pending requests"));
+            }
+        }
+
+        Ok(next.run(req).await)
+    }
+
+    // Entries left of the ones our proxies appended are whatever the client chose to send
+    fn client_ip(&self, req: &Request) -> Option<IpAddr> {
+        let hops = self.limits.get_proxy_hops();
+        if hops > 0 {
+            let entries: Vec<&str> = req
+                .headers()
+                .get_all("x-forwarded-for")
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .flat_map(|value| value.split(','))
+                .collect();
+            let forwarded = entries
+                .len()
+                .checked_sub(hops)
+                .and_then(|position| entries.get(position))
+                .and_then(|ip| ip.trim().parse().ok());
+            if forwarded.is_some() {
+                return forwarded;
+            }
+        }
+        req.extensions().get::<ConnectInfo<SocketAddr>>().map(|info| info.0.ip())
+    }
+
+    fn too_many(retry_after: u64, message: &str) -> Response {
+        let body = json!({ "error": "too_many_requests", "message": message });
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            Json(body)
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::extract::{MatchedPath, Request, State};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::Router;
+
+use crate::services::metrics::MetricsTrait;
+
+pub struct RequestMetrics {
+    metrics: Arc<dyn MetricsTrait>
+}
+
+impl RequestMetrics {
+    pub fn new(metrics: Arc<dyn MetricsTrait>) -> Self { Self { metrics } }
+
+    pub fn layer(self, router: Router) -> Router {
+        router.layer(middleware::from_fn_with_state(Arc::new(self), Self::observe))
+    }
+
+    async fn observe(
+        State(observer): State<Arc<RequestMetrics>>,
+        req: Request,
+        next: Next
+    ) -> Response {
+        // Labelled by route template, raw paths would add a series per id
+        let route = match req.extensions().get::<MatchedPath>() {
+            Some(path) => path.as_str().to_string(),
+            None => "unmatched".to_string()
+        };
+        let method = req.method().to_string();
+        let start = Instant::now();
+        let response = next.run(req).await;
+        let status = response.status().as_u16();
+        observer.metrics.observe_request(&method, &route, status, start.elapsed());
+        response
+    }
+}
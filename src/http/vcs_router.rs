@@ -18,15 +18,20 @@
 use std::sync::Arc;
 
 use axum::extract::rejection::JsonRejection;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::routing::{get, post};
-use axum::{Json, Router};
+use axum::{Extension, Json, Router};
 use ymir::data::entities::vc_request::Model;
 use ymir::errors::AppResult;
-use ymir::types::vcs::vc_decision_approval::VcDecisionApproval;
 use ymir::utils::extract_payload;
 
-use crate::core::traits::CoreApproverTrait;
+use crate::core::traits::{CoreApproverTrait, CoreOutboxTrait};
+use crate::data::entities::{
+    approval_decision, approval_vote, callback_outbox, request_event, verification_trust
+};
+use crate::services::approval::types::{ApprovalTally, ApprovalVote};
+use crate::services::auth::types::AdminPrincipal;
+use crate::services::repo::types::{Page, RequestQuery};
 
 pub struct ApproverRouter {
     approver: Arc<dyn CoreApproverTrait>
@@ -39,13 +44,21 @@ impl ApproverRouter {
             .route("/all", get(Self::get_all_requests))
             .route("/{id}", get(Self::get_one_request))
             .route("/{id}", post(Self::manage_request))
+            .route("/{id}/trust", get(Self::get_trust))
+            .route("/{id}/decision", get(Self::get_decision))
+            .route("/{id}/votes", get(Self::get_votes))
+            .route("/{id}/history", get(Self::get_history))
+            .route("/{id}/credentials", get(Self::get_credentials))
+            .route("/{id}/callbacks", get(Self::get_callbacks))
+            .route("/{id}/callbacks/{callback_id}/redeliver", post(Self::redeliver_callback))
             .with_state(self.approver)
     }
 
     async fn get_all_requests(
-        State(approver): State<Arc<dyn CoreApproverTrait>>
-    ) -> AppResult<Json<Vec<Model>>> {
-        Ok(Json(approver.get_all().await?))
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Query(query): Query<RequestQuery>
+    ) -> AppResult<Json<Page<Model>>> {
+        Ok(Json(approver.get_all(query).await?))
     }
 
     async fn get_one_request(
@@ -55,12 +68,63 @@ impl ApproverRouter {
         Ok(Json(approver.get_by_id(id).await?))
     }
 
+    async fn get_trust(
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<verification_trust::Model>> {
+        Ok(Json(approver.get_trust(id).await?))
+    }
+
+    async fn get_decision(
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<approval_decision::Model>> {
+        Ok(Json(approver.get_decision(id).await?))
+    }
+
+    async fn get_votes(
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<Vec<approval_vote::Model>>> {
+        Ok(Json(approver.get_votes(id).await?))
+    }
+
+    async fn get_history(
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<Vec<request_event::Model>>> {
+        Ok(Json(approver.get_history(id).await?))
+    }
+
+    async fn get_credentials(
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<Vec<String>>> {
+        Ok(Json(approver.get_credentials(id).await?))
+    }
+
+    async fn get_callbacks(
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Path(id): Path<String>
+    ) -> AppResult<Json<Vec<callback_outbox::Model>>> {
+        Ok(Json(approver.get_callbacks(id).await?))
+    }
+
+    async fn redeliver_callback(
+        State(approver): State<Arc<dyn CoreApproverTrait>>,
+        Path((id, callback_id)): Path<(String, String)>
+    ) -> AppResult<Json<callback_outbox::Model>> {
+        Ok(Json(approver.redeliver_callback(id, callback_id).await?))
+    }
+
     async fn manage_request(
         State(approver): State<Arc<dyn CoreApproverTrait>>,
         Path(id): Path<String>,
-        payload: Result<Json<VcDecisionApproval>, JsonRejection>
-    ) -> AppResult<()> {
+        principal: Option<Extension<AdminPrincipal>>,
+        payload: Result<Json<ApprovalVote>, JsonRejection>
+    ) -> AppResult<Json<ApprovalTally>> {
         let payload = extract_payload(payload)?;
-        approver.manage_req(id, payload).await
+        let actor = principal.map(|Extension(principal)| principal.subject);
+        Ok(Json(approver.manage_req(id, payload, actor).await?))
     }
 }
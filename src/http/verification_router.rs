@@ -0,0 +1,64 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use ymir::errors::AppResult;
+use ymir::utils::{extract_bearer_token, extract_payload};
+
+use crate::core::traits::CoreVerificationTrait;
+use crate::data::entities::verification_session::Model;
+use crate::services::verification::types::{SessionRequest, SessionResponse};
+
+pub struct VerificationRouter {
+    verification: Arc<dyn CoreVerificationTrait>
+}
+
+impl VerificationRouter {
+    pub fn new(verification: Arc<dyn CoreVerificationTrait>) -> Self { Self { verification } }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/session", post(Self::create_session))
+            .route("/session/{id}", get(Self::get_session))
+            .with_state(self.verification)
+    }
+
+    async fn create_session(
+        State(verification): State<Arc<dyn CoreVerificationTrait>>,
+        headers: HeaderMap,
+        payload: Result<Json<SessionRequest>, JsonRejection>
+    ) -> AppResult<Json<SessionResponse>> {
+        let token = extract_bearer_token(headers)?;
+        let payload = extract_payload(payload)?;
+        Ok(Json(verification.create_session(token, payload).await?))
+    }
+
+    async fn get_session(
+        State(verification): State<Arc<dyn CoreVerificationTrait>>,
+        headers: HeaderMap,
+        Path(id): Path<String>
+    ) -> AppResult<Json<Model>> {
+        let token = extract_bearer_token(headers)?;
+        Ok(Json(verification.get_session(token, id).await?))
+    }
+}
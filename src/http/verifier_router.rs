@@ -19,42 +19,98 @@ use std::sync::Arc;
 
 use axum::extract::rejection::FormRejection;
 use axum::extract::{Path, State};
+use axum::http::header;
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Form, Json, Router};
 use ymir::errors::AppResult;
-use ymir::types::vcs::VPDef;
-use ymir::types::verifying::VerifyPayload;
 use ymir::utils::extract_form_payload;
 
-use crate::core::traits::CoreVerifierTrait;
+use crate::core::traits::{
+    CoreGaiaTrait, CoreLoginTrait, CoreVerificationTrait, CoreVerifierTrait
+};
+use crate::services::encryption::types::ResponsePayload;
+use crate::services::presentation::types::{DcqlQuery, VpDefinition};
+
+#[derive(Clone)]
+struct VerifierState {
+    core: Arc<dyn CoreVerifierTrait>,
+    gaia: Option<Arc<dyn CoreGaiaTrait>>,
+    sessions: Option<Arc<dyn CoreVerificationTrait>>,
+    logins: Option<Arc<dyn CoreLoginTrait>>
+}
 
 pub struct VerifierRouter {
-    verifier: Arc<dyn CoreVerifierTrait>
+    verifier: Arc<dyn CoreVerifierTrait>,
+    gaia: Option<Arc<dyn CoreGaiaTrait>>,
+    sessions: Option<Arc<dyn CoreVerificationTrait>>,
+    logins: Option<Arc<dyn CoreLoginTrait>>
 }
 
 impl VerifierRouter {
-    pub fn new(verifier: Arc<dyn CoreVerifierTrait>) -> Self { Self { verifier } }
+    pub fn new(
+        verifier: Arc<dyn CoreVerifierTrait>,
+        gaia: Option<Arc<dyn CoreGaiaTrait>>,
+        sessions: Option<Arc<dyn CoreVerificationTrait>>,
+        logins: Option<Arc<dyn CoreLoginTrait>>
+    ) -> Self {
+        Self { verifier, gaia, sessions, logins }
+    }
     pub fn router(self) -> Router {
         Router::new()
+            .route("/request/{state}", get(Self::request_object))
             .route("/pd/{state}", get(Self::vp_definition))
+            .route("/dcql/{state}", get(Self::dcql_query))
             .route("/verify/{state}", post(Self::verify))
-            .with_state(self.verifier)
+            .with_state(VerifierState {
+                core: self.verifier,
+                gaia: self.gaia,
+                sessions: self.sessions,
+                logins: self.logins
+            })
+    }
+    async fn request_object(
+        State(verifier): State<VerifierState>,
+        Path(state): Path<String>
+    ) -> AppResult {
+        let jwt = verifier.core.get_request_object(state).await?;
+        Ok(([(header::CONTENT_TYPE, "application/oauth-authz-req+jwt")], jwt).into_response())
     }
+
     async fn vp_definition(
-        State(verifier): State<Arc<dyn CoreVerifierTrait>>,
+        State(verifier): State<VerifierState>,
+        Path(state): Path<String>
+    ) -> AppResult<Json<VpDefinition>> {
+        Ok(Json(verifier.core.get_vp_def(state).await?))
+    }
+
+    async fn dcql_query(
+        State(verifier): State<VerifierState>,
         Path(state): Path<String>
-    ) -> AppResult<Json<VPDef>> {
-        Ok(Json(verifier.get_vp_def(state).await?))
+    ) -> AppResult<Json<DcqlQuery>> {
+        Ok(Json(verifier.core.get_dcql(state).await?))
     }
 
     async fn verify(
-        State(verifier): State<Arc<dyn CoreVerifierTrait>>,
+        State(verifier): State<VerifierState>,
         Path(state): Path<String>,
-        payload: Result<Form<VerifyPayload>, FormRejection>
+        payload: Result<Form<ResponsePayload>, FormRejection>
     ) -> AppResult {
         let payload = extract_form_payload(payload)?;
-        Ok(match verifier.verify(state, payload.vp_token).await {
+        let vp_token = verifier.core.open_response(&state, payload).await?;
+        let result = match (verifier.sessions, verifier.gaia, verifier.logins) {
+            (Some(sessions), _, _) if sessions.is_standalone_session(&state).await? => {
+                sessions.verify_session(state, vp_token).await
+            }
+            (_, Some(gaia), _) if gaia.is_gaia_session(&state).await? => {
+                gaia.verify_participant(state, vp_token).await
+            }
+            (_, _, Some(logins)) if logins.is_login_session(&state).await? => {
+                logins.verify_login(state, vp_token).await
+            }
+            _ => verifier.core.verify(state, vp_token).await
+        };
+        Ok(match result {
             Ok(Some(uri)) => uri.into_response(),
             Ok(None) => ().into_response(),
             Err(e) => e.into_response()
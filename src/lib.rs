@@ -21,3 +21,4 @@ pub mod data;
 pub mod http;
 pub mod services;
 pub mod setup;
+pub mod utils;
@@ -16,6 +16,7 @@
  */
 
 use heimdall::setup::commands::cmd::AuthorityCommands;
+use heimdall::setup::telemetry::Telemetry;
 use tracing::info;
 use tracing_subscriber::filter::LevelFilter;
 use tracing_subscriber::EnvFilter;
@@ -48,8 +49,9 @@ async fn main() -> Outcome<()> {
             error.log();
             error
         })?;
-    tracing_subscriber::fmt().with_env_filter(filter).init();
+    let telemetry = Telemetry::init(filter)?;
     info!("{}", INFO);
-    AuthorityCommands::init_command_line().await?;
-    Ok(())
+    let result = AuthorityCommands::init_command_line(&telemetry).await;
+    telemetry.shutdown();
+    result
 }
@@ -0,0 +1,35 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use ymir::data::entities::vc_request;
+
+use super::types::ApprovalDecision;
+
+pub trait ApprovalTrait: Send + Sync + 'static {
+    // `vc_types` lists every credential of the grant, linked requests share its decision;
+    // `cert` is the certificate the grant is bound to, subject rules never match without one
+    fn evaluate(
+        &self,
+        req_model: &vc_request::Model,
+        vc_types: &[String],
+        cert: Option<&String>,
+        known_participant: bool,
+        now: DateTime<Utc>
+    ) -> ApprovalDecision;
+    fn quorum(&self, vc_types: &[String]) -> usize;
+}
@@ -0,0 +1,182 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, NaiveTime, Timelike, Utc};
+use tracing::warn;
+use x509_parser::parse_x509_certificate;
+use ymir::data::entities::vc_request;
+
+use super::types::ApprovalDecision;
+use super::ApprovalTrait;
+use crate::config::approval::{
+    ApprovalConfig, ApprovalConfigTrait, ApprovalOutcome, ApprovalRule, TimeWindow
+};
+
+pub struct ApprovalService {
+    config: ApprovalConfig
+}
+
+impl ApprovalService {
+    pub fn new(config: ApprovalConfig) -> Self { Self { config } }
+
+    fn subject(cert: Option<&String>) -> HashMap<String, String> {
+        let mut attrs = HashMap::new();
+        let Some(bytes) = cert.and_then(|cert| STANDARD.decode(cert).ok()) else {
+            return attrs;
+        };
+        let Ok((_, cert)) = parse_x509_certificate(&bytes) else {
+            warn!("Unable to parse the request certificate, subject rules will not match");
+            return attrs;
+        };
+        for attr in cert.subject.iter_attributes() {
+            let Ok(value) = attr.attr_value().as_str() else { continue };
+            let oid = attr.attr_type().to_id_string();
+            let short = match oid.as_str() {
+                "2.5.4.3" => Some("CN"),
+                "2.5.4.5" => Some("serialNumber"),
+                "2.5.4.6" => Some("C"),
+                "2.5.4.7" => Some("L"),
+                "2.5.4.8" => Some("ST"),
+                "2.5.4.10" => Some("O"),
+                "2.5.4.11" => Some("OU"),
+                "2.5.4.97" => Some("organizationIdentifier"),
+                _ => None
+            };
+            if let Some(short) = short {
+                attrs.insert(short.to_string(), value.to_string());
+            }
+            attrs.insert(oid, value.to_string());
+        }
+        attrs
+    }
+
+    fn glob(pattern: &str, value: &str) -> bool {
+        let mut parts = pattern.split('*');
+        let first = parts.next().unwrap_or_default();
+        let Some(mut rest) = value.strip_prefix(first) else {
+            return false;
+        };
+        let parts: Vec<&str> = parts.collect();
+        let Some((last, middle)) = parts.split_last() else {
+            return rest.is_empty();
+        };
+        for part in middle {
+            match rest.find(part) {
+                Some(pos) => rest = &rest[pos + part.len()..],
+                None => return false
+            }
+        }
+        rest.ends_with(last)
+    }
+
+    fn in_window(window: &TimeWindow, now: DateTime<Utc>) -> bool {
+        let day = now.format("%a").to_string();
+        if !window.days.is_empty() && !window.days.iter().any(|d| d.eq_ignore_ascii_case(&day)) {
+            return false;
+        }
+        let (Ok(from), Ok(to)) = (
+            NaiveTime::parse_from_str(&window.from, "%H:%M"),
+            NaiveTime::parse_from_str(&window.to, "%H:%M")
+        ) else {
+            warn!("Invalid approval time window {}-{}", window.from, window.to);
+            return false;
+        };
+        let time = NaiveTime::from_hms_opt(now.hour(), now.minute(), 0).unwrap_or(from);
+        match from <= to {
+            true => from <= time && time < to,
+            false => time >= from || time < to
+        }
+    }
+
+    fn strictness(outcome: &ApprovalOutcome) -> u8 {
+        match outcome {
+            ApprovalOutcome::Approve => 0,
+            ApprovalOutcome::ManualReview => 1,
+            ApprovalOutcome::Reject => 2
+        }
+    }
+
+    fn matches(
+        rule: &ApprovalRule,
+        vc_type: &str,
+        req_model: &vc_request::Model,
+        subject: &HashMap<String, String>,
+        known_participant: bool,
+        now: DateTime<Utc>
+    ) -> bool {
+        if !rule.vc_types.is_empty() && !rule.vc_types.iter().any(|t| t == vc_type) {
+            return false;
+        }
+        if !rule.participants.is_empty()
+            && !rule.participants.iter().any(|p| Self::glob(p, &req_model.participant_slug))
+        {
+            return false;
+        }
+        if !rule.cert_subject.iter().all(|(k, v)| subject.get(k).is_some_and(|s| s == v)) {
+            return false;
+        }
+        if rule.known_participant.is_some_and(|known| known != known_participant) {
+            return false;
+        }
+        rule.window.as_ref().is_none_or(|window| Self::in_window(window, now))
+    }
+}
+
+impl ApprovalConfigTrait for ApprovalService {
+    fn approval_config(&self) -> &ApprovalConfig { &self.config }
+}
+
+impl ApprovalTrait for ApprovalService {
+    fn evaluate(
+        &self,
+        req_model: &vc_request::Model,
+        vc_types: &[String],
+        cert: Option<&String>,
+        known_participant: bool,
+        now: DateTime<Utc>
+    ) -> ApprovalDecision {
+        let subject = Self::subject(cert);
+        let decide = |vc_type: &String| {
+            self.get_rules()
+                .iter()
+                .find(|rule| {
+                    Self::matches(rule, vc_type, req_model, &subject, known_participant, now)
+                })
+                .map(|rule| ApprovalDecision::new(rule.outcome.clone(), Some(rule.name.clone())))
+                .unwrap_or_else(|| ApprovalDecision::new(self.get_default_outcome().clone(), None))
+        };
+        // The grant gets the strictest outcome among its credentials
+        vc_types
+            .iter()
+            .map(decide)
+            .reduce(|strictest, decision| {
+                match Self::strictness(&decision.outcome) > Self::strictness(&strictest.outcome) {
+                    true => decision,
+                    false => strictest
+                }
+            })
+            .unwrap_or_else(|| ApprovalDecision::new(self.get_default_outcome().clone(), None))
+    }
+
+    fn quorum(&self, vc_types: &[String]) -> usize {
+        vc_types.iter().map(|vc_type| self.get_quorum(vc_type)).max().unwrap_or(1)
+    }
+}
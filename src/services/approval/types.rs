@@ -0,0 +1,84 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::approval::ApprovalOutcome;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ApprovalDecision {
+    pub outcome: ApprovalOutcome,
+    pub rule: Option<String>
+}
+
+impl ApprovalDecision {
+    pub fn new(outcome: ApprovalOutcome, rule: Option<String>) -> Self { Self { outcome, rule } }
+}
+
+// Superset of `VcDecisionApproval`; the approver is only required under a quorum
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApprovalVote {
+    pub approve: bool,
+    pub approver: Option<String>,
+    pub reason: Option<String>
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ApprovalTally {
+    pub status: String,
+    pub approvals: usize,
+    pub required: usize
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestEvent {
+    Created,
+    Modified,
+    Verified,
+    Voted,
+    Approved,
+    Rejected,
+    OfferFetched,
+    TokenIssued,
+    CredentialIssued,
+    Cancelled,
+    Expired,
+    CallbackFailed
+}
+
+impl fmt::Display for RequestEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            RequestEvent::Created => "created",
+            RequestEvent::Modified => "modified",
+            RequestEvent::Verified => "verified",
+            RequestEvent::Voted => "voted",
+            RequestEvent::Approved => "approved",
+            RequestEvent::Rejected => "rejected",
+            RequestEvent::OfferFetched => "offer_fetched",
+            RequestEvent::TokenIssued => "token_issued",
+            RequestEvent::CredentialIssued => "credential_issued",
+            RequestEvent::Cancelled => "cancelled",
+            RequestEvent::Expired => "expired",
+            RequestEvent::CallbackFailed => "callback_failed"
+        };
+        write!(f, "{}", s)
+    }
+}
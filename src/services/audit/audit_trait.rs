@@ -0,0 +1,43 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use jsonwebtoken::DecodingKey;
+use ymir::errors::Outcome;
+
+use super::types::{AuditEvent, AuditReport};
+use crate::config::audit::AuditConfigTrait;
+use crate::data::entities::audit_entry;
+
+#[async_trait]
+pub trait AuditTrait: AuditConfigTrait + Send + Sync + 'static {
+    // Chains the event onto `last` and hashes it
+    fn start(
+        &self,
+        last: Option<&audit_entry::Model>,
+        event: &AuditEvent
+    ) -> Outcome<audit_entry::NewModel>;
+    async fn sign(&self, model: &mut audit_entry::NewModel) -> Outcome<()>;
+    async fn verifying_key(&self) -> Outcome<DecodingKey>;
+    // Checks the entry against the end of the chain seen so far; false once the chain breaks
+    fn verify_entry(
+        &self,
+        report: &mut AuditReport,
+        entry: &audit_entry::Model,
+        key: &DecodingKey
+    ) -> bool;
+}
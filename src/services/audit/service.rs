@@ -0,0 +1,177 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{SubsecRound, Utc};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use ymir::errors::{Errors, Outcome};
+use ymir::services::vault::{VaultService, VaultTrait};
+use ymir::types::secrets::StringHelper;
+use ymir::utils::expect_from_env;
+
+use super::types::{AuditDigest, AuditEvent, AuditReport, AuditSignature, GENESIS_HASH};
+use super::AuditTrait;
+use crate::config::audit::{AuditConfig, AuditConfigTrait};
+use crate::data::entities::audit_entry;
+
+pub struct AuditService {
+    config: AuditConfig,
+    vault: Arc<VaultService>
+}
+
+impl AuditService {
+    pub fn new(config: AuditConfig, vault: Arc<VaultService>) -> Self { Self { config, vault } }
+
+    async fn read_secret(&self, env: &str) -> Outcome<String> {
+        let path = expect_from_env(env);
+        let secret: StringHelper = self.vault.read(None, &path).await?;
+        Ok(secret.data().to_string())
+    }
+
+    fn alg(&self) -> Outcome<Algorithm> {
+        Algorithm::from_str(self.get_audit_alg()).map_err(|e| {
+            let msg = format!("Unsupported audit signing algorithm '{}'", self.get_audit_alg());
+            Errors::crazy(msg, Some(Box::new(e)))
+        })
+    }
+
+    fn hash(digest: &AuditDigest) -> Outcome<String> {
+        let bytes = serde_json::to_vec(digest)
+            .map_err(|e| Errors::parse("Unable to serialize audit entry", Some(Box::new(e))))?;
+        Ok(Sha256::digest(&bytes).iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    fn check(
+        &self,
+        report: &AuditReport,
+        entry: &audit_entry::Model,
+        key: &DecodingKey
+    ) -> Outcome<()> {
+        match (report.last_seq, report.last_hash.as_deref()) {
+            (Some(seq), Some(hash)) => {
+                if entry.seq != seq + 1 {
+                    let msg = format!("Expected entry {} but found {}", seq + 1, entry.seq);
+                    return Err(Errors::security(msg, None));
+                }
+                if entry.prev_hash != hash {
+                    return Err(Errors::security("Entry does not link to the previous one", None));
+                }
+            }
+            // Exports may start mid-chain, only the first entry links to the genesis hash
+            _ if entry.seq == 1 && entry.prev_hash != GENESIS_HASH => {
+                return Err(Errors::security("First entry does not start the chain", None));
+            }
+            _ => {}
+        }
+
+        if Self::hash(&AuditDigest::from(entry))? != entry.hash {
+            return Err(Errors::security("Entry hash does not match its contents", None));
+        }
+
+        let mut validation = Validation::new(self.alg()?);
+        validation.required_spec_claims.clear();
+        validation.validate_exp = false;
+        let claims = decode::<AuditSignature>(&entry.signature, key, &validation)
+            .map_err(|e| Errors::security("Invalid entry signature", Some(Box::new(e))))?
+            .claims;
+        if claims.seq != entry.seq || claims.hash != entry.hash {
+            return Err(Errors::security("Signature does not cover the entry", None));
+        }
+        Ok(())
+    }
+}
+
+impl AuditConfigTrait for AuditService {
+    fn audit_config(&self) -> &AuditConfig { &self.config }
+}
+
+#[async_trait]
+impl AuditTrait for AuditService {
+    fn start(
+        &self,
+        last: Option<&audit_entry::Model>,
+        event: &AuditEvent
+    ) -> Outcome<audit_entry::NewModel> {
+        let mut model = audit_entry::NewModel {
+            seq: last.map_or(1, |last| last.seq + 1),
+            action: event.action.to_string(),
+            actor: event.actor.clone(),
+            subject: event.subject.clone(),
+            details: event.details.as_ref().map(Value::to_string),
+            // The database keeps microseconds, anything finer would change the hash
+            created_at: Utc::now().naive_utc().trunc_subsecs(6),
+            prev_hash: last.map_or(GENESIS_HASH.to_string(), |last| last.hash.clone()),
+            hash: String::new(),
+            signature: String::new()
+        };
+        model.hash = Self::hash(&AuditDigest::from(&model))?;
+        Ok(model)
+    }
+
+    async fn sign(&self, model: &mut audit_entry::NewModel) -> Outcome<()> {
+        let alg = self.alg()?;
+        let mut header = Header::new(alg);
+        header.kid = self.get_audit_kid().map(str::to_string);
+
+        let pem = self.read_secret("VAULT_APP_PRIV_KEY").await?;
+        let key = match alg {
+            Algorithm::ES256 | Algorithm::ES384 => EncodingKey::from_ec_pem(pem.as_bytes()),
+            Algorithm::EdDSA => EncodingKey::from_ed_pem(pem.as_bytes()),
+            _ => EncodingKey::from_rsa_pem(pem.as_bytes())
+        }
+        .map_err(|e| Errors::crazy("Unable to load audit signing key", Some(Box::new(e))))?;
+
+        let claims = AuditSignature { seq: model.seq, hash: model.hash.clone() };
+        model.signature = encode(&header, &claims, &key)
+            .map_err(|e| Errors::crazy("Unable to sign audit entry", Some(Box::new(e))))?;
+        Ok(())
+    }
+
+    async fn verifying_key(&self) -> Outcome<DecodingKey> {
+        let pem = self.read_secret("VAULT_APP_PUB_PKEY").await?;
+        match self.alg()? {
+            Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(pem.as_bytes()),
+            Algorithm::EdDSA => DecodingKey::from_ed_pem(pem.as_bytes()),
+            _ => DecodingKey::from_rsa_pem(pem.as_bytes())
+        }
+        .map_err(|e| Errors::crazy("Unable to load audit verifying key", Some(Box::new(e))))
+    }
+
+    fn verify_entry(
+        &self,
+        report: &mut AuditReport,
+        entry: &audit_entry::Model,
+        key: &DecodingKey
+    ) -> bool {
+        if let Err(e) = self.check(report, entry, key) {
+            report.valid = false;
+            report.broken_at = Some(entry.seq);
+            report.error = Some(e.to_string());
+            return false;
+        }
+        report.entries += 1;
+        report.first_seq.get_or_insert(entry.seq);
+        report.last_seq = Some(entry.seq);
+        report.last_hash = Some(entry.hash.clone());
+        true
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::data::entities::audit_entry;
+
+// Previous hash of the first entry
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditAction {
+    GrantRequested,
+    GrantModified,
+    RequestVoted,
+    RequestApproved,
+    RequestRejected,
+    CredentialIssued,
+    GrantRevoked,
+    KeyRotated,
+    WalletChanged,
+    AdminLogin,
+    OperatorIssued,
+    ParticipantApproved,
+    ParticipantRejected
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            AuditAction::GrantRequested => "grant_requested",
+            AuditAction::GrantModified => "grant_modified",
+            AuditAction::RequestVoted => "request_voted",
+            AuditAction::RequestApproved => "request_approved",
+            AuditAction::RequestRejected => "request_rejected",
+            AuditAction::CredentialIssued => "credential_issued",
+            AuditAction::GrantRevoked => "grant_revoked",
+            AuditAction::KeyRotated => "key_rotated",
+            AuditAction::WalletChanged => "wallet_changed",
+            AuditAction::AdminLogin => "admin_login",
+            AuditAction::OperatorIssued => "operator_issued",
+            AuditAction::ParticipantApproved => "participant_approved",
+            AuditAction::ParticipantRejected => "participant_rejected"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    pub action: AuditAction,
+    pub actor: Option<String>,
+    // What the action was taken on, usually a request id
+    pub subject: Option<String>,
+    pub details: Option<Value>
+}
+
+impl AuditEvent {
+    pub fn new(action: AuditAction, actor: Option<String>, subject: Option<&str>) -> Self {
+        Self { action, actor, subject: subject.map(str::to_string), details: None }
+    }
+
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+}
+
+// Hashed fields in a fixed order, shared by new and stored entries
+#[derive(Serialize)]
+pub struct AuditDigest<'a> {
+    pub seq: i64,
+    pub action: &'a str,
+    pub actor: Option<&'a str>,
+    pub subject: Option<&'a str>,
+    pub details: Option<&'a str>,
+    pub created_at: String,
+    pub prev_hash: &'a str
+}
+
+impl<'a> From<&'a audit_entry::NewModel> for AuditDigest<'a> {
+    fn from(model: &'a audit_entry::NewModel) -> Self {
+        Self {
+            seq: model.seq,
+            action: &model.action,
+            actor: model.actor.as_deref(),
+            subject: model.subject.as_deref(),
+            details: model.details.as_deref(),
+            created_at: model.created_at.format("%Y-%m-%dT%H:%M:%S%.6f").to_string(),
+            prev_hash: &model.prev_hash
+        }
+    }
+}
+
+impl<'a> From<&'a audit_entry::Model> for AuditDigest<'a> {
+    fn from(model: &'a audit_entry::Model) -> Self {
+        Self {
+            seq: model.seq,
+            action: &model.action,
+            actor: model.actor.as_deref(),
+            subject: model.subject.as_deref(),
+            details: model.details.as_deref(),
+            created_at: model.created_at.format("%Y-%m-%dT%H:%M:%S%.6f").to_string(),
+            prev_hash: &model.prev_hash
+        }
+    }
+}
+
+// Claims of the JWS stored as an entry's signature
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditSignature {
+    pub seq: i64,
+    pub hash: String
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct AuditQuery {
+    // Entries after this sequence number
+    pub after: Option<i64>,
+    pub limit: Option<u64>
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AuditReport {
+    pub valid: bool,
+    pub entries: u64,
+    pub first_seq: Option<i64>,
+    pub last_seq: Option<i64>,
+    pub last_hash: Option<String>,
+    // First entry that failed, later ones are not checked
+    pub broken_at: Option<i64>,
+    pub error: Option<String>
+}
+
+impl Default for AuditReport {
+    fn default() -> Self {
+        Self {
+            valid: true,
+            entries: 0,
+            first_seq: None,
+            last_seq: None,
+            last_hash: None,
+            broken_at: None,
+            error: None
+        }
+    }
+}
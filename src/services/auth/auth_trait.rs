@@ -0,0 +1,46 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use serde_json::Value;
+use ymir::errors::{Errors, Outcome};
+
+use super::types::{AdminPrincipal, AdminSession, OperatorRequest};
+use crate::config::auth::AdminRole;
+use crate::data::entities::admin_login;
+
+#[async_trait]
+pub trait AuthTrait: Send + Sync + 'static {
+    fn is_enabled(&self) -> bool;
+    // Accepts a configured API key, an admin session or an OIDC access token
+    async fn authenticate(&self, token: &str) -> Outcome<AdminPrincipal>;
+    fn authorize(&self, principal: &AdminPrincipal, required: &AdminRole) -> Outcome<()>;
+    // The nonce is handed to whoever started the login and checked on every poll
+    fn start_login(&self) -> Outcome<(admin_login::NewModel, String)>;
+    fn check_poll(&self, model: &admin_login::Model, nonce: Option<&str>) -> Outcome<()>;
+    fn complete_login(
+        &self,
+        model: &mut admin_login::Model,
+        vp_token: &str,
+        issuers: &[String]
+    ) -> Outcome<()>;
+    // Records the error, the login stays open for another presentation until it expires
+    fn reject_login(&self, model: &mut admin_login::Model, error: &Errors);
+    fn issue_session(&self, model: &mut admin_login::Model) -> Outcome<AdminSession>;
+    fn operator_issuers(&self) -> Vec<String>;
+    fn operator_claims(&self, payload: &OperatorRequest, issuer_did: &str) -> Outcome<Value>;
+}
@@ -0,0 +1,354 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
+
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{Duration, Utc};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation
+};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use tracing::info;
+use ymir::errors::{Errors, Outcome};
+use ymir::services::client::ClientTrait;
+use ymir::types::vcs::claims_v2::VCClaimsV2;
+use ymir::types::vcs::vc_issuer::VCIssuer;
+use ymir::utils::parse_to_value;
+
+use super::types::{AdminPrincipal, AdminSession, LoginStatus, OperatorRequest, SessionClaims};
+use super::AuthTrait;
+use crate::config::auth::{AdminRole, AuthConfig, AuthConfigTrait, OidcConfig, WalletLoginConfig};
+use crate::data::entities::admin_login;
+use crate::utils::{
+    extract_vp_credentials, jwk_algorithms, trace_headers, vc_has_type, vc_issuer, vc_subject_ids
+};
+
+const SESSION_ISSUER: &str = "heimdall-admin";
+// Seconds between JWKS refetches triggered by unknown kids
+const JWKS_COOLDOWN: u64 = 60;
+
+pub struct AuthService {
+    config: AuthConfig,
+    client: Arc<dyn ClientTrait>,
+    jwks: RwLock<JwkSet>,
+    jwks_fetched_at: Mutex<Option<Instant>>,
+    session_secret: Vec<u8>
+}
+
+impl AuthService {
+    pub fn new(config: AuthConfig, client: Arc<dyn ClientTrait>) -> Self {
+        let jwks = match config.oidc.as_ref().and_then(|oidc| oidc.jwks_file.as_ref()) {
+            Some(path) => {
+                let data = std::fs::read_to_string(path).expect("Unable to read admin JWKS file");
+                serde_json::from_str(&data).expect("Invalid admin JWKS file")
+            }
+            None => JwkSet { keys: Vec::new() }
+        };
+        let secret = config.wallet_login.as_ref().and_then(|login| login.session_secret.as_ref());
+        let session_secret = match secret {
+            Some(secret) => secret.as_bytes().to_vec(),
+            None => {
+                let mut bytes = vec![0u8; 32];
+                SystemRandom::new().fill(&mut bytes).expect("Unable to generate session secret");
+                bytes
+            }
+        };
+        Self {
+            config,
+            client,
+            jwks: RwLock::new(jwks),
+            jwks_fetched_at: Mutex::new(None),
+            session_secret
+        }
+    }
+
+    fn wallet_login(&self) -> Outcome<&WalletLoginConfig> {
+        self.get_wallet_login()
+            .ok_or_else(|| Errors::not_active("Wallet login is not enabled", None))
+    }
+
+    fn nonce_hash(nonce: &str) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(nonce.as_bytes()))
+    }
+
+    fn cached_jwk(&self, kid: Option<&str>) -> Option<Jwk> {
+        let jwks = self.jwks.read().expect("Admin JWKS lock poisoned");
+        match kid {
+            Some(kid) => jwks.find(kid).cloned(),
+            None => jwks.keys.first().cloned()
+        }
+    }
+
+    async fn fetch_jwks(&self, uri: &str) -> Outcome<()> {
+        let res = self.client.get(uri, Some(trace_headers(HeaderMap::new()))).await?;
+        if !res.status().is_success() {
+            let status = Some(res.status());
+            return Err(Errors::consumer(uri, "GET", status, "Unable to fetch admin JWKS", None));
+        }
+        let body = res.text().await.map_err(|e| {
+            Errors::consumer(uri, "GET", None, "Unable to read admin JWKS", Some(Box::new(e)))
+        })?;
+        let jwks: JwkSet = serde_json::from_str(&body)
+            .map_err(|e| Errors::parse("Unable to parse admin JWKS", Some(Box::new(e))))?;
+        *self.jwks.write().expect("Admin JWKS lock poisoned") = jwks;
+        Ok(())
+    }
+
+    async fn find_jwk(&self, oidc: &OidcConfig, kid: Option<&str>) -> Outcome<Jwk> {
+        if let Some(jwk) = self.cached_jwk(kid) {
+            return Ok(jwk);
+        }
+        // Unknown kids may come from a key rotation, but tokens with made up kids must not
+        // make us hammer the identity provider
+        if let Some(uri) = oidc.jwks_uri.as_ref().filter(|_| self.jwks_refresh_due()) {
+            self.fetch_jwks(uri).await?;
+        }
+        self.cached_jwk(kid).ok_or_else(|| Errors::unauthorized("Unknown admin token key", None))
+    }
+
+    fn jwks_refresh_due(&self) -> bool {
+        let mut fetched_at = self.jwks_fetched_at.lock().expect("Admin JWKS lock poisoned");
+        if fetched_at.is_some_and(|at| at.elapsed().as_secs() < JWKS_COOLDOWN) {
+            return false;
+        }
+        *fetched_at = Some(Instant::now());
+        true
+    }
+
+    fn claim_role(oidc: &OidcConfig, claims: &Value) -> Option<AdminRole> {
+        let pointer = format!("/{}", oidc.roles_claim.replace('.', "/"));
+        let names: Vec<&str> = match claims.pointer(&pointer) {
+            Some(Value::Array(values)) => values.iter().filter_map(Value::as_str).collect(),
+            Some(Value::String(value)) => value.split(' ').collect(),
+            _ => Vec::new()
+        };
+        names
+            .into_iter()
+            .filter_map(|name| {
+                oidc.roles.get(name).copied().or_else(|| AdminRole::from_str(name).ok())
+            })
+            .max()
+    }
+
+    async fn verify_oidc(
+        &self,
+        oidc: &OidcConfig,
+        header: Header,
+        token: &str
+    ) -> Outcome<AdminPrincipal> {
+        let jwk = self.find_jwk(oidc, header.kid.as_deref()).await?;
+        let key = DecodingKey::from_jwk(&jwk)
+            .map_err(|e| Errors::unauthorized("Invalid admin token key", Some(Box::new(e))))?;
+
+        // The key decides the algorithm, the token header only has to agree with it
+        let algorithms = jwk_algorithms(&jwk);
+        let Some(alg) = algorithms.first() else {
+            return Err(Errors::unauthorized("Admin token key has no usable algorithm", None));
+        };
+        let mut validation = Validation::new(*alg);
+        validation.algorithms = algorithms;
+        validation.set_issuer(&[&oidc.issuer]);
+        validation.set_audience(&[&oidc.audience]);
+        let claims = decode::<Value>(token, &key, &validation)
+            .map_err(|e| Errors::unauthorized("Invalid admin token", Some(Box::new(e))))?
+            .claims;
+
+        let subject = claims.get("sub").and_then(Value::as_str).unwrap_or("unknown").to_string();
+        let role = Self::claim_role(oidc, &claims)
+            .ok_or_else(|| Errors::unauthorized("Admin token grants no role", None))?;
+        Ok(AdminPrincipal { subject, role })
+    }
+
+    fn verify_session(&self, token: &str) -> Outcome<AdminPrincipal> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&[SESSION_ISSUER]);
+        let claims = decode::<SessionClaims>(
+            token,
+            &DecodingKey::from_secret(&self.session_secret),
+            &validation
+        )
+        .map_err(|e| Errors::unauthorized("Invalid admin session", Some(Box::new(e))))?
+        .claims;
+        Ok(AdminPrincipal { subject: claims.sub, role: claims.role })
+    }
+}
+
+impl AuthConfigTrait for AuthService {
+    fn auth_config(&self) -> &AuthConfig { &self.config }
+}
+
+#[async_trait]
+impl AuthTrait for AuthService {
+    fn is_enabled(&self) -> bool { self.is_auth_enabled() }
+
+    async fn authenticate(&self, token: &str) -> Outcome<AdminPrincipal> {
+        if let Some(api_key) = self.get_api_key(token) {
+            return Ok(AdminPrincipal { subject: api_key.name.clone(), role: api_key.role });
+        }
+        let header = decode_header(token)
+            .map_err(|e| Errors::unauthorized("Invalid admin credentials", Some(Box::new(e))))?;
+        match (header.alg, self.get_oidc()) {
+            (Algorithm::HS256, _) if self.is_wallet_login_active() => self.verify_session(token),
+            (_, Some(oidc)) => self.verify_oidc(oidc, header, token).await,
+            _ => Err(Errors::unauthorized("Invalid admin credentials", None))
+        }
+    }
+
+    fn authorize(&self, principal: &AdminPrincipal, required: &AdminRole) -> Outcome<()> {
+        if principal.role >= *required {
+            return Ok(());
+        }
+        Err(Errors::security(
+            format!("{} needs the {:?} role for this operation", principal.subject, required),
+            None
+        ))
+    }
+
+    fn start_login(&self) -> Outcome<(admin_login::NewModel, String)> {
+        let login = self.wallet_login()?;
+        let mut bytes = [0u8; 32];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .map_err(|_| Errors::crazy("Unable to generate login nonce", None))?;
+        let nonce = URL_SAFE_NO_PAD.encode(bytes);
+        let n_model = admin_login::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            vc_type: login.vc_type.clone(),
+            nonce_hash: Self::nonce_hash(&nonce)
+        };
+        Ok((n_model, nonce))
+    }
+
+    fn check_poll(&self, model: &admin_login::Model, nonce: Option<&str>) -> Outcome<()> {
+        let expected = model.nonce_hash.as_deref().unwrap_or_default();
+        let matches = nonce.is_some_and(|nonce| {
+            let hash = Self::nonce_hash(nonce);
+            !expected.is_empty() && bool::from(hash.as_bytes().ct_eq(expected.as_bytes()))
+        });
+        match matches {
+            true => Ok(()),
+            false => Err(Errors::unauthorized("Login nonce does not match", None))
+        }
+    }
+
+    fn complete_login(
+        &self,
+        model: &mut admin_login::Model,
+        vp_token: &str,
+        issuers: &[String]
+    ) -> Outcome<()> {
+        let (holder, credentials) = extract_vp_credentials(vp_token)?;
+        let operator = credentials
+            .iter()
+            .find(|vc| {
+                vc_has_type(vc, &model.vc_type)
+                    && vc_issuer(vc).is_some_and(|iss| issuers.iter().any(|i| i == iss))
+                    && vc_subject_ids(vc).contains(&holder.as_str())
+            })
+            .ok_or_else(|| {
+                Errors::unauthorized(
+                    format!("Presentation holds no trusted {} for its holder", model.vc_type),
+                    None
+                )
+            })?;
+
+        let role = match operator.pointer("/credentialSubject/role").and_then(Value::as_str) {
+            Some(role) => AdminRole::from_str(role)?,
+            None => AdminRole::Viewer
+        };
+        info!("Operator {} logged in as {}", holder, role);
+        model.subject = Some(holder);
+        model.role = Some(role.to_string());
+        model.status = LoginStatus::Verified.to_string();
+        model.error = None;
+        model.ended_at = Some(Utc::now().naive_utc());
+        Ok(())
+    }
+
+    fn reject_login(&self, model: &mut admin_login::Model, error: &Errors) {
+        model.error = Some(error.to_string());
+    }
+
+    fn issue_session(&self, model: &mut admin_login::Model) -> Outcome<AdminSession> {
+        if model.status != LoginStatus::Verified.to_string() {
+            return Err(Errors::unauthorized("Admin login is not verified", None));
+        }
+        let login = self.wallet_login()?;
+        let subject = model.subject.clone().unwrap_or_default();
+        let role = AdminRole::from_str(model.role.as_deref().unwrap_or_default())?;
+
+        let now = Utc::now().timestamp();
+        let claims = SessionClaims {
+            iss: SESSION_ISSUER.to_string(),
+            sub: subject.clone(),
+            role,
+            iat: now,
+            exp: now + login.session_ttl
+        };
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &claims,
+            &EncodingKey::from_secret(&self.session_secret)
+        )
+        .map_err(|e| Errors::crazy("Unable to sign admin session", Some(Box::new(e))))?;
+
+        model.status = LoginStatus::Consumed.to_string();
+        Ok(AdminSession { token, subject, role, expires_at: claims.exp })
+    }
+
+    fn operator_issuers(&self) -> Vec<String> {
+        self.get_wallet_login().map(|login| login.issuers.clone()).unwrap_or_default()
+    }
+
+    fn operator_claims(&self, payload: &OperatorRequest, issuer_did: &str) -> Outcome<Value> {
+        let login = self.wallet_login()?;
+        let mut subject = json!({ "id": payload.holder, "role": payload.role });
+        if let Some(name) = payload.name.as_ref() {
+            subject["name"] = json!(name);
+        }
+
+        let now = Utc::now();
+        let id = format!("urn:uuid:{}", uuid::Uuid::new_v4());
+        parse_to_value(&VCClaimsV2 {
+            exp: None,
+            iat: None,
+            jti: Some(id.clone()),
+            iss: Some(issuer_did.to_string()),
+            sub: Some(payload.holder.clone()),
+            context: vec!["https://www.w3.org/ns/credentials/v2".to_string()],
+            r#type: vec!["VerifiableCredential".to_string(), login.vc_type.clone()],
+            id,
+            credential_subject: subject,
+            issuer: VCIssuer {
+                id: issuer_did.to_string(),
+                name: Some("RainbowAuthority".to_string())
+            },
+            valid_from: Some(now),
+            valid_until: Some(now + Duration::days(365))
+        })
+    }
+}
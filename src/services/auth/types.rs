@@ -0,0 +1,138 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+
+use axum::http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::config::auth::AdminRole;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AdminPrincipal {
+    pub subject: String,
+    pub role: AdminRole
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SessionClaims {
+    pub iss: String,
+    pub sub: String,
+    pub role: AdminRole,
+    pub iat: i64,
+    pub exp: i64
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct AdminSession {
+    pub token: String,
+    pub subject: String,
+    pub role: AdminRole,
+    pub expires_at: i64
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoginResponse {
+    pub id: String,
+    pub uri: String,
+    // Sent back as the x-login-nonce header when polling
+    pub nonce: String
+}
+
+// The session is only handed out on the first poll after the wallet presented
+#[derive(Serialize, Debug, Clone)]
+pub struct LoginPoll {
+    pub status: String,
+    pub error: Option<String>,
+    pub session: Option<AdminSession>
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OperatorRequest {
+    pub holder: String,
+    pub name: Option<String>,
+    pub role: AdminRole
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoginStatus {
+    Pending,
+    Verified,
+    Consumed,
+    Expired
+}
+
+impl fmt::Display for LoginStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            LoginStatus::Pending => "Pending",
+            LoginStatus::Verified => "Verified",
+            LoginStatus::Consumed => "Consumed",
+            LoginStatus::Expired => "Expired"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+// Admin route groups, matched on the path below the api version
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteGroup {
+    Approver,
+    Minions,
+    Wallet,
+    React,
+    Operators,
+    Audit,
+    // Reviews of self-declared Gaia-X participant data, onboarding itself stays public
+    GaiaReview
+}
+
+impl RouteGroup {
+    pub fn from_path(path: &str) -> Option<Self> {
+        let mut segments = path.trim_start_matches('/').split('/');
+        match (segments.next()?, segments.next()) {
+            ("approver", _) => Some(RouteGroup::Approver),
+            ("minions", _) => Some(RouteGroup::Minions),
+            ("wallet", _) => Some(RouteGroup::Wallet),
+            ("react", _) => Some(RouteGroup::React),
+            ("auth", Some("operators")) => Some(RouteGroup::Operators),
+            ("audit", _) => Some(RouteGroup::Audit),
+            ("gaia", Some("participant")) if path.ends_with("/review") => {
+                Some(RouteGroup::GaiaReview)
+            }
+            _ => None
+        }
+    }
+
+    pub fn required_role(&self, method: &Method, path: &str) -> AdminRole {
+        let read = matches!(*method, Method::GET | Method::HEAD);
+        match self {
+            RouteGroup::Approver if read => AdminRole::Viewer,
+            // Redelivering callbacks is an operational action, not a decision on a request
+            RouteGroup::Approver if path.ends_with("/redeliver") => AdminRole::Admin,
+            RouteGroup::Approver => AdminRole::Approver,
+            RouteGroup::Minions if read => AdminRole::Viewer,
+            RouteGroup::Minions => AdminRole::Admin,
+            RouteGroup::Wallet => AdminRole::Admin,
+            RouteGroup::React => AdminRole::Viewer,
+            RouteGroup::Operators => AdminRole::Admin,
+            RouteGroup::Audit => AdminRole::Admin,
+            RouteGroup::GaiaReview => AdminRole::Approver
+        }
+    }
+}
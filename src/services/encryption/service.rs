@@ -0,0 +1,249 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use p256::ecdh::diffie_hellman;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::{PublicKey, SecretKey};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_128_GCM, AES_256_GCM};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tracing::info;
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+use super::types::DecryptedResponse;
+use super::ResponseEncryptionTrait;
+use crate::config::encryption::{EncryptionConfig, EncryptionConfigTrait};
+
+pub struct ResponseEncryptionService {
+    config: EncryptionConfig,
+    rng: SystemRandom
+}
+
+impl ResponseEncryptionService {
+    pub fn new(config: EncryptionConfig) -> Self { Self { config, rng: SystemRandom::new() } }
+
+    fn secret(key: &str) -> Outcome<SecretKey> {
+        let bytes = Self::decode(key, "response key")?;
+        SecretKey::from_slice(&bytes)
+            .map_err(|_| Errors::crazy("Stored response key is not a P-256 key", None))
+    }
+
+    fn jwk(state: &str, key: &SecretKey) -> Value {
+        let point = key.public_key().to_encoded_point(false);
+        let coordinate = |c: Option<&p256::FieldBytes>| {
+            c.map(|c| URL_SAFE_NO_PAD.encode(c)).unwrap_or_default()
+        };
+        json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "use": "enc",
+            "alg": "ECDH-ES",
+            "kid": state,
+            "x": coordinate(point.x()),
+            "y": coordinate(point.y())
+        })
+    }
+
+    fn decode(part: &str, name: &str) -> Outcome<Vec<u8>> {
+        URL_SAFE_NO_PAD.decode(part).map_err(|e| {
+            Errors::format(BadFormat::Received, format!("Invalid JWE {}", name), Some(Box::new(e)))
+        })
+    }
+
+    fn epk(header: &Value) -> Outcome<PublicKey> {
+        let coordinate = |name: &str| -> Outcome<Vec<u8>> {
+            let value = header
+                .get("epk")
+                .and_then(|epk| epk.get(name))
+                .and_then(Value::as_str)
+                .ok_or_else(|| Errors::format(BadFormat::Received, "JWE has no valid epk", None))?;
+            Self::decode(value, "epk")
+        };
+        let mut sec1 = vec![0x04];
+        sec1.extend(coordinate("x")?);
+        sec1.extend(coordinate("y")?);
+        PublicKey::from_sec1_bytes(&sec1).map_err(|_| {
+            Errors::format(BadFormat::Received, "JWE epk is not a P-256 point", None)
+        })
+    }
+
+    // RFC 7518 4.6.2, single-party Concat KDF over SHA-256
+    fn concat_kdf(z: &[u8], enc: &str, apu: &[u8], apv: &[u8], key_len: usize) -> Vec<u8> {
+        let with_len = |data: &[u8]| [&(data.len() as u32).to_be_bytes()[..], data].concat();
+        let other_info = [
+            with_len(enc.as_bytes()),
+            with_len(apu),
+            with_len(apv),
+            ((key_len * 8) as u32).to_be_bytes().to_vec()
+        ]
+        .concat();
+
+        let mut derived = Vec::new();
+        let mut round: u32 = 1;
+        while derived.len() < key_len {
+            let mut hasher = Sha256::new();
+            hasher.update(round.to_be_bytes());
+            hasher.update(z);
+            hasher.update(&other_info);
+            derived.extend(hasher.finalize());
+            round += 1;
+        }
+        derived.truncate(key_len);
+        derived
+    }
+}
+
+impl EncryptionConfigTrait for ResponseEncryptionService {
+    fn encryption_config(&self) -> &EncryptionConfig { &self.config }
+}
+
+impl ResponseEncryptionTrait for ResponseEncryptionService {
+    fn generate_key(&self) -> Outcome<String> {
+        loop {
+            let mut bytes = [0u8; 32];
+            self.rng.fill(&mut bytes).map_err(|_| Errors::crazy("Unable to generate key", None))?;
+            if let Ok(key) = SecretKey::from_slice(&bytes) {
+                return Ok(URL_SAFE_NO_PAD.encode(key.to_bytes()));
+            }
+        }
+    }
+
+    fn prepare(&self, state: &str, key: &str, inline_uri: &str) -> Outcome<String> {
+        let key = Self::secret(key)?;
+        let (scheme, query) = inline_uri.split_once('?').unwrap_or((inline_uri, ""));
+        let params: Vec<(String, String)> = serde_urlencoded::from_str(query).map_err(|e| {
+            Errors::format(BadFormat::Sent, "Unable to parse verification uri", Some(Box::new(e)))
+        })?;
+
+        let mut metadata = json!({});
+        let mut params: Vec<(String, String)> = params
+            .into_iter()
+            .filter(|(k, v)| match k.as_str() {
+                "client_metadata" => {
+                    metadata = serde_json::from_str(v).unwrap_or(json!({}));
+                    false
+                }
+                "response_mode" => false,
+                _ => true
+            })
+            .collect();
+
+        if let Some(metadata) = metadata.as_object_mut() {
+            let enc = self.get_enc();
+            metadata.insert("jwks".to_string(), json!({ "keys": [Self::jwk(state, &key)] }));
+            metadata.insert("authorization_encrypted_response_alg".to_string(), json!("ECDH-ES"));
+            metadata.insert("authorization_encrypted_response_enc".to_string(), json!(enc));
+            metadata.insert("encrypted_response_enc_values_supported".to_string(), json!([enc]));
+        }
+        params.push(("response_mode".to_string(), "direct_post.jwt".to_string()));
+        params.push(("client_metadata".to_string(), metadata.to_string()));
+
+        let query = serde_urlencoded::to_string(params).map_err(|e| {
+            Errors::format(BadFormat::Sent, "Unable to build verification uri", Some(Box::new(e)))
+        })?;
+        Ok(format!("{}?{}", scheme, query))
+    }
+
+    fn decrypt(&self, key: &str, jwe: &str) -> Outcome<DecryptedResponse> {
+        info!("Decrypting direct_post.jwt response");
+
+        let parts: Vec<&str> = jwe.split('.').collect();
+        let &[protected, encrypted_key, iv, ciphertext, tag] = parts.as_slice() else {
+            return Err(Errors::format(BadFormat::Received, "Response is not a compact JWE", None));
+        };
+        if !encrypted_key.is_empty() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Only ECDH-ES direct key agreement is supported",
+                None
+            ));
+        }
+
+        let header: Value = serde_json::from_slice(&Self::decode(protected, "header")?)
+            .map_err(|e| Errors::parse("Unable to parse JWE header", Some(Box::new(e))))?;
+        let alg = header.get("alg").and_then(Value::as_str).unwrap_or_default();
+        let enc = header.get("enc").and_then(Value::as_str).unwrap_or_default();
+        if alg != "ECDH-ES" {
+            return Err(Errors::format(
+                BadFormat::Received,
+                format!("Unsupported JWE alg '{}'", alg),
+                None
+            ));
+        }
+        let (algorithm, key_len) = match enc {
+            "A128GCM" => (&AES_128_GCM, 16),
+            "A256GCM" => (&AES_256_GCM, 32),
+            _ => {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    format!("Unsupported JWE enc '{}'", enc),
+                    None
+                ))
+            }
+        };
+
+        let secret = Self::secret(key)?;
+        let shared = diffie_hellman(secret.to_nonzero_scalar(), Self::epk(&header)?.as_affine());
+        let party = |name: &str| -> Outcome<Vec<u8>> {
+            match header.get(name).and_then(Value::as_str) {
+                Some(value) => Self::decode(value, name),
+                None => Ok(vec![])
+            }
+        };
+        let cek = Self::concat_kdf(
+            shared.raw_secret_bytes(),
+            enc,
+            &party("apu")?,
+            &party("apv")?,
+            key_len
+        );
+
+        let key = UnboundKey::new(algorithm, &cek)
+            .map_err(|_| Errors::crazy("Unable to build content encryption key", None))?;
+        let nonce = Nonce::try_assume_unique_for_key(&Self::decode(iv, "iv")?)
+            .map_err(|_| Errors::format(BadFormat::Received, "Invalid JWE iv", None))?;
+        let mut data =
+            [Self::decode(ciphertext, "ciphertext")?, Self::decode(tag, "tag")?].concat();
+        let plaintext = LessSafeKey::new(key)
+            .open_in_place(nonce, Aad::from(protected.as_bytes()), &mut data)
+            .map_err(|_| Errors::security("Unable to decrypt response", None))?;
+
+        let response: DecryptedResponse = serde_json::from_slice(plaintext)
+            .map_err(|e| Errors::parse("Unable to parse decrypted response", Some(Box::new(e))))?;
+
+        // DCQL responses carry no presentation_submission, Presentation Exchange ones must
+        let well_formed = response.presentation_submission.as_ref().is_none_or(|submission| {
+            submission.get("definition_id").and_then(Value::as_str).is_some()
+                && submission
+                    .get("descriptor_map")
+                    .and_then(Value::as_array)
+                    .is_some_and(|map| !map.is_empty())
+        });
+        if !well_formed {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "presentation_submission must have a definition_id and a descriptor_map",
+                None
+            ));
+        }
+
+        Ok(response)
+    }
+}
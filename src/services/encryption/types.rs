@@ -0,0 +1,33 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResponsePayload {
+    pub vp_token: Option<String>,
+    pub presentation_submission: Option<String>,
+    pub response: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DecryptedResponse {
+    // A compact VP, or the object DCQL responses key by credential query id
+    pub vp_token: Value,
+    pub presentation_submission: Option<Value>
+}
@@ -0,0 +1,29 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::NaiveDateTime;
+use ymir::errors::Outcome;
+
+use super::types::SessionPhase;
+use crate::data::entities::session_deadline;
+
+pub trait ExpiryTrait: Send + Sync + 'static {
+    fn ttl(&self, phase: &SessionPhase) -> i64;
+    fn deadline(&self, phase: &SessionPhase) -> NaiveDateTime;
+    fn ensure_active(&self, model: &session_deadline::Model, phase: &SessionPhase) -> Outcome<()>;
+    fn cleanup_interval(&self) -> u64;
+}
@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use ymir::errors::{Errors, Outcome};
+
+use super::types::SessionPhase;
+use super::ExpiryTrait;
+use crate::config::session::{SessionConfig, SessionConfigTrait};
+use crate::data::entities::session_deadline;
+
+pub struct ExpiryService {
+    config: SessionConfig
+}
+
+impl ExpiryService {
+    pub fn new(config: SessionConfig) -> Self { Self { config } }
+}
+
+impl SessionConfigTrait for ExpiryService {
+    fn session_config(&self) -> &SessionConfig { &self.config }
+}
+
+impl ExpiryTrait for ExpiryService {
+    fn ttl(&self, phase: &SessionPhase) -> i64 {
+        match phase {
+            SessionPhase::Interaction => self.config.interaction_ttl,
+            SessionPhase::Verification => self.config.verification_ttl,
+            SessionPhase::Issuance => self.config.issuance_ttl
+        }
+    }
+
+    fn deadline(&self, phase: &SessionPhase) -> NaiveDateTime {
+        (Utc::now() + Duration::seconds(self.ttl(phase))).naive_utc()
+    }
+
+    fn ensure_active(&self, model: &session_deadline::Model, phase: &SessionPhase) -> Outcome<()> {
+        let until = match phase {
+            SessionPhase::Interaction => model.interaction_until,
+            SessionPhase::Verification => model.verification_until,
+            SessionPhase::Issuance => model.issuance_until
+        };
+        let expired = model.expired_at.is_some()
+            || until.is_some_and(|until| until < Utc::now().naive_utc());
+        match expired {
+            true => Err(Errors::security(format!("The {} session has expired", phase), None)),
+            false => Ok(())
+        }
+    }
+
+    fn cleanup_interval(&self) -> u64 { self.get_cleanup_interval() }
+}
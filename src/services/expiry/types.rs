@@ -0,0 +1,78 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use ymir::errors::{BadFormat, Errors};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionPhase {
+    Interaction,
+    Verification,
+    Issuance
+}
+
+impl fmt::Display for SessionPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SessionPhase::Interaction => "interaction",
+            SessionPhase::Verification => "verification",
+            SessionPhase::Issuance => "issuance"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionOrigin {
+    // Backed by a vc_request, with or without a GNAP interaction
+    Request,
+    Gaia,
+    Standalone,
+    Login
+}
+
+impl FromStr for SessionOrigin {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Request" => Ok(Self::Request),
+            "Gaia" => Ok(Self::Gaia),
+            "Standalone" => Ok(Self::Standalone),
+            "Login" => Ok(Self::Login),
+            _ => Err(Errors::format(
+                BadFormat::Received,
+                format!("Invalid session origin '{}'", s),
+                None
+            ))
+        }
+    }
+}
+
+impl fmt::Display for SessionOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            SessionOrigin::Request => "Request",
+            SessionOrigin::Gaia => "Gaia",
+            SessionOrigin::Standalone => "Standalone",
+            SessionOrigin::Login => "Login"
+        };
+        write!(f, "{}", s)
+    }
+}
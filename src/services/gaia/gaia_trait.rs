@@ -0,0 +1,39 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use ymir::data::entities::vc_request;
+use ymir::errors::Outcome;
+
+use super::types::{GaiaOnboardRequest, GaiaTerms, GaiaVcType};
+use crate::data::entities::gaia_participant;
+
+pub trait GaiaTrait: Send + Sync + 'static {
+    fn start(&self, payload: &GaiaOnboardRequest) -> Outcome<gaia_participant::NewModel>;
+    fn validate_presentation(
+        &self,
+        model: &mut gaia_participant::Model,
+        vp_token: &str
+    ) -> Outcome<()>;
+    fn start_request(
+        &self,
+        model: &gaia_participant::Model,
+        vc_type: &GaiaVcType
+    ) -> vc_request::NewModel;
+    fn gather_data(&self, model: &gaia_participant::Model) -> Outcome<String>;
+    fn credentials(&self) -> Vec<GaiaVcType>;
+    fn terms(&self) -> GaiaTerms;
+}
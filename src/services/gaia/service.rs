@@ -0,0 +1,155 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tracing::info;
+use ymir::data::entities::vc_request;
+use ymir::errors::{BadFormat, Errors, Outcome};
+use ymir::utils::{get_from_opt, parse_to_string, parse_to_value};
+
+use super::types::{
+    GaiaAddress, GaiaOnboardRequest, GaiaParticipantData, GaiaTerms, GaiaVcType
+};
+use super::GaiaTrait;
+use crate::data::entities::gaia_participant;
+use crate::utils::extract_vp_credentials;
+
+pub struct GaiaService {
+    terms: GaiaTerms
+}
+
+impl GaiaService {
+    pub fn new(terms_and_conditions: String) -> Self {
+        let digest = Sha256::digest(terms_and_conditions.as_bytes());
+        let hash = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        Self { terms: GaiaTerms { text: terms_and_conditions, hash } }
+    }
+
+    fn address(value: &Value, what: &str) -> Outcome<GaiaAddress> {
+        serde_json::from_value(value.clone()).map_err(|e| {
+            let msg = format!("Stored {} is not a valid address", what);
+            Errors::parse(msg, Some(Box::new(e)))
+        })
+    }
+}
+
+impl GaiaTrait for GaiaService {
+    fn start(&self, payload: &GaiaOnboardRequest) -> Outcome<gaia_participant::NewModel> {
+        info!("Managing Gaia-X onboarding request");
+
+        if payload.legal_name.trim().is_empty() {
+            return Err(Errors::format(BadFormat::Received, "Legal name cannot be empty", None));
+        }
+        payload.headquarter_address.validate("Headquarter address")?;
+        payload.legal_address.validate("Legal address")?;
+        if !payload.terms_and_conditions_hash.eq_ignore_ascii_case(&self.terms.hash) {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "Terms and conditions must be accepted with the hash of their current text",
+                None
+            ));
+        }
+
+        Ok(gaia_participant::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            legal_name: payload.legal_name.clone(),
+            headquarter_address: parse_to_value(&payload.headquarter_address)?,
+            legal_address: parse_to_value(&payload.legal_address)?,
+            terms_hash: self.terms.hash.clone()
+        })
+    }
+
+    fn validate_presentation(
+        &self,
+        model: &mut gaia_participant::Model,
+        vp_token: &str
+    ) -> Outcome<()> {
+        info!("Extracting Gaia-X participant data from presentation");
+
+        let (holder, credentials) = extract_vp_credentials(vp_token)?;
+
+        let registration = credentials
+            .iter()
+            .find(|vc| match vc.get("type") {
+                Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).any(|t| {
+                    t.to_lowercase().contains("legalregistrationnumber")
+                }),
+                _ => false
+            })
+            .ok_or_else(|| {
+                Errors::format(
+                    BadFormat::Received,
+                    "Presentation does not contain a legal registration number credential",
+                    None
+                )
+            })?;
+
+        let mut subject = registration.get("credentialSubject").cloned().ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Credential has no credentialSubject", None)
+        })?;
+
+        if subject.get("id").and_then(Value::as_str) != Some(holder.as_str()) {
+            return Err(Errors::security(
+                "Registration number credential was not issued to the presentation holder",
+                None
+            ));
+        }
+
+        if let Some(fields) = subject.as_object_mut() {
+            fields.remove("id");
+            fields.remove("type");
+        }
+
+        model.holder_did = Some(holder);
+        model.registration_number = Some(subject);
+        model.status = "Verified".to_string();
+        Ok(())
+    }
+
+    fn start_request(
+        &self,
+        model: &gaia_participant::Model,
+        vc_type: &GaiaVcType
+    ) -> vc_request::NewModel {
+        vc_request::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            participant_slug: model.legal_name.clone(),
+            cert: None,
+            vc_type: vc_type.to_string(),
+            interact_method: vec![]
+        }
+    }
+
+    fn gather_data(&self, model: &gaia_participant::Model) -> Outcome<String> {
+        let data = GaiaParticipantData {
+            holder_did: get_from_opt(model.holder_did.as_ref(), "holder did")?,
+            legal_name: model.legal_name.clone(),
+            headquarter_address: Self::address(&model.headquarter_address, "headquarter address")?,
+            legal_address: Self::address(&model.legal_address, "legal address")?,
+            registration_number: get_from_opt(
+                model.registration_number.as_ref(),
+                "registration number"
+            )?
+        };
+        parse_to_string(&data)
+    }
+
+    fn credentials(&self) -> Vec<GaiaVcType> { GaiaVcType::all() }
+
+    fn terms(&self) -> GaiaTerms { self.terms.clone() }
+}
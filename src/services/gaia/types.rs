@@ -0,0 +1,177 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum GaiaVcType {
+    LegalParticipant,
+    TermsAndConditions,
+    LegalRegistrationNumber
+}
+
+impl GaiaVcType {
+    pub fn all() -> Vec<Self> {
+        vec![Self::LegalParticipant, Self::TermsAndConditions, Self::LegalRegistrationNumber]
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            GaiaVcType::LegalParticipant => "gx:LegalParticipant",
+            GaiaVcType::TermsAndConditions => "gx:GaiaXTermsAndConditions",
+            GaiaVcType::LegalRegistrationNumber => "gx:legalRegistrationNumber"
+        }
+        .to_string()
+    }
+
+    pub fn subject_id(&self, holder_did: &str) -> String {
+        let fragment = match self {
+            GaiaVcType::LegalParticipant => "legal-participant",
+            GaiaVcType::TermsAndConditions => "terms-and-conditions",
+            GaiaVcType::LegalRegistrationNumber => "registration-number"
+        };
+        format!("{}#{}", holder_did, fragment)
+    }
+}
+
+impl FromStr for GaiaVcType {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "GxLegalParticipant" => Ok(Self::LegalParticipant),
+            "GxTermsAndConditions" => Ok(Self::TermsAndConditions),
+            "GxLegalRegistrationNumber" => Ok(Self::LegalRegistrationNumber),
+            _ => Err(Errors::format(
+                BadFormat::Received,
+                format!("Invalid Gaia-X credential type '{}'", s),
+                None
+            ))
+        }
+    }
+}
+
+impl fmt::Display for GaiaVcType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            GaiaVcType::LegalParticipant => "GxLegalParticipant",
+            GaiaVcType::TermsAndConditions => "GxTermsAndConditions",
+            GaiaVcType::LegalRegistrationNumber => "GxLegalRegistrationNumber"
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GaiaAddress {
+    pub country_subdivision_code: String,
+    pub street_address: Option<String>,
+    pub postal_code: Option<String>,
+    pub locality: Option<String>
+}
+
+impl GaiaAddress {
+    // ISO 3166-2: an alpha-2 country code, a hyphen and up to three alphanumerics
+    pub fn validate(&self, what: &str) -> Outcome<()> {
+        let valid = match self.country_subdivision_code.split_once('-') {
+            Some((country, subdivision)) => {
+                country.len() == 2
+                    && country.bytes().all(|b| b.is_ascii_uppercase())
+                    && (1..=3).contains(&subdivision.len())
+                    && subdivision.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+            }
+            None => false
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(Errors::format(
+                BadFormat::Received,
+                format!(
+                    "{} subdivision '{}' is not an ISO 3166-2 code",
+                    what, self.country_subdivision_code
+                ),
+                None
+            ))
+        }
+    }
+
+    pub fn to_gx(&self) -> Value {
+        let mut address = json!({ "gx:countrySubdivisionCode": self.country_subdivision_code });
+        let optional = [
+            ("vcard:street-address", &self.street_address),
+            ("vcard:postal-code", &self.postal_code),
+            ("vcard:locality", &self.locality)
+        ];
+        if let Some(fields) = address.as_object_mut() {
+            for (key, value) in optional {
+                if let Some(value) = value {
+                    fields.insert(key.to_string(), json!(value));
+                }
+            }
+        }
+        address
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GaiaOnboardRequest {
+    pub legal_name: String,
+    pub headquarter_address: GaiaAddress,
+    pub legal_address: GaiaAddress,
+    // SHA-256 (hex) of the terms and conditions text, accepting that exact version
+    pub terms_and_conditions_hash: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GaiaTerms {
+    pub text: String,
+    pub hash: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GaiaReview {
+    pub approve: bool,
+    pub reason: Option<String>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GaiaOnboardResponse {
+    pub id: String,
+    pub uri: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GaiaCredentialRef {
+    pub vc_type: String,
+    pub id: String,
+    pub uri: String
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GaiaParticipantData {
+    pub holder_did: String,
+    pub legal_name: String,
+    pub headquarter_address: GaiaAddress,
+    pub legal_address: GaiaAddress,
+    pub registration_number: Value
+}
@@ -23,6 +23,9 @@ use ymir::types::gnap::grant_request::{GrantRequest, Interact4GR};
 use ymir::types::gnap::grant_response::GrantResponse;
 use ymir::types::vcs::VcType;
 
+use super::types::{GnapResponse, GrantCredential};
+use crate::data::entities::interaction_start;
+
 #[async_trait]
 pub trait GateKeeperTrait: Send + Sync + 'static {
     fn start(
@@ -31,6 +34,9 @@ pub trait GateKeeperTrait: Send + Sync + 'static {
     ) -> Outcome<(vc_request::NewModel, recv_interaction::NewModel)>;
     fn validate_acc_req(&self, payload: &GrantRequest) -> Outcome<Interact4GR>;
     fn validate_vc_to_issue(&self, vc_type: &VcType) -> Outcome<()>;
+    /// Draws the server nonce and stores the interaction hash, returning the nonce
+    fn seal_finish(&self, int_model: &mut recv_interaction::Model) -> Outcome<String>;
+    fn validate_cont_token(&self, int_model: &recv_interaction::Model, token: &str) -> Outcome<()>;
     fn validate_cont_req(
         &self,
         int_model: &recv_interaction::Model,
@@ -44,6 +50,37 @@ pub trait GateKeeperTrait: Send + Sync + 'static {
         req_model: &mut vc_request::Model,
         int_model: &recv_interaction::Model
     ) -> Outcome<Value>;
-    async fn notify_minion(&self, int_model: &recv_interaction::Model, body: Value) -> Outcome<()>;
+    fn rotate_continuation(
+        &self,
+        int_model: &mut recv_interaction::Model,
+        cont_id: &str,
+        wait: Option<u64>
+    ) -> Value;
+    fn requested_vc_types(&self, access: &Value) -> Outcome<Vec<VcType>>;
+    fn grant_credentials(&self, access: &Value) -> Outcome<Vec<GrantCredential>>;
+    fn start_linked_request(
+        &self,
+        req_model: &vc_request::Model,
+        vc_type: &VcType
+    ) -> vc_request::NewModel;
     fn manage_cross_user(&self, model: &recv_interaction::Model) -> Outcome<GrantResponse>;
+    fn start_interaction(
+        &self,
+        int_model: &recv_interaction::Model,
+        verification_uri: &str
+    ) -> Outcome<interaction_start::NewModel>;
+    fn interaction_response(
+        &self,
+        int_model: &recv_interaction::Model,
+        start_model: &interaction_start::Model,
+        verification_uri: &str,
+        expires_in: i64
+    ) -> Outcome<GnapResponse>;
+    fn interaction_uri(&self, id: &str) -> String;
+    fn interaction_page(
+        &self,
+        req_model: &vc_request::Model,
+        start_model: &interaction_start::Model
+    ) -> String;
+    fn device_page(&self, error: Option<&str>) -> String;
 }
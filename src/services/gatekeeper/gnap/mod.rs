@@ -16,5 +16,6 @@
  */
 
 pub mod config;
+mod pages;
 mod service;
 pub use service::GnapService;
@@ -0,0 +1,97 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+const STYLE: &str = "body{font-family:sans-serif;max-width:32rem;margin:4rem auto;padding:0 1rem}\
+    a.button,button{display:inline-block;padding:.6rem 1.2rem;border-radius:.4rem;border:0;\
+    background:#1f2937;color:#fff;text-decoration:none;font-size:1rem;cursor:pointer}\
+    button.secondary{background:#e5e7eb;color:#111}code{word-break:break-all}\
+    input{font-size:1.4rem;letter-spacing:.2rem;text-transform:uppercase;padding:.4rem}";
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn layout(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+        <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+        <title>{}</title><style>{}</style></head><body>{}</body></html>",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+pub fn interaction(
+    id: &str,
+    participant: &str,
+    vc_type: &str,
+    verification_uri: &str,
+    status: &str
+) -> String {
+    let body = match status {
+        "Pending" => format!(
+            "<h1>Authorization request</h1>\
+            <p><strong>{participant}</strong> is requesting a <strong>{vc_type}</strong> \
+            credential on your behalf.</p>\
+            <p>Present your credentials with your wallet to authenticate and consent.</p>\
+            <p><a class=\"button\" href=\"{uri}\">Open wallet</a></p>\
+            <p>Or copy this request into your wallet:<br><code>{uri}</code></p>\
+            <form method=\"post\" action=\"{id}/deny\">\
+            <button class=\"secondary\" type=\"submit\">Decline</button></form>\
+            <p id=\"state\"></p>\
+            <script>\
+            const poll = async () => {{\
+              const res = await fetch(\"{id}/status\");\
+              if (!res.ok) return;\
+              const body = await res.json();\
+              if (body.status === \"Pending\") return;\
+              clearInterval(timer);\
+              if (body.redirect) {{ window.location.href = body.redirect; return; }}\
+              document.getElementById(\"state\").textContent = body.status === \"Verified\"\
+                ? \"Done. You can close this window.\" : \"The request was declined.\";\
+            }};\
+            const timer = setInterval(poll, 3000);\
+            </script>",
+            participant = escape(participant),
+            vc_type = escape(vc_type),
+            uri = escape(verification_uri),
+            id = escape(id)
+        ),
+        "Verified" => "<h1>Done</h1><p>You can close this window.</p>".to_string(),
+        _ => "<h1>Request declined</h1><p>You can close this window.</p>".to_string()
+    };
+    layout("Authorization request", &body)
+}
+
+pub fn device(error: Option<&str>) -> String {
+    let error = error.map(|e| format!("<p><strong>{}</strong></p>", escape(e))).unwrap_or_default();
+    let body = format!(
+        "<h1>Connect a device</h1>\
+        <p>Enter the code shown on your device.</p>{}\
+        <form method=\"get\" action=\"device\">\
+        <p><input name=\"code\" autocomplete=\"off\" autofocus required></p>\
+        <button type=\"submit\">Continue</button></form>",
+        error
+    );
+    layout("Connect a device", &body)
+}
@@ -18,7 +18,8 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use serde_json::Value;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{json, Value};
 use tracing::info;
 use ymir::config::traits::HostsConfigTrait;
 use ymir::config::types::HostType;
@@ -33,8 +34,14 @@ use ymir::types::vcs::VcType;
 use ymir::utils::{create_opaque_token, json_headers, parse_to_value};
 
 use super::config::{GnapConfig, GnapConfigTrait};
+use super::pages;
 use crate::config::role::{AuthorityRole, RoleConfigTrait};
+use crate::data::entities::interaction_start;
+use crate::services::gatekeeper::types::{
+    GnapResponse, GrantCredential, HashMethod, InteractResponse, InteractionMode, UserCodeUri
+};
 use crate::services::gatekeeper::GateKeeperTrait;
+use crate::utils::trace_headers;
 
 pub struct GnapService {
     config: GnapConfig,
@@ -45,6 +52,32 @@ impl GnapService {
     pub fn new(config: GnapConfig, client: Arc<dyn ClientTrait>) -> Self {
         GnapService { config, client }
     }
+
+    fn gate_url(&self) -> String {
+        format!(
+            "{}{}/gate",
+            self.config.hosts().get_host(HostType::Http),
+            self.config.get_api_path()
+        )
+    }
+
+    // Consonants only, so codes are easy to type and never spell words (RFC 8628 §6.1)
+    fn user_code() -> Outcome<String> {
+        const ALPHABET: &[u8] = b"BCDFGHJKLMNPQRSTVWXZ";
+        let rng = SystemRandom::new();
+        let mut code = String::with_capacity(8);
+        while code.len() < 8 {
+            let mut byte = [0u8; 1];
+            rng.fill(&mut byte)
+                .map_err(|_| Errors::crazy("Unable to generate a user code", None))?;
+            if (byte[0] as usize) < 240 {
+                code.push(ALPHABET[byte[0] as usize % ALPHABET.len()] as char);
+            }
+        }
+        Ok(code)
+    }
+
+    fn display_code(code: &str) -> String { format!("{}-{}", &code[..4], &code[4..]) }
 }
 
 #[async_trait]
@@ -61,16 +94,21 @@ impl GateKeeperTrait for GnapService {
         let cert = client.key.cert;
         let participant_slug = client.class_id.unwrap_or("Unknown".to_string());
 
-        let vc_type = payload.access_token.access.datatypes.as_ref().ok_or_else(|| {
+        let datatypes = payload.access_token.access.datatypes.as_ref().ok_or_else(|| {
             Errors::format(BadFormat::Received, "No field datatypes in the request", None)
         })?;
-        let vc_type = vc_type
+        let vc_types = datatypes
+            .iter()
+            .map(|datatype| VcType::from_conf(datatype))
+            .collect::<Outcome<Vec<_>>>()?;
+        for vc_type in &vc_types {
+            self.validate_vc_to_issue(vc_type)?;
+        }
+        // The first datatype is issued through this request, the rest through linked ones
+        let vc_type = vc_types
             .first()
             .ok_or_else(|| Errors::format(BadFormat::Received, "Datatypes are empty", None))?;
 
-        let vc_type = VcType::from_conf(vc_type)?;
-        self.validate_vc_to_issue(&vc_type)?;
-
         let new_request_model = vc_request::NewModel {
             id: id.clone(),
             participant_slug,
@@ -79,11 +117,7 @@ impl GateKeeperTrait for GnapService {
             interact_method: interact.start.clone()
         };
 
-        let host_url = format!(
-            "{}{}/gate",
-            self.config.hosts().get_host(HostType::Http),
-            self.config.get_api_path()
-        );
+        let host_url = self.gate_url();
         let continue_endpoint = format!("{}/continue", &host_url);
         let grant_endpoint = format!("{}/access", &host_url);
         let continue_token = create_opaque_token();
@@ -117,13 +151,17 @@ impl GateKeeperTrait for GnapService {
         })?;
 
         let start = &interact.start;
-        if !&start.contains(&"cross-user".to_string()) && !&start.contains(&"oidc4vp".to_string()) {
+        if !&start.contains(&InteractStart::CrossUser.to_string())
+            && !&start.contains(&InteractStart::Oidc4VP.to_string())
+            && InteractionMode::select(start).is_empty()
+        {
             return Err(Errors::not_impl("Interact method not supported yet", None));
         }
 
         interact.finish.uri.as_ref().ok_or_else(|| {
             Errors::format(BadFormat::Received, "Interact method does not have an uri", None)
         })?;
+        HashMethod::from_request(interact.finish.hash_method.as_deref())?;
 
         Ok(interact.clone())
     }
@@ -156,6 +194,11 @@ impl GateKeeperTrait for GnapService {
 
             AuthorityRole::EcoAuthority => Ok(()),
 
+            AuthorityRole::GaiaAuthority => Err(Errors::unauthorized(
+                "As a gaia authority we only issue vcs through the Gaia-X onboarding flow",
+                None
+            )),
+
             AuthorityRole::ClearingHouse | AuthorityRole::ClearingHouseProxy => {
                 // TODO
                 Ok(())
@@ -181,6 +224,24 @@ impl GateKeeperTrait for GnapService {
             ));
         }
 
+        self.validate_cont_token(int_model, token)
+    }
+
+    fn seal_finish(&self, int_model: &mut recv_interaction::Model) -> Outcome<String> {
+        let method = HashMethod::from_request(int_model.hash_method.as_deref())?;
+        info!("Computing {} interaction hash", method);
+
+        let server_nonce = create_opaque_token();
+        int_model.hash = method.interaction_hash(
+            &int_model.client_nonce,
+            &server_nonce,
+            &int_model.interact_ref,
+            &int_model.grant_endpoint
+        );
+        Ok(server_nonce)
+    }
+
+    fn validate_cont_token(&self, int_model: &recv_interaction::Model, token: &str) -> Outcome<()> {
         if token != int_model.continue_token {
             return Err(Errors::security(
                 format!("Token '{}' does not match '{}'", token, int_model.continue_token),
@@ -189,6 +250,71 @@ impl GateKeeperTrait for GnapService {
         }
         Ok(())
     }
+
+    fn rotate_continuation(
+        &self,
+        int_model: &mut recv_interaction::Model,
+        cont_id: &str,
+        wait: Option<u64>
+    ) -> Value {
+        info!("Rotating continuation token");
+
+        int_model.continue_token = create_opaque_token();
+        let mut continuation = json!({
+            "access_token": { "value": int_model.continue_token },
+            "uri": format!("{}/{}", int_model.continue_endpoint, cont_id)
+        });
+        if let Some(wait) = wait {
+            continuation["wait"] = json!(wait);
+        }
+        continuation
+    }
+
+    fn requested_vc_types(&self, access: &Value) -> Outcome<Vec<VcType>> {
+        let datatypes = access
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|right| right.get("datatypes").and_then(Value::as_array))
+            .flatten()
+            .filter_map(Value::as_str);
+
+        let mut vc_types: Vec<VcType> = Vec::new();
+        for datatype in datatypes {
+            let vc_type = VcType::from_conf(&datatype.to_string())?;
+            self.validate_vc_to_issue(&vc_type)?;
+            if !vc_types.iter().any(|known| known.to_string() == vc_type.to_string()) {
+                vc_types.push(vc_type);
+            }
+        }
+        Ok(vc_types)
+    }
+
+    fn grant_credentials(&self, access: &Value) -> Outcome<Vec<GrantCredential>> {
+        let credentials = self
+            .requested_vc_types(access)?
+            .iter()
+            .map(|vc_type| GrantCredential { vc_type: vc_type.to_string(), id: None, uri: None })
+            .collect();
+        Ok(credentials)
+    }
+
+    fn start_linked_request(
+        &self,
+        req_model: &vc_request::Model,
+        vc_type: &VcType
+    ) -> vc_request::NewModel {
+        info!("Linking {} request to grant {}", vc_type, req_model.id);
+
+        vc_request::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            participant_slug: req_model.participant_slug.clone(),
+            cert: req_model.cert.clone(),
+            vc_type: vc_type.to_string(),
+            // Linked requests are finished through the grant's own interaction
+            interact_method: vec![]
+        }
+    }
     async fn end_verification(&self, model: &recv_interaction::Model) -> Outcome<Option<String>> {
         info!("Ending verification");
 
@@ -205,7 +331,8 @@ impl GateKeeperTrait for GnapService {
                 interact_ref: model.interact_ref.clone(),
                 hash: model.hash.clone()
             };
-            self.client.post(&url, Some(json_headers()), Body::json(&body)?).await?;
+            let headers = trace_headers(json_headers());
+            self.client.post(&url, Some(headers), Body::json(&body)?).await?;
 
             Ok(None)
         } else {
@@ -241,23 +368,6 @@ impl GateKeeperTrait for GnapService {
         }
     }
 
-    async fn notify_minion(&self, int_model: &recv_interaction::Model, body: Value) -> Outcome<()> {
-        let res = self.client.post(&int_model.uri, Some(json_headers()), Body::Json(body)).await?;
-
-        if res.status().is_success() {
-            info!("Minion received callback successfully");
-            Ok(())
-        } else {
-            Err(Errors::consumer(
-                &int_model.uri,
-                "POST",
-                Some(res.status()),
-                "Minion did not receive callback successfully",
-                None
-            ))
-        }
-    }
-
     fn manage_cross_user(&self, model: &recv_interaction::Model) -> Outcome<GrantResponse> {
         info!("Managing cross-user request");
         if self.config.is_cert_allowed() {
@@ -269,4 +379,75 @@ impl GateKeeperTrait for GnapService {
             ))
         }
     }
+
+    fn start_interaction(
+        &self,
+        int_model: &recv_interaction::Model,
+        verification_uri: &str
+    ) -> Outcome<interaction_start::NewModel> {
+        info!("Starting hosted interaction");
+
+        let modes = InteractionMode::select(&int_model.start);
+        let user_code = match modes.iter().any(|m| *m != InteractionMode::Redirect) {
+            true => Some(Self::user_code()?),
+            false => None
+        };
+        Ok(interaction_start::NewModel {
+            id: int_model.id.clone(),
+            user_code,
+            verification_uri: verification_uri.to_string()
+        })
+    }
+
+    fn interaction_response(
+        &self,
+        int_model: &recv_interaction::Model,
+        start_model: &interaction_start::Model,
+        verification_uri: &str,
+        expires_in: i64
+    ) -> Outcome<GnapResponse> {
+        // ymir builds the continuation member, the interaction members are ours
+        let uri = verification_uri.to_string();
+        let base = GrantResponse::new(&InteractStart::Oidc4VP, int_model, Some(&uri));
+        let mut response = GnapResponse::from_grant(&base)?;
+
+        let mut interact = InteractResponse { expires_in: Some(expires_in), ..Default::default() };
+        let code = start_model.user_code.as_deref().map(Self::display_code);
+        for mode in InteractionMode::select(&int_model.start) {
+            match mode {
+                InteractionMode::Redirect => {
+                    interact.redirect = Some(self.interaction_uri(&int_model.id))
+                }
+                InteractionMode::UserCode => interact.user_code = code.clone(),
+                InteractionMode::UserCodeUri => {
+                    interact.user_code_uri = code.clone().map(|code| UserCodeUri {
+                        code,
+                        uri: format!("{}/device", self.gate_url())
+                    })
+                }
+            }
+        }
+        response.interact = Some(interact);
+        Ok(response)
+    }
+
+    fn interaction_uri(&self, id: &str) -> String {
+        format!("{}/interact/{}", self.gate_url(), id)
+    }
+
+    fn interaction_page(
+        &self,
+        req_model: &vc_request::Model,
+        start_model: &interaction_start::Model
+    ) -> String {
+        pages::interaction(
+            &start_model.id,
+            &req_model.participant_slug,
+            &req_model.vc_type,
+            &start_model.verification_uri,
+            &start_model.status
+        )
+    }
+
+    fn device_page(&self, error: Option<&str>) -> String { pages::device(error) }
 }
@@ -17,4 +17,5 @@
 
 pub mod gatekeeper_trait;
 pub mod gnap;
+pub mod types;
 pub use gatekeeper_trait::GateKeeperTrait;
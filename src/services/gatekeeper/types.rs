@@ -0,0 +1,357 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256, Sha512};
+use sha3::Sha3_512;
+use ymir::errors::{BadFormat, Errors, Outcome};
+use ymir::types::gnap::grant_request::GrantRequest;
+use ymir::types::gnap::grant_response::GrantResponse;
+use ymir::utils::parse_to_value;
+
+// Interaction start modes served by Heimdall itself (RFC 9635 §2.5.1)
+#[derive(Debug, Clone, PartialEq)]
+pub enum InteractionMode {
+    Redirect,
+    UserCode,
+    UserCodeUri
+}
+
+impl InteractionMode {
+    pub fn select(start: &[String]) -> Vec<Self> {
+        start.iter().filter_map(|s| Self::from_str(s).ok()).collect()
+    }
+}
+
+impl FromStr for InteractionMode {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "redirect" => Ok(Self::Redirect),
+            "user_code" => Ok(Self::UserCode),
+            "user_code_uri" => Ok(Self::UserCodeUri),
+            _ => Err(Errors::not_impl(format!("Interact start '{}' not supported", s), None))
+        }
+    }
+}
+
+impl fmt::Display for InteractionMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            InteractionMode::Redirect => "redirect",
+            InteractionMode::UserCode => "user_code",
+            InteractionMode::UserCodeUri => "user_code_uri"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+// Interaction finish hash algorithms (RFC 9635 §4.2.3), sha-256 when the client names none
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum HashMethod {
+    #[default]
+    Sha256,
+    Sha512,
+    Sha3_512
+}
+
+impl HashMethod {
+    pub fn from_request(hash_method: Option<&str>) -> Outcome<Self> {
+        hash_method.map_or(Ok(Self::default()), Self::from_str)
+    }
+
+    pub fn digest(&self, input: &[u8]) -> Vec<u8> {
+        match self {
+            HashMethod::Sha256 => Sha256::digest(input).to_vec(),
+            HashMethod::Sha512 => Sha512::digest(input).to_vec(),
+            HashMethod::Sha3_512 => Sha3_512::digest(input).to_vec()
+        }
+    }
+
+    pub fn interaction_hash(
+        &self,
+        client_nonce: &str,
+        server_nonce: &str,
+        interact_ref: &str,
+        grant_endpoint: &str
+    ) -> String {
+        let input = [client_nonce, server_nonce, interact_ref, grant_endpoint].join("\n");
+        URL_SAFE_NO_PAD.encode(self.digest(input.as_bytes()))
+    }
+}
+
+impl FromStr for HashMethod {
+    type Err = Errors;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha-256" => Ok(Self::Sha256),
+            "sha-512" => Ok(Self::Sha512),
+            "sha3-512" => Ok(Self::Sha3_512),
+            _ => Err(Errors::format(
+                BadFormat::Received,
+                format!("Interaction hash method '{}' not supported", s),
+                None
+            ))
+        }
+    }
+}
+
+impl fmt::Display for HashMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            HashMethod::Sha256 => "sha-256",
+            HashMethod::Sha512 => "sha-512",
+            HashMethod::Sha3_512 => "sha3-512"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserCodeUri {
+    pub code: String,
+    pub uri: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InteractResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_code_uri: Option<UserCodeUri>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AccessTokenResponse {
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manage: Option<Value>,
+    #[serde(default)]
+    pub access: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>
+}
+
+// Superset of ymir's GrantResponse; unknown members are carried over untouched
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GnapResponse {
+    #[serde(rename = "continue", skip_serializing_if = "Option::is_none")]
+    pub continue_: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<AccessTokenResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interact: Option<InteractResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>
+}
+
+impl GnapResponse {
+    pub fn from_grant(response: &GrantResponse) -> Outcome<Self> {
+        serde_json::from_value(parse_to_value(response)?)
+            .map_err(|e| Errors::parse("Unable to parse grant response", Some(Box::new(e))))
+    }
+
+    // The finish nonce is ours, since the interaction hash is computed by Heimdall
+    pub fn with_finish(mut self, server_nonce: String) -> Self {
+        self.interact.get_or_insert_with(Default::default).finish = Some(server_nonce);
+        self
+    }
+
+    pub fn error(description: String) -> Self {
+        Self::from_grant(&GrantResponse::error(description.clone())).unwrap_or_else(|_| Self {
+            error: Some(Value::String(description)),
+            ..Default::default()
+        })
+    }
+}
+
+// `access` may list several rights (RFC 9635 §8) while ymir's GrantRequest models one, so
+// the rights are kept apart and ymir gets a single right carrying every datatype
+#[derive(Debug, Clone)]
+pub struct GnapGrantRequest {
+    pub request: GrantRequest,
+    pub access: Value
+}
+
+impl GnapGrantRequest {
+    pub fn parse(body: &[u8]) -> Outcome<Self> {
+        let mut value: Value = serde_json::from_slice(body).map_err(|e| {
+            Errors::format(BadFormat::Received, "Invalid grant request", Some(Box::new(e)))
+        })?;
+        let access = value.pointer("/access_token/access").cloned().unwrap_or_default();
+
+        if let Value::Array(rights) = &access {
+            let mut datatypes: Vec<Value> = Vec::new();
+            for datatype in rights
+                .iter()
+                .filter_map(|right| right.get("datatypes").and_then(Value::as_array))
+                .flatten()
+            {
+                if !datatypes.contains(datatype) {
+                    datatypes.push(datatype.clone());
+                }
+            }
+            let mut merged = rights.iter().find(|right| right.is_object()).cloned().ok_or_else(
+                || Errors::format(BadFormat::Received, "No access right to grant", None)
+            )?;
+            merged["datatypes"] = Value::Array(datatypes);
+            value["access_token"]["access"] = merged;
+        }
+
+        let request = serde_json::from_value(value).map_err(|e| {
+            Errors::format(BadFormat::Received, "Invalid grant request", Some(Box::new(e)))
+        })?;
+        Ok(Self { request, access })
+    }
+}
+
+// One of the credentials a grant asked for; the first one is issued through the grant's own
+// request and the rest through linked requests created once the grant is continued
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GrantCredential {
+    pub vc_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>
+}
+
+impl GrantCredential {
+    pub fn list(stored: Option<&Value>) -> Outcome<Vec<Self>> {
+        let Some(stored) = stored else {
+            return Ok(Vec::new());
+        };
+        serde_json::from_value(stored.clone())
+            .map_err(|e| Errors::parse("Unable to parse grant credentials", Some(Box::new(e))))
+    }
+
+    // Every type the grant asks for, starting with the primary one
+    pub fn vc_types(primary: &str, stored: Option<&Value>) -> Outcome<Vec<String>> {
+        let mut vc_types = vec![primary.to_string()];
+        for credential in Self::list(stored)? {
+            if !vc_types.contains(&credential.vc_type) {
+                vc_types.push(credential.vc_type);
+            }
+        }
+        Ok(vc_types)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AccessRequest {
+    pub access: Value
+}
+
+// PATCH body of a continuation (RFC 9635 §5.3)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GrantModification {
+    pub access_token: Option<AccessRequest>
+}
+
+#[derive(Debug, Clone)]
+pub struct Continuation {
+    pub vc_uri: String,
+    pub response: GnapResponse
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InteractionStatus {
+    pub status: String,
+    pub redirect: Option<String>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeviceQuery {
+    pub code: Option<String>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example values from RFC 9635 §4.2.3
+    fn hash(method: &HashMethod) -> String {
+        method.interaction_hash(
+            "VJLO6A4CATR0KRO",
+            "MBDOFXG4Y5CVJCX821LH",
+            "4IFWWIKYB2PQ6U56NL1",
+            "https://server.example.com/tx"
+        )
+    }
+
+    #[test]
+    fn sha_256_interaction_hash() {
+        let method = HashMethod::from_request(Some("sha-256")).unwrap();
+        assert_eq!(hash(&method), "x-gguKWTj8rQf7d7i3w3UhzvuJ5bpOlKyAlVpLxBffY");
+    }
+
+    #[test]
+    fn sha_512_interaction_hash() {
+        let method = HashMethod::from_request(Some("sha-512")).unwrap();
+        assert_eq!(
+            hash(&method),
+            "454VR2f6OAHg3PDng-iAbfPEeBCI70VP0KcpleQZBC5TfJRbNOgz0RGVWI_gLaQXwRFst3CyzWPS_IPRDZ39fw"
+        );
+    }
+
+    #[test]
+    fn sha3_512_interaction_hash() {
+        let method = HashMethod::from_request(Some("sha3-512")).unwrap();
+        assert_eq!(
+            hash(&method),
+            "pyUkVJSmpqSJMaDYsk5G8WCvgY91l-agUPe1wgn-cc5rUtN69gPI2-S_s-Eswed8iB4PJ_a5Hg6DNi7qGgKwSQ"
+        );
+    }
+
+    #[test]
+    fn defaults_to_sha_256() {
+        assert_eq!(HashMethod::from_request(None).unwrap(), HashMethod::Sha256);
+    }
+
+    #[test]
+    fn rejects_unsupported_hash_method() {
+        assert!(HashMethod::from_request(Some("md5")).is_err());
+        assert!(HashMethod::from_request(Some("SHA-256")).is_err());
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use ymir::config::traits::{ApiConfigTrait, HostsConfigTrait};
+use ymir::config::types::CommonHostsConfig;
+
+use crate::config::jar::{JarConfig, JarConfigTrait};
+use crate::config::CoreApplicationConfig;
+
+pub struct JarServiceConfig {
+    hosts: CommonHostsConfig,
+    jar: JarConfig,
+    api_path: String
+}
+
+impl JarServiceConfig {
+    pub fn get_api_path(&self) -> &str { &self.api_path }
+}
+
+impl From<CoreApplicationConfig> for JarServiceConfig {
+    fn from(config: CoreApplicationConfig) -> Self {
+        Self {
+            hosts: config.hosts().clone(),
+            jar: config.jar_config().clone(),
+            api_path: config.get_api_version()
+        }
+    }
+}
+
+impl HostsConfigTrait for JarServiceConfig {
+    fn hosts(&self) -> &CommonHostsConfig { &self.hosts }
+}
+
+impl JarConfigTrait for JarServiceConfig {
+    fn jar_config(&self) -> &JarConfig { &self.jar }
+}
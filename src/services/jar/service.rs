@@ -0,0 +1,145 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::{Map, Value};
+use tracing::info;
+use ymir::config::traits::HostsConfigTrait;
+use ymir::config::types::HostType;
+use ymir::errors::{BadFormat, Errors, Outcome};
+use ymir::services::vault::{VaultService, VaultTrait};
+use ymir::types::secrets::StringHelper;
+use ymir::utils::expect_from_env;
+
+use super::{JarServiceConfig, JarTrait};
+use crate::config::jar::{ClientIdScheme, JarConfigTrait};
+
+pub struct JarService {
+    config: JarServiceConfig,
+    vault: Arc<VaultService>
+}
+
+impl JarService {
+    pub fn new(config: JarServiceConfig, vault: Arc<VaultService>) -> Self {
+        Self { config, vault }
+    }
+
+    fn split_uri(inline_uri: &str) -> (&str, &str) {
+        inline_uri.split_once('?').unwrap_or((inline_uri, ""))
+    }
+
+    fn claims(&self, inline_uri: &str) -> Outcome<Map<String, Value>> {
+        let (_, query) = Self::split_uri(inline_uri);
+        let params: Vec<(String, String)> = serde_urlencoded::from_str(query).map_err(|e| {
+            Errors::format(BadFormat::Sent, "Unable to parse verification uri", Some(Box::new(e)))
+        })?;
+
+        let mut claims = Map::new();
+        for (key, value) in params {
+            let value = match value.starts_with('{') || value.starts_with('[') {
+                true => serde_json::from_str(&value).unwrap_or(Value::String(value)),
+                false => Value::String(value)
+            };
+            claims.insert(key, value);
+        }
+
+        let now = Utc::now().timestamp();
+        let client_id = self.config.get_client_id().to_string();
+        claims.insert("client_id".to_string(), Value::String(client_id.clone()));
+        claims.insert(
+            "client_id_scheme".to_string(),
+            Value::String(self.config.get_client_id_scheme().to_string())
+        );
+        claims.insert("iss".to_string(), Value::String(client_id));
+        claims.insert("aud".to_string(), Value::String("https://self-issued.me/v2".to_string()));
+        claims.insert("iat".to_string(), Value::from(now));
+        claims.insert("exp".to_string(), Value::from(now + self.config.jar_config().ttl));
+        Ok(claims)
+    }
+
+    async fn read_secret(&self, env: &str) -> Outcome<String> {
+        let path = expect_from_env(env);
+        let secret: StringHelper = self.vault.read(None, &path).await?;
+        Ok(secret.data().to_string())
+    }
+
+    fn encoding_key(alg: &Algorithm, pem: &[u8]) -> Outcome<EncodingKey> {
+        let key = match alg {
+            Algorithm::ES256 | Algorithm::ES384 => EncodingKey::from_ec_pem(pem),
+            Algorithm::EdDSA => EncodingKey::from_ed_pem(pem),
+            _ => EncodingKey::from_rsa_pem(pem)
+        };
+        key.map_err(|e| {
+            Errors::crazy("Unable to load request object signing key", Some(Box::new(e)))
+        })
+    }
+
+    fn pem_to_der_b64(pem: &str) -> String {
+        pem.lines().filter(|line| !line.starts_with("-----")).map(str::trim).collect()
+    }
+}
+
+#[async_trait]
+impl JarTrait for JarService {
+    fn request_reference(&self, inline_uri: &str, state: &str) -> Outcome<String> {
+        let (scheme, _) = Self::split_uri(inline_uri);
+        let request_uri = format!(
+            "{}{}/verifier/request/{}",
+            self.config.hosts().get_host(HostType::Http),
+            self.config.get_api_path(),
+            state
+        );
+        let query = serde_urlencoded::to_string([
+            ("client_id", self.config.get_client_id()),
+            ("request_uri", request_uri.as_str())
+        ])
+        .map_err(|e| {
+            Errors::format(BadFormat::Sent, "Unable to build request_uri", Some(Box::new(e)))
+        })?;
+        Ok(format!("{}?{}", scheme, query))
+    }
+
+    async fn sign_request(&self, inline_uri: &str) -> Outcome<String> {
+        info!("Signing authorization request object");
+
+        let config = self.config.jar_config();
+        let alg = Algorithm::from_str(&config.alg).map_err(|e| {
+            let msg = format!("Unsupported signing algorithm '{}'", config.alg);
+            Errors::crazy(msg, Some(Box::new(e)))
+        })?;
+
+        let mut header = Header::new(alg);
+        header.typ = Some("oauth-authz-req+jwt".to_string());
+        header.kid = config.kid.clone();
+        if config.client_id_scheme == ClientIdScheme::X509SanDns {
+            let cert = self.read_secret("VAULT_APP_CERT").await?;
+            header.x5c = Some(vec![Self::pem_to_der_b64(&cert)]);
+        }
+
+        let pkey = self.read_secret("VAULT_APP_PRIV_KEY").await?;
+        let key = Self::encoding_key(&alg, pkey.as_bytes())?;
+        let claims = self.claims(inline_uri)?;
+
+        encode(&header, &claims, &key)
+            .map_err(|e| Errors::crazy("Unable to sign request object", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,34 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use ymir::errors::Outcome;
+
+use super::types::{ApprovalSource, VerificationFailure};
+use crate::config::approval::ApprovalOutcome;
+
+pub trait MetricsTrait: Send + Sync + 'static {
+    fn observe_request(&self, method: &str, route: &str, status: u16, elapsed: Duration);
+    fn grant_requested(&self, vc_type: &str, outcome: &ApprovalOutcome);
+    fn request_decided(&self, approve: bool, source: &ApprovalSource);
+    fn credential_issued(&self, vc_type: &str);
+    fn verification_failed(&self, reason: &VerificationFailure);
+    fn callback_failed(&self, status: &str);
+    fn set_sse_subscribers(&self, count: usize);
+    fn render(&self) -> Outcome<String>;
+}
@@ -0,0 +1,154 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::time::Duration;
+
+use prometheus::core::Collector;
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder
+};
+use ymir::errors::{Errors, Outcome};
+
+use super::types::{ApprovalSource, VerificationFailure};
+use super::MetricsTrait;
+use crate::config::approval::ApprovalOutcome;
+
+pub struct MetricsService {
+    registry: Registry,
+    requests: IntCounterVec,
+    latency: HistogramVec,
+    grants: IntCounterVec,
+    approvals: IntCounterVec,
+    credentials: IntCounterVec,
+    verification_failures: IntCounterVec,
+    callback_failures: IntCounterVec,
+    sse_subscribers: IntGauge
+}
+
+impl MetricsService {
+    fn counter(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> IntCounterVec {
+        let counter = IntCounterVec::new(Opts::new(name, help), labels).expect("Invalid metric");
+        Self::register(registry, counter)
+    }
+
+    // Names are fixed at compile time, so a failure here is a programming error
+    fn register<C: Collector + Clone + 'static>(registry: &Registry, collector: C) -> C {
+        registry.register(Box::new(collector.clone())).expect("Duplicated metric");
+        collector
+    }
+}
+
+impl Default for MetricsService {
+    fn default() -> Self {
+        let registry = Registry::new_custom(Some("heimdall".to_string()), None)
+            .expect("Invalid metrics prefix");
+
+        let requests = Self::counter(
+            &registry,
+            "http_requests_total",
+            "Handled requests by route and status",
+            &["method", "route", "status"]
+        );
+        let latency = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "Request latency by route"),
+            &["method", "route"]
+        )
+        .expect("Invalid metric");
+        let latency = Self::register(&registry, latency);
+        let grants = Self::counter(
+            &registry,
+            "grant_requests_total",
+            "Grant requests by credential type and approval policy outcome",
+            &["vc_type", "outcome"]
+        );
+        let approvals = Self::counter(
+            &registry,
+            "approvals_total",
+            "Approved and rejected requests by who took the decision",
+            &["outcome", "source"]
+        );
+        let credentials = Self::counter(
+            &registry,
+            "credentials_issued_total",
+            "Issued credentials by type",
+            &["vc_type"]
+        );
+        let verification_failures = Self::counter(
+            &registry,
+            "verification_failures_total",
+            "Rejected presentations by the check that failed",
+            &["reason"]
+        );
+        let callback_failures = Self::counter(
+            &registry,
+            "callback_failures_total",
+            "Failed minion callback deliveries by the callback status left behind",
+            &["status"]
+        );
+        let sse_subscribers =
+            IntGauge::new("sse_subscribers", "Open notification streams").expect("Invalid metric");
+        let sse_subscribers = Self::register(&registry, sse_subscribers);
+
+        Self {
+            registry,
+            requests,
+            latency,
+            grants,
+            approvals,
+            credentials,
+            verification_failures,
+            callback_failures,
+            sse_subscribers
+        }
+    }
+}
+
+impl MetricsTrait for MetricsService {
+    fn observe_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        self.requests.with_label_values(&[method, route, status.to_string().as_str()]).inc();
+        self.latency.with_label_values(&[method, route]).observe(elapsed.as_secs_f64());
+    }
+
+    fn grant_requested(&self, vc_type: &str, outcome: &ApprovalOutcome) {
+        self.grants.with_label_values(&[vc_type, outcome.to_string().as_str()]).inc();
+    }
+
+    fn request_decided(&self, approve: bool, source: &ApprovalSource) {
+        let outcome = if approve { "approved" } else { "rejected" };
+        self.approvals.with_label_values(&[outcome, source.to_string().as_str()]).inc();
+    }
+
+    fn credential_issued(&self, vc_type: &str) {
+        self.credentials.with_label_values(&[vc_type]).inc();
+    }
+
+    fn verification_failed(&self, reason: &VerificationFailure) {
+        self.verification_failures.with_label_values(&[reason.to_string().as_str()]).inc();
+    }
+
+    fn callback_failed(&self, status: &str) {
+        self.callback_failures.with_label_values(&[status]).inc();
+    }
+
+    fn set_sse_subscribers(&self, count: usize) { self.sse_subscribers.set(count as i64); }
+
+    fn render(&self) -> Outcome<String> {
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .map_err(|e| Errors::crazy("Unable to encode metrics", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+
+#[derive(Clone, Debug)]
+pub enum VerificationFailure {
+    Proof,
+    Trust,
+    Status,
+    Query
+}
+
+impl fmt::Display for VerificationFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            VerificationFailure::Proof => "proof",
+            VerificationFailure::Trust => "trust",
+            VerificationFailure::Status => "status",
+            VerificationFailure::Query => "query"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ApprovalSource {
+    Policy,
+    Manual
+}
+
+impl fmt::Display for ApprovalSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ApprovalSource::Policy => "policy",
+            ApprovalSource::Manual => "manual"
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -15,7 +15,23 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod approval;
+pub mod audit;
+pub mod auth;
+pub mod encryption;
+pub mod expiry;
+pub mod gaia;
 pub mod gatekeeper;
+pub mod jar;
+pub mod metrics;
 pub mod notifications;
+pub mod outbox;
+pub mod presentation;
+pub mod proofing;
+pub mod rate_limit;
 pub mod repo;
+pub mod status;
+pub mod tokens;
+pub mod trust;
 pub mod vcs_builder;
+pub mod verification;
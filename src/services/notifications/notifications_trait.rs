@@ -25,4 +25,5 @@ use ymir::data::entities::vc_request::Model;
 pub trait NotificationsTrait: Send + Sync + 'static {
     fn notify(&self, model: &Model);
     fn handle(&self) -> Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>;
+    fn subscribers(&self) -> usize;
 }
@@ -70,4 +70,6 @@ impl NotificationsTrait for NotificationService {
 
         Box::pin(stream)
     }
+
+    fn subscribers(&self) -> usize { self.sender.receiver_count() }
 }
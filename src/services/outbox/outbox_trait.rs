@@ -0,0 +1,31 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use serde_json::Value;
+use ymir::data::entities::recv_interaction;
+
+use crate::data::entities::callback_outbox;
+
+#[async_trait]
+pub trait OutboxTrait: Send + Sync + 'static {
+    fn start(&self, int_model: &recv_interaction::Model, body: Value) -> callback_outbox::NewModel;
+    // Tries the callback once and schedules the next attempt or dead-letters it on failure
+    async fn deliver(&self, model: &mut callback_outbox::Model);
+    fn reset(&self, model: &mut callback_outbox::Model);
+    fn interval(&self) -> u64;
+}
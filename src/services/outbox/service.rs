@@ -0,0 +1,120 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::http::HeaderValue;
+use chrono::{Duration, Utc};
+use serde_json::Value;
+use tracing::{info, warn};
+use ymir::data::entities::recv_interaction;
+use ymir::errors::{Errors, Outcome};
+use ymir::services::client::ClientTrait;
+use ymir::types::http::Body;
+use ymir::utils::json_headers;
+
+use super::types::CallbackStatus;
+use super::OutboxTrait;
+use crate::config::callback::{CallbackConfig, CallbackConfigTrait};
+use crate::data::entities::callback_outbox;
+use crate::utils::trace_headers;
+
+const SIGNATURE_HEADER: &str = "x-heimdall-signature";
+
+pub struct OutboxService {
+    config: CallbackConfig,
+    client: Arc<dyn ClientTrait>
+}
+
+impl OutboxService {
+    pub fn new(config: CallbackConfig, client: Arc<dyn ClientTrait>) -> Self {
+        Self { config, client }
+    }
+
+    async fn post(&self, model: &callback_outbox::Model) -> Outcome<()> {
+        let body = Body::Json(model.body.clone());
+        let mut headers = trace_headers(json_headers());
+        if let Some(signature) = &model.signature {
+            let value = HeaderValue::from_str(signature).map_err(|e| {
+                Errors::parse("Unable to build callback signature header", Some(Box::new(e)))
+            })?;
+            headers.insert(SIGNATURE_HEADER, value);
+        }
+        let res = self.client.post(&model.uri, Some(headers), body).await?;
+        if res.status().is_success() {
+            return Ok(());
+        }
+        Err(Errors::consumer(
+            &model.uri,
+            "POST",
+            Some(res.status()),
+            "Callback receiver did not accept the callback",
+            None
+        ))
+    }
+}
+
+impl CallbackConfigTrait for OutboxService {
+    fn callback_config(&self) -> &CallbackConfig { &self.config }
+}
+
+#[async_trait]
+impl OutboxTrait for OutboxService {
+    fn start(&self, int_model: &recv_interaction::Model, body: Value) -> callback_outbox::NewModel {
+        callback_outbox::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            request_id: int_model.id.clone(),
+            uri: int_model.uri.clone(),
+            body,
+            signature: None
+        }
+    }
+
+    async fn deliver(&self, model: &mut callback_outbox::Model) {
+        let result = self.post(model).await;
+        model.attempts += 1;
+        match result {
+            Ok(()) => {
+                info!("Callback {} delivered on attempt {}", model.id, model.attempts);
+                model.status = CallbackStatus::Delivered.to_string();
+                model.last_error = None;
+                model.delivered_at = Some(Utc::now().naive_utc());
+            }
+            Err(e) => {
+                e.log();
+                model.last_error = Some(e.to_string());
+                if model.attempts >= self.get_max_attempts() {
+                    warn!("Callback {} dead-lettered after {} attempts", model.id, model.attempts);
+                    model.status = CallbackStatus::Dead.to_string();
+                    return;
+                }
+                let delay = Duration::seconds(self.get_backoff(model.attempts));
+                model.next_attempt_at = (Utc::now() + delay).naive_utc();
+            }
+        }
+    }
+
+    fn reset(&self, model: &mut callback_outbox::Model) {
+        model.status = CallbackStatus::Pending.to_string();
+        model.attempts = 0;
+        model.next_attempt_at = Utc::now().naive_utc();
+        model.last_error = None;
+    }
+
+    fn interval(&self) -> u64 { self.get_outbox_interval() }
+}
@@ -0,0 +1,38 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CallbackStatus {
+    Pending,
+    Delivered,
+    // Gave up after `callback_config.max_attempts`, only redelivered on demand
+    Dead
+}
+
+impl fmt::Display for CallbackStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CallbackStatus::Pending => "Pending",
+            CallbackStatus::Delivered => "Delivered",
+            CallbackStatus::Dead => "Dead"
+        };
+        write!(f, "{}", s)
+    }
+}
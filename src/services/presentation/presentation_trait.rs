@@ -0,0 +1,34 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use ymir::errors::Outcome;
+
+use super::types::{DcqlQuery, PresentationDefinition};
+use crate::config::presentation::PresentationUseCase;
+
+pub trait PresentationTrait: Send + Sync + 'static {
+    fn select(&self, vc_types: &[String]) -> Option<PresentationUseCase>;
+    fn presentation_definition(
+        &self,
+        id: &str,
+        use_case: &PresentationUseCase
+    ) -> PresentationDefinition;
+    fn dcql_query(&self, use_case: &PresentationUseCase) -> DcqlQuery;
+    fn with_dcql_query(&self, uri: &str, use_case: &PresentationUseCase) -> Outcome<String>;
+    fn uses_dcql(&self) -> bool;
+    fn validate(&self, use_case: &PresentationUseCase, vp_token: &str) -> Outcome<()>;
+}
@@ -0,0 +1,218 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde_json::{json, Map, Value};
+use tracing::info;
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+use super::types::{
+    Constraints, DcqlClaim, DcqlCredential, DcqlQuery, Field, InputDescriptor,
+    PresentationDefinition
+};
+use super::PresentationTrait;
+use crate::config::presentation::{
+    CredentialRequirement, PresentationConfig, PresentationConfigTrait, PresentationUseCase
+};
+use crate::utils::{extract_vp_credentials, vc_has_type, vc_issuer, vp_credential_formats};
+
+pub struct PresentationService {
+    config: PresentationConfig
+}
+
+impl PresentationService {
+    pub fn new(config: PresentationConfig) -> Self { Self { config } }
+
+    fn lookup<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+        path.iter().try_fold(value, |current, key| match current {
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => current.get(key)
+        })
+    }
+
+    fn format_matches(required: &str, embedded: &str) -> bool {
+        match embedded {
+            "jwt_vc_json" => matches!(required, "jwt_vc_json" | "jwt_vc_json-ld" | "jwt_vc"),
+            "dc+sd-jwt" => matches!(required, "dc+sd-jwt" | "vc+sd-jwt"),
+            other => required == other
+        }
+    }
+
+    fn satisfies(vc: &Value, format: &str, requirement: &CredentialRequirement) -> bool {
+        if !Self::format_matches(&requirement.format, format) {
+            return false;
+        }
+        if !vc_has_type(vc, &requirement.vc_type) {
+            return false;
+        }
+        if !requirement.issuers.is_empty() {
+            match vc_issuer(vc) {
+                Some(issuer) if requirement.issuers.iter().any(|i| i == issuer) => {}
+                _ => return false
+            }
+        }
+        requirement.claims.iter().all(|claim| match Self::lookup(vc, &claim.path) {
+            Some(value) => claim.values.is_empty() || claim.values.contains(value),
+            None => false
+        })
+    }
+}
+
+impl PresentationConfigTrait for PresentationService {
+    fn presentation_config(&self) -> &PresentationConfig { &self.config }
+}
+
+impl PresentationTrait for PresentationService {
+    fn select(&self, vc_types: &[String]) -> Option<PresentationUseCase> {
+        let mut selected: Vec<&PresentationUseCase> = Vec::new();
+        for use_case in vc_types.iter().filter_map(|vc_type| self.get_use_case(vc_type)) {
+            if !selected.iter().any(|s| s.id == use_case.id) {
+                selected.push(use_case);
+            }
+        }
+        // Grants asking for several types must satisfy the use case of each of them
+        let (first, rest) = selected.split_first()?;
+        let mut merged = (*first).clone();
+        for use_case in rest {
+            merged.id = format!("{}+{}", merged.id, use_case.id);
+            for requirement in &use_case.credentials {
+                if !merged.credentials.iter().any(|c| c.id == requirement.id) {
+                    merged.credentials.push(requirement.clone());
+                }
+            }
+        }
+        Some(merged)
+    }
+
+    fn presentation_definition(
+        &self,
+        id: &str,
+        use_case: &PresentationUseCase
+    ) -> PresentationDefinition {
+        let input_descriptors = use_case
+            .credentials
+            .iter()
+            .map(|requirement| {
+                let mut fields = vec![Field {
+                    path: vec!["$.type".to_string(), "$.vc.type".to_string()],
+                    filter: Some(json!({
+                        "type": "array",
+                        "contains": { "const": requirement.vc_type }
+                    }))
+                }];
+                if !requirement.issuers.is_empty() {
+                    fields.push(Field {
+                        path: vec![
+                            "$.iss".to_string(),
+                            "$.issuer".to_string(),
+                            "$.vc.issuer.id".to_string(),
+                        ],
+                        filter: Some(json!({ "type": "string", "enum": requirement.issuers }))
+                    });
+                }
+                for claim in &requirement.claims {
+                    let path = claim.path.join(".");
+                    let filter = match claim.values.is_empty() {
+                        true => None,
+                        false => Some(json!({ "enum": claim.values }))
+                    };
+                    fields.push(Field {
+                        path: vec![format!("$.{}", path), format!("$.vc.{}", path)],
+                        filter
+                    });
+                }
+                InputDescriptor {
+                    id: requirement.id.clone(),
+                    format: Value::Object(Map::from_iter([(
+                        requirement.format.clone(),
+                        json!({})
+                    )])),
+                    constraints: Constraints { fields }
+                }
+            })
+            .collect();
+
+        PresentationDefinition { id: format!("{}-{}", use_case.id, id), input_descriptors }
+    }
+
+    fn dcql_query(&self, use_case: &PresentationUseCase) -> DcqlQuery {
+        let credentials = use_case
+            .credentials
+            .iter()
+            .map(|requirement| DcqlCredential {
+                id: requirement.id.clone(),
+                format: requirement.format.clone(),
+                meta: json!({
+                    "type_values": [["VerifiableCredential", requirement.vc_type]]
+                }),
+                claims: requirement
+                    .claims
+                    .iter()
+                    .map(|claim| DcqlClaim {
+                        path: claim.path.clone(),
+                        values: claim.values.clone()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        DcqlQuery { credentials }
+    }
+
+    fn with_dcql_query(&self, uri: &str, use_case: &PresentationUseCase) -> Outcome<String> {
+        let (scheme, query) = uri.split_once('?').unwrap_or((uri, ""));
+        let params: Vec<(String, String)> = serde_urlencoded::from_str(query).map_err(|e| {
+            Errors::format(BadFormat::Sent, "Unable to parse verification uri", Some(Box::new(e)))
+        })?;
+        // Both query languages in one request are rejected by wallets
+        let mut params: Vec<(String, String)> = params
+            .into_iter()
+            .filter(|(k, _)| !k.starts_with("presentation_definition"))
+            .collect();
+        let dcql = serde_json::to_string(&self.dcql_query(use_case)).map_err(|e| {
+            Errors::parse("Unable to serialize DCQL query", Some(Box::new(e)))
+        })?;
+        params.push(("dcql_query".to_string(), dcql));
+
+        let query = serde_urlencoded::to_string(params).map_err(|e| {
+            Errors::format(BadFormat::Sent, "Unable to build verification uri", Some(Box::new(e)))
+        })?;
+        Ok(format!("{}?{}", scheme, query))
+    }
+
+    fn uses_dcql(&self) -> bool { self.is_dcql_enabled() }
+
+    fn validate(&self, use_case: &PresentationUseCase, vp_token: &str) -> Outcome<()> {
+        info!("Validating presentation against use case '{}'", use_case.id);
+
+        let (_, vcs) = extract_vp_credentials(vp_token)?;
+        let formats = vp_credential_formats(vp_token)?;
+
+        for requirement in &use_case.credentials {
+            let mut credentials = vcs.iter().zip(formats.iter());
+            if !credentials.any(|(vc, format)| Self::satisfies(vc, format, requirement)) {
+                return Err(Errors::unauthorized(
+                    format!(
+                        "Presentation does not satisfy requirement '{}' of use case '{}'",
+                        requirement.id, use_case.id
+                    ),
+                    None
+                ));
+            }
+        }
+        Ok(())
+    }
+}
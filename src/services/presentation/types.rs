@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ymir::types::vcs::VPDef;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PresentationDefinition {
+    pub id: String,
+    pub input_descriptors: Vec<InputDescriptor>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InputDescriptor {
+    pub id: String,
+    pub format: Value,
+    pub constraints: Constraints
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Constraints {
+    pub fields: Vec<Field>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Field {
+    pub path: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Value>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DcqlQuery {
+    pub credentials: Vec<DcqlCredential>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DcqlCredential {
+    pub id: String,
+    pub format: String,
+    pub meta: Value,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub claims: Vec<DcqlClaim>
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct DcqlClaim {
+    pub path: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<Value>
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum VpDefinition {
+    Configured(PresentationDefinition),
+    Default(VPDef)
+}
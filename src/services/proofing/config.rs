@@ -0,0 +1,41 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use ymir::config::traits::HostsConfigTrait;
+use ymir::config::types::CommonHostsConfig;
+
+use crate::config::proofing::{ProofingConfig, ProofingConfigTrait};
+use crate::config::CoreApplicationConfig;
+
+pub struct ProofServiceConfig {
+    hosts: CommonHostsConfig,
+    proofing: ProofingConfig
+}
+
+impl From<CoreApplicationConfig> for ProofServiceConfig {
+    fn from(config: CoreApplicationConfig) -> Self {
+        Self { hosts: config.hosts().clone(), proofing: config.proofing_config().clone() }
+    }
+}
+
+impl HostsConfigTrait for ProofServiceConfig {
+    fn hosts(&self) -> &CommonHostsConfig { &self.hosts }
+}
+
+impl ProofingConfigTrait for ProofServiceConfig {
+    fn proofing_config(&self) -> &ProofingConfig { &self.proofing }
+}
@@ -0,0 +1,166 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+use super::types::SignedRequest;
+
+/// First signature of a `Signature-Input` header (RFC 9421 §4.1)
+pub struct SignatureInput {
+    pub label: String,
+    pub components: Vec<String>,
+    pub raw_params: String,
+    pub created: Option<i64>,
+    pub keyid: Option<String>,
+    pub alg: Option<String>
+}
+
+fn invalid(message: &str) -> Errors { Errors::format(BadFormat::Received, message, None) }
+
+// Splits a structured field dictionary into its members, ignoring commas in quoted strings
+fn members(header: &str) -> Vec<&str> {
+    let mut members = Vec::new();
+    let (mut start, mut quoted) = (0, false);
+    for (i, c) in header.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                members.push(header[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    members.push(header[start..].trim());
+    members
+}
+
+fn unquote(value: &str) -> String { value.trim_matches('"').to_string() }
+
+pub fn parse_input(header: &str) -> Outcome<SignatureInput> {
+    let member = members(header).into_iter().next().unwrap_or_default();
+    let (label, raw_params) =
+        member.split_once('=').ok_or_else(|| invalid("Malformed Signature-Input header"))?;
+    let list = raw_params
+        .strip_prefix('(')
+        .and_then(|rest| rest.split_once(')'))
+        .ok_or_else(|| invalid("Signature-Input must be an inner list"))?;
+
+    let mut components = Vec::new();
+    for component in list.0.split_whitespace() {
+        if !component.starts_with('"') || !component.ends_with('"') {
+            return Err(Errors::not_impl("Component parameters are not supported", None));
+        }
+        components.push(unquote(component).to_lowercase());
+    }
+
+    let mut input = SignatureInput {
+        label: label.trim().to_string(),
+        components,
+        raw_params: raw_params.to_string(),
+        created: None,
+        keyid: None,
+        alg: None
+    };
+    for param in list.1.split(';').filter(|p| !p.is_empty()) {
+        match param.split_once('=') {
+            Some(("created", value)) => {
+                input.created =
+                    Some(value.parse().map_err(|_| invalid("Invalid 'created' parameter"))?)
+            }
+            Some(("keyid", value)) => input.keyid = Some(unquote(value)),
+            Some(("alg", value)) => input.alg = Some(unquote(value)),
+            _ => {}
+        }
+    }
+    Ok(input)
+}
+
+pub fn parse_signature(header: &str, label: &str) -> Outcome<Vec<u8>> {
+    let value = members(header)
+        .into_iter()
+        .filter_map(|member| member.split_once('='))
+        .find(|(name, _)| name.trim() == label)
+        .map(|(_, value)| value.trim())
+        .ok_or_else(|| invalid("No signature matches the Signature-Input label"))?;
+    let value = value
+        .strip_prefix(':')
+        .and_then(|v| v.strip_suffix(':'))
+        .ok_or_else(|| invalid("Signature must be a byte sequence"))?;
+    STANDARD
+        .decode(value)
+        .map_err(|e| Errors::format(BadFormat::Received, "Invalid signature", Some(Box::new(e))))
+}
+
+/// Parses a `Content-Digest` header into (algorithm, digest) pairs (RFC 9530)
+pub fn parse_digests(header: &str) -> Vec<(String, Vec<u8>)> {
+    members(header)
+        .into_iter()
+        .filter_map(|member| member.split_once('='))
+        .filter_map(|(alg, value)| {
+            let value = value.trim().strip_prefix(':')?.strip_suffix(':')?;
+            Some((alg.trim().to_string(), STANDARD.decode(value).ok()?))
+        })
+        .collect()
+}
+
+fn component_value(component: &str, request: &SignedRequest, target: &str) -> Outcome<String> {
+    let (scheme, rest) = target.split_once("://").unwrap_or(("https", target));
+    let (authority, path_query) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let (path, query) = path_query.split_once('?').unwrap_or((path_query, ""));
+
+    let value = match component {
+        "@method" => request.method.to_uppercase(),
+        "@target-uri" => target.to_string(),
+        "@authority" => authority.to_lowercase(),
+        "@scheme" => scheme.to_lowercase(),
+        "@request-target" => path_query.to_string(),
+        "@path" => path.to_string(),
+        "@query" => format!("?{}", query),
+        derived if derived.starts_with('@') => {
+            return Err(Errors::not_impl(
+                format!("Derived component '{}' not supported", derived),
+                None
+            ))
+        }
+        header => request.header(header).ok_or_else(|| {
+            Errors::format(
+                BadFormat::Received,
+                format!("Signed header '{}' is missing", header),
+                None
+            )
+        })?
+    };
+    Ok(value)
+}
+
+/// Builds the signature base the client signed (RFC 9421 §2.5)
+pub fn signature_base(
+    input: &SignatureInput,
+    request: &SignedRequest,
+    target: &str
+) -> Outcome<String> {
+    let mut base = String::new();
+    for component in &input.components {
+        let value = component_value(component, request, target)?;
+        base.push_str(&format!("\"{}\": {}\n", component, value));
+    }
+    base.push_str(&format!("\"@signature-params\": {}", input.raw_params));
+    Ok(base)
+}
@@ -0,0 +1,124 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::signature::{
+    RsaParameters, RsaPublicKeyComponents, UnparsedPublicKey, VerificationAlgorithm,
+    ECDSA_P256_SHA256_FIXED, ECDSA_P384_SHA384_FIXED, ED25519, RSA_PKCS1_2048_8192_SHA256,
+    RSA_PSS_2048_8192_SHA256, RSA_PSS_2048_8192_SHA512
+};
+use serde_json::Value;
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+fn member<'a>(jwk: &'a Value, name: &str) -> Outcome<&'a str> {
+    jwk.get(name).and_then(Value::as_str).ok_or_else(|| {
+        Errors::format(BadFormat::Received, format!("Client JWK is missing '{}'", name), None)
+    })
+}
+
+fn decoded(jwk: &Value, name: &str) -> Outcome<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(member(jwk, name)?).map_err(|e| {
+        Errors::format(BadFormat::Received, "Client JWK is not base64url", Some(Box::new(e)))
+    })
+}
+
+fn expect(jwk: &Value, name: &str, expected: &str) -> Outcome<()> {
+    match member(jwk, name)? == expected {
+        true => Ok(()),
+        false => Err(Errors::security(
+            format!("Client JWK {} does not match the signature algorithm", name),
+            None
+        ))
+    }
+}
+
+fn ec_point(jwk: &Value, crv: &str) -> Outcome<Vec<u8>> {
+    expect(jwk, "kty", "EC")?;
+    expect(jwk, "crv", crv)?;
+    let mut point = vec![0x04];
+    point.extend(decoded(jwk, "x")?);
+    point.extend(decoded(jwk, "y")?);
+    Ok(point)
+}
+
+fn check(
+    algorithm: &'static dyn VerificationAlgorithm,
+    key: Vec<u8>,
+    message: &[u8],
+    signature: &[u8]
+) -> Outcome<()> {
+    UnparsedPublicKey::new(algorithm, key)
+        .verify(message, signature)
+        .map_err(|_| Errors::unauthorized("Key proof signature is not valid", None))
+}
+
+fn check_rsa(
+    params: &'static RsaParameters,
+    jwk: &Value,
+    message: &[u8],
+    signature: &[u8]
+) -> Outcome<()> {
+    expect(jwk, "kty", "RSA")?;
+    let (n, e) = (decoded(jwk, "n")?, decoded(jwk, "e")?);
+    RsaPublicKeyComponents { n: &n, e: &e }
+        .verify(params, message, signature)
+        .map_err(|_| Errors::unauthorized("Key proof signature is not valid", None))
+}
+
+/// Algorithm used when the signature does not name one, in HTTP Message Signatures terms
+pub fn default_alg(jwk: &Value) -> Outcome<String> {
+    if let Some(alg) = jwk.get("alg").and_then(Value::as_str) {
+        return Ok(alg.to_string());
+    }
+    let alg = match (member(jwk, "kty")?, jwk.get("crv").and_then(Value::as_str)) {
+        ("EC", Some("P-256")) => "ecdsa-p256-sha256",
+        ("EC", Some("P-384")) => "ecdsa-p384-sha384",
+        ("OKP", Some("Ed25519")) => "ed25519",
+        ("RSA", _) => "rsa-pss-sha512",
+        (kty, _) => {
+            return Err(Errors::not_impl(format!("Client key type '{}' not supported", kty), None))
+        }
+    };
+    Ok(alg.to_string())
+}
+
+/// Verifies a signature with the client JWK, accepting both HTTP Message Signatures
+/// and JOSE algorithm names
+pub fn verify(jwk: &Value, alg: &str, message: &[u8], signature: &[u8]) -> Outcome<()> {
+    match alg {
+        "ecdsa-p256-sha256" | "ES256" => {
+            check(&ECDSA_P256_SHA256_FIXED, ec_point(jwk, "P-256")?, message, signature)
+        }
+        "ecdsa-p384-sha384" | "ES384" => {
+            check(&ECDSA_P384_SHA384_FIXED, ec_point(jwk, "P-384")?, message, signature)
+        }
+        "ed25519" | "EdDSA" => {
+            expect(jwk, "kty", "OKP")?;
+            expect(jwk, "crv", "Ed25519")?;
+            check(&ED25519, decoded(jwk, "x")?, message, signature)
+        }
+        "rsa-pss-sha512" | "PS512" => {
+            check_rsa(&RSA_PSS_2048_8192_SHA512, jwk, message, signature)
+        }
+        "PS256" => check_rsa(&RSA_PSS_2048_8192_SHA256, jwk, message, signature),
+        "rsa-v1_5-sha256" | "RS256" => {
+            check_rsa(&RSA_PKCS1_2048_8192_SHA256, jwk, message, signature)
+        }
+        alg => Err(Errors::not_impl(format!("Signature algorithm '{}' not supported", alg), None))
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde_json::Value;
+use ymir::errors::Outcome;
+
+use super::types::SignedRequest;
+
+pub trait ProofTrait: Send + Sync + 'static {
+    /// Checks the proof of a grant request and returns the key the grant gets bound to,
+    /// or `None` when the client key declares no proofing method
+    fn bind(&self, key: &Value, request: &SignedRequest) -> Outcome<Option<Value>>;
+    fn verify(&self, key: &Value, request: &SignedRequest) -> Outcome<()>;
+    /// Certificate of a key bound over mTLS, the only one the client proved to hold
+    fn bound_cert(&self, key: &Value) -> Option<String>;
+}
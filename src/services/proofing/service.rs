@@ -0,0 +1,260 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use chrono::Utc;
+use serde_json::Value;
+use sha2::{Digest, Sha256, Sha512};
+use tracing::info;
+use x509_parser::pem::parse_x509_pem;
+use ymir::config::traits::HostsConfigTrait;
+use ymir::config::types::HostType;
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+use super::types::{ProofMethod, SignedRequest};
+use super::{httpsig, jwk, ProofServiceConfig, ProofTrait};
+use crate::config::proofing::ProofingConfigTrait;
+
+pub struct ProofService {
+    config: ProofServiceConfig
+}
+
+impl ProofService {
+    pub fn new(config: ProofServiceConfig) -> Self { Self { config } }
+
+    // Clients sign the URI they called, which is the public one and not the one we bind to
+    fn target_uri(&self, request: &SignedRequest) -> String {
+        format!("{}{}", self.config.hosts().get_host(HostType::Http), request.path)
+    }
+
+    fn client_jwk(key: &Value) -> Outcome<&Value> {
+        key.get("jwk").ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Client key has no 'jwk' to verify with", None)
+        })
+    }
+
+    fn check_created(&self, created: Option<i64>) -> Outcome<()> {
+        let created = created.ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Signature has no 'created' time", None)
+        })?;
+        match (Utc::now().timestamp() - created).abs() <= self.config.get_max_skew() {
+            true => Ok(()),
+            false => Err(Errors::security("Signature is too old or from the future", None))
+        }
+    }
+
+    fn check_content_digest(request: &SignedRequest) -> Outcome<()> {
+        let header = request.header("content-digest").ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Content-Digest header is missing", None)
+        })?;
+        let matches = httpsig::parse_digests(&header).iter().any(|(alg, digest)| {
+            match alg.as_str() {
+                "sha-256" => Sha256::digest(&request.body).as_slice() == digest.as_slice(),
+                "sha-512" => Sha512::digest(&request.body).as_slice() == digest.as_slice(),
+                _ => false
+            }
+        });
+        match matches {
+            true => Ok(()),
+            false => Err(Errors::security("Content-Digest does not match the body", None))
+        }
+    }
+
+    fn gnap_token(request: &SignedRequest) -> Option<String> {
+        request
+            .header("authorization")
+            .and_then(|auth| auth.strip_prefix("GNAP ").map(|token| token.trim().to_string()))
+    }
+
+    // RFC 9635 §7.3.1
+    fn verify_httpsig(&self, key: &Value, request: &SignedRequest) -> Outcome<()> {
+        let input = request.header("signature-input").ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Signature-Input header is missing", None)
+        })?;
+        let input = httpsig::parse_input(&input)?;
+
+        let mut required = vec!["@method", "@target-uri"];
+        if !request.body.is_empty() {
+            Self::check_content_digest(request)?;
+            required.push("content-digest");
+        }
+        if request.headers.contains_key("authorization") {
+            required.push("authorization");
+        }
+        if let Some(missing) = required.iter().find(|c| !input.components.iter().any(|s| s == *c))
+        {
+            return Err(Errors::security(
+                format!("Signature does not cover '{}'", missing),
+                None
+            ));
+        }
+        self.check_created(input.created)?;
+
+        let jwk = Self::client_jwk(key)?;
+        if let (Some(keyid), Some(kid)) = (&input.keyid, jwk.get("kid").and_then(Value::as_str)) {
+            if keyid != kid {
+                return Err(Errors::security("Signature keyid does not match the client key", None));
+            }
+        }
+        let alg = match &input.alg {
+            Some(alg) => alg.clone(),
+            None => jwk::default_alg(jwk)?
+        };
+        let signature = request.header("signature").ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Signature header is missing", None)
+        })?;
+        let signature = httpsig::parse_signature(&signature, &input.label)?;
+        let base = httpsig::signature_base(&input, request, &self.target_uri(request))?;
+        jwk::verify(jwk, &alg, base.as_bytes(), &signature)
+    }
+
+    // RFC 9635 §7.3.3
+    fn verify_jwsd(&self, key: &Value, request: &SignedRequest) -> Outcome<()> {
+        let jws = request.header("detached-jws").ok_or_else(|| {
+            Errors::format(BadFormat::Received, "Detached-JWS header is missing", None)
+        })?;
+        let (header_b64, signature_b64) = match jws.split('.').collect::<Vec<_>>()[..] {
+            [header, "", signature] => (header, signature),
+            _ => return Err(Errors::format(BadFormat::Received, "Malformed detached JWS", None))
+        };
+        let decode = |part: &str| {
+            URL_SAFE_NO_PAD.decode(part).map_err(|e| {
+                let message = "Detached JWS is not base64url";
+                Errors::format(BadFormat::Received, message, Some(Box::new(e)))
+            })
+        };
+        let header: Value = serde_json::from_slice(&decode(header_b64)?)
+            .map_err(|e| Errors::parse("Unable to parse detached JWS header", Some(Box::new(e))))?;
+        let claim = |name: &str| header.get(name).and_then(Value::as_str).unwrap_or_default();
+
+        if claim("htm") != request.method.to_uppercase() {
+            return Err(Errors::security("Detached JWS was made for another method", None));
+        }
+        if claim("uri") != self.target_uri(request) {
+            return Err(Errors::security("Detached JWS was made for another URI", None));
+        }
+        self.check_created(header.get("created").and_then(Value::as_i64))?;
+        if let Some(token) = Self::gnap_token(request) {
+            let ath = URL_SAFE_NO_PAD.encode(Sha256::digest(token.as_bytes()));
+            if claim("ath") != ath {
+                return Err(Errors::security("Detached JWS does not cover the access token", None));
+            }
+        }
+
+        let signing_input = format!("{}.{}", header_b64, URL_SAFE_NO_PAD.encode(&request.body));
+        let jwk = Self::client_jwk(key)?;
+        jwk::verify(jwk, claim("alg"), signing_input.as_bytes(), &decode(signature_b64)?)
+    }
+
+    // RFC 9635 §7.3.2, with TLS terminated by a proxy that forwards the client certificate
+    fn verify_mtls(&self, key: &Value, request: &SignedRequest) -> Outcome<()> {
+        let presented = request.header(self.config.get_mtls_header()).ok_or_else(|| {
+            Errors::unauthorized("No client certificate was presented", None)
+        })?;
+        let presented = Self::certificate_der(&Self::percent_decode(&presented))?;
+        let thumbprint = URL_SAFE_NO_PAD.encode(Sha256::digest(&presented));
+
+        let matches = match (key.get("cert#S256"), key.get("cert")) {
+            (Some(Value::String(expected)), _) => *expected == thumbprint,
+            (_, Some(Value::String(cert))) => Self::certificate_der(cert)? == presented,
+            _ => {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    "Client key has no certificate to bind to",
+                    None
+                ))
+            }
+        };
+        match matches {
+            true => Ok(()),
+            false => Err(Errors::unauthorized("Client certificate does not match the key", None))
+        }
+    }
+
+    // Accepts PEM, with or without armor, and tolerates internal whitespace
+    fn certificate_der(cert: &str) -> Outcome<Vec<u8>> {
+        if cert.contains("-----BEGIN") {
+            let (_, pem) = parse_x509_pem(cert.as_bytes()).map_err(|e| {
+                Errors::format(BadFormat::Received, "Invalid PEM certificate", Some(Box::new(e)))
+            })?;
+            return Ok(pem.contents);
+        }
+        let cert: String = cert.chars().filter(|c| !c.is_whitespace()).collect();
+        STANDARD.decode(cert).map_err(|e| {
+            Errors::format(BadFormat::Received, "Invalid certificate encoding", Some(Box::new(e)))
+        })
+    }
+
+    fn percent_decode(value: &str) -> String {
+        let bytes = value.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = bytes.get(i + 1..i + 3).and_then(|h| std::str::from_utf8(h).ok());
+            match (bytes[i], hex.and_then(|h| u8::from_str_radix(h, 16).ok())) {
+                (b'%', Some(byte)) => {
+                    decoded.push(byte);
+                    i += 3;
+                }
+                (byte, _) => {
+                    decoded.push(byte);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+}
+
+impl ProofTrait for ProofService {
+    fn bind(&self, key: &Value, request: &SignedRequest) -> Outcome<Option<Value>> {
+        // Only keys declaring no proof at all go unbound, a declared method has to hold
+        if key.get("proof").is_none() && !self.config.is_proofing_required() {
+            info!("Grant is not bound to the client key, it declares no proofing method");
+            return Ok(None);
+        }
+        self.verify(key, request)?;
+        Ok(Some(key.clone()))
+    }
+
+    fn verify(&self, key: &Value, request: &SignedRequest) -> Outcome<()> {
+        let method = ProofMethod::from_key(key)?;
+        info!("Verifying {} key proof", method);
+
+        match method {
+            ProofMethod::HttpSig => self.verify_httpsig(key, request),
+            ProofMethod::Mtls => self.verify_mtls(key, request),
+            ProofMethod::Jwsd => self.verify_jwsd(key, request)
+        }
+    }
+
+    fn bound_cert(&self, key: &Value) -> Option<String> {
+        if ProofMethod::from_key(key).ok()? != ProofMethod::Mtls {
+            return None;
+        }
+        let cert = key.get("cert")?.as_str()?;
+        // With a thumbprint, that is what the presented certificate was checked against
+        if let Some(expected) = key.get("cert#S256") {
+            let der = Self::certificate_der(cert).ok()?;
+            if expected.as_str()? != URL_SAFE_NO_PAD.encode(Sha256::digest(der)) {
+                return None;
+            }
+        }
+        Some(cert.to_string())
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use axum::body::Bytes;
+use axum::http::HeaderMap;
+use serde_json::Value;
+use ymir::errors::{Errors, Outcome};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProofMethod {
+    HttpSig,
+    Mtls,
+    Jwsd
+}
+
+impl ProofMethod {
+    // `proof` is either the method name or an object carrying it (RFC 9635 §7.1)
+    pub fn from_key(key: &Value) -> Outcome<Self> {
+        let proof = key.get("proof").ok_or_else(|| {
+            Errors::security("Client key does not declare a proofing method", None)
+        })?;
+        let method = match proof {
+            Value::Object(proof) => proof.get("method").and_then(Value::as_str),
+            proof => proof.as_str()
+        };
+        method
+            .ok_or_else(|| Errors::security("Client key proofing method is malformed", None))?
+            .parse()
+    }
+}
+
+impl FromStr for ProofMethod {
+    type Err = Errors;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "httpsig" => Ok(ProofMethod::HttpSig),
+            "mtls" => Ok(ProofMethod::Mtls),
+            "jwsd" => Ok(ProofMethod::Jwsd),
+            method => Err(Errors::not_impl(
+                format!("Key proofing method '{}' not supported", method),
+                None
+            ))
+        }
+    }
+}
+
+impl Display for ProofMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let method = match self {
+            ProofMethod::HttpSig => "httpsig",
+            ProofMethod::Mtls => "mtls",
+            ProofMethod::Jwsd => "jwsd"
+        };
+        write!(f, "{}", method)
+    }
+}
+
+/// Request as received by the gatekeeper, kept raw so its proof can be checked
+#[derive(Clone, Debug)]
+pub struct SignedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+    pub client_ip: Option<IpAddr>
+}
+
+impl SignedRequest {
+    pub fn header(&self, name: &str) -> Option<String> {
+        let values: Vec<&str> =
+            self.headers.get_all(name).iter().filter_map(|v| v.to_str().ok()).collect();
+        match values.is_empty() {
+            true => None,
+            false => Some(values.iter().map(|v| v.trim()).collect::<Vec<_>>().join(", "))
+        }
+    }
+}
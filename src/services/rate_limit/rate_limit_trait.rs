@@ -0,0 +1,31 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::net::IpAddr;
+
+use super::types::{PendingOwner, RateDecision};
+use crate::config::rate_limit::RateLimitConfigTrait;
+
+pub trait RateLimitTrait: RateLimitConfigTrait + Send + Sync + 'static {
+    // Takes a token from every bucket matching the request
+    fn check(&self, path: &str, ip: Option<IpAddr>, client: Option<&str>) -> RateDecision;
+    // Whether the body is needed to tell the client or participant apart
+    fn reads_body(&self, path: &str) -> bool;
+    fn client_key(&self, path: &str, body: &[u8]) -> Option<String>;
+    // Owner of a grant request, whose pending requests are capped
+    fn pending_owner(&self, path: &str, body: &[u8], ip: Option<IpAddr>) -> Option<PendingOwner>;
+}
@@ -0,0 +1,152 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::types::{PendingOwner, RateDecision, TokenBucket, TrackedBuckets};
+use super::RateLimitTrait;
+use crate::config::rate_limit::{RateLimitConfig, RateLimitConfigTrait, RateQuota};
+
+const GRANT_PATH: &str = "/gate/access";
+// Hard bound on tracked keys; past it the least recently used tenth is evicted at once
+const MAX_TRACKED: usize = 10_000;
+const EVICT_BATCH: usize = MAX_TRACKED / 10;
+// Idle buckets that have refilled are dropped at most this often
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+pub struct RateLimitService {
+    config: RateLimitConfig,
+    tracked: Mutex<TrackedBuckets>
+}
+
+impl RateLimitService {
+    pub fn new(config: RateLimitConfig) -> Self {
+        let tracked = TrackedBuckets { buckets: HashMap::new(), swept: Instant::now() };
+        Self { config, tracked: Mutex::new(tracked) }
+    }
+
+    fn take(&self, key: String, quota: &RateQuota) -> RateDecision {
+        let now = Instant::now();
+        let mut tracked = self.tracked.lock().unwrap_or_else(PoisonError::into_inner);
+        if now.duration_since(tracked.swept) >= SWEEP_INTERVAL {
+            tracked.buckets.retain(|_, bucket| !bucket.is_full(now));
+            tracked.swept = now;
+        }
+        if tracked.buckets.len() >= MAX_TRACKED && !tracked.buckets.contains_key(&key) {
+            Self::evict(&mut tracked.buckets);
+        }
+        tracked
+            .buckets
+            .entry(key)
+            .or_insert_with(|| {
+                TokenBucket::new(quota.burst as f64, quota.per_minute as f64 / 60.0, now)
+            })
+            .take(now)
+    }
+
+    // Drops the least recently used buckets in one pass, so the cost is spread over the batch
+    fn evict(buckets: &mut HashMap<String, TokenBucket>) {
+        let mut updated: Vec<Instant> = buckets.values().map(|bucket| bucket.updated).collect();
+        let (_, cutoff, _) = updated.select_nth_unstable(EVICT_BATCH);
+        let cutoff = *cutoff;
+        buckets.retain(|_, bucket| bucket.updated > cutoff);
+    }
+
+    fn digest(value: &str) -> String { URL_SAFE_NO_PAD.encode(Sha256::digest(value.as_bytes())) }
+}
+
+impl RateLimitConfigTrait for RateLimitService {
+    fn rate_limit_config(&self) -> &RateLimitConfig { &self.config }
+}
+
+impl RateLimitTrait for RateLimitService {
+    fn check(&self, path: &str, ip: Option<IpAddr>, client: Option<&str>) -> RateDecision {
+        let Some(bucket) = self.get_rate_bucket(path) else {
+            return RateDecision::Allowed;
+        };
+        let mut limits = Vec::new();
+        if let (Some(quota), Some(ip)) = (&bucket.per_ip, ip) {
+            limits.push((format!("{}|ip|{}", bucket.path, ip), quota));
+        }
+        if let (Some(quota), Some(client)) = (&bucket.per_client, client) {
+            limits.push((format!("{}|client|{}", bucket.path, client), quota));
+        }
+
+        // The address goes first, so a limited address cannot mint new client buckets
+        for (key, quota) in limits {
+            if let RateDecision::Limited(secs) = self.take(key, quota) {
+                return RateDecision::Limited(secs.max(1));
+            }
+        }
+        RateDecision::Allowed
+    }
+
+    fn reads_body(&self, path: &str) -> bool {
+        let per_client = self.get_rate_bucket(path).is_some_and(|b| b.per_client.is_some());
+        let capped = path.starts_with(GRANT_PATH) && self.get_max_pending().is_some();
+        per_client || capped
+    }
+
+    fn client_key(&self, path: &str, body: &[u8]) -> Option<String> {
+        if let Ok(value) = serde_json::from_slice::<Value>(body) {
+            // GNAP clients are either a key or a reference to one
+            match value.get("client") {
+                Some(Value::String(instance)) => return Some(instance.clone()),
+                Some(client) => {
+                    if let Some(key) = client.get("key") {
+                        return Some(Self::digest(&key.to_string()));
+                    }
+                }
+                None => {}
+            }
+            if let Some(client_id) = value.get("client_id").and_then(Value::as_str) {
+                return Some(client_id.to_string());
+            }
+        }
+        if let Ok(mut params) = serde_urlencoded::from_bytes::<HashMap<String, String>>(body) {
+            if let Some(client) =
+                params.remove("client_id").or_else(|| params.remove("pre-authorized_code"))
+            {
+                return Some(Self::digest(&client));
+            }
+        }
+        // Requests like presentations carry their session in the path instead
+        let bucket = self.get_rate_bucket(path)?;
+        let rest = path.strip_prefix(&bucket.path)?.trim_matches('/');
+        (!rest.is_empty()).then(|| rest.to_string())
+    }
+
+    fn pending_owner(&self, path: &str, body: &[u8], ip: Option<IpAddr>) -> Option<PendingOwner> {
+        if !path.starts_with(GRANT_PATH) {
+            return None;
+        }
+        let value = serde_json::from_slice::<Value>(body).ok()?;
+        // Clients without a class would otherwise all share the gatekeeper's "Unknown" slug
+        match value.pointer("/client/class_id").and_then(Value::as_str) {
+            Some(slug) => Some(PendingOwner::Participant(slug.to_string())),
+            None => ip.map(PendingOwner::Address)
+        }
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Formatter;
+use std::net::IpAddr;
+use std::time::Instant;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RateDecision {
+    Allowed,
+    // Seconds until the next request would be accepted
+    Limited(u64)
+}
+
+// Client address as resolved by the rate limiter, proxies taken into account
+#[derive(Debug, Clone, Copy)]
+pub struct ClientIp(pub IpAddr);
+
+// Whose pending requests a grant request counts against
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingOwner {
+    Participant(String),
+    // Clients without a class are told apart by address only
+    Address(IpAddr)
+}
+
+impl fmt::Display for PendingOwner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PendingOwner::Participant(slug) => write!(f, "participant {}", slug),
+            PendingOwner::Address(ip) => write!(f, "address {}", ip)
+        }
+    }
+}
+
+// Buckets by key, along with when idle ones were last swept
+#[derive(Debug)]
+pub struct TrackedBuckets {
+    pub buckets: HashMap<String, TokenBucket>,
+    pub swept: Instant
+}
+
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    pub tokens: f64,
+    pub capacity: f64,
+    // Tokens refilled per second
+    pub rate: f64,
+    pub updated: Instant
+}
+
+impl TokenBucket {
+    pub fn new(capacity: f64, rate: f64, now: Instant) -> Self {
+        Self { tokens: capacity, capacity, rate, updated: now }
+    }
+
+    pub fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated = now;
+    }
+
+    pub fn take(&mut self, now: Instant) -> RateDecision {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return RateDecision::Allowed;
+        }
+        if self.rate <= 0.0 {
+            return RateDecision::Limited(60);
+        }
+        RateDecision::Limited(((1.0 - self.tokens) / self.rate).ceil() as u64)
+    }
+
+    // A full bucket behaves like a fresh one, so it can be dropped
+    pub fn is_full(&self, now: Instant) -> bool {
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens + elapsed * self.rate >= self.capacity
+    }
+}
@@ -15,8 +15,11 @@
  * along with this program. If not, see <https://www.gnu.org/licenses/>.
  */
 
+pub mod postgres;
 mod repo_trait;
 mod service;
+pub mod subtraits;
+pub mod types;
 
 pub use repo_trait::RepoTrait;
 pub use service::RepoForSql;
@@ -0,0 +1,67 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::approval_decision::{ActiveModel, Entity, Model, NewModel};
+use crate::services::repo::subtraits::ApprovalDecisionTrait;
+
+pub struct ApprovalDecisionRepo {
+    db_connection: DatabaseConnection
+}
+
+impl ApprovalDecisionRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl ApprovalDecisionTrait for ApprovalDecisionRepo {
+    #[instrument(name = "repo.approval_decision.get_by_id", level = "debug", skip_all)]
+    async fn get_by_id(&self, id: &str) -> Outcome<Model> {
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| Errors::db(format!("No approval decision for request '{}'", id), None))
+    }
+
+    #[instrument(name = "repo.approval_decision.find_by_id", level = "debug", skip_all)]
+    async fn find_by_id(&self, id: &str) -> Outcome<Option<Model>> {
+        Entity::find_by_id(id)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve approval decision", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.approval_decision.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to save approval decision", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.approval_decision.delete", level = "debug", skip_all)]
+    async fn delete(&self, id: &str) -> Outcome<()> {
+        Entity::delete_by_id(id)
+            .exec(&self.db_connection)
+            .await
+            .map(|_| ())
+            .map_err(|e| Errors::db("Unable to delete approval decision", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,65 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder
+};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::approval_vote::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::services::repo::subtraits::ApprovalVoteTrait;
+
+pub struct ApprovalVoteRepo {
+    db_connection: DatabaseConnection
+}
+
+impl ApprovalVoteRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl ApprovalVoteTrait for ApprovalVoteRepo {
+    #[instrument(name = "repo.approval_vote.get_by_request", level = "debug", skip_all)]
+    async fn get_by_request(&self, request_id: &str) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .filter(Column::RequestId.eq(request_id))
+            .order_by_asc(Column::VotedAt)
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve approval votes", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.approval_vote.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to save approval vote", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.approval_vote.delete_by_request", level = "debug", skip_all)]
+    async fn delete_by_request(&self, request_id: &str) -> Outcome<u64> {
+        Entity::delete_many()
+            .filter(Column::RequestId.eq(request_id))
+            .exec(&self.db_connection)
+            .await
+            .map(|res| res.rows_affected)
+            .map_err(|e| Errors::db("Unable to delete approval votes", Some(Box::new(e))))
+    }
+}
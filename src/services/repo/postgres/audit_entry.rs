@@ -0,0 +1,66 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect
+};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::audit_entry::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::services::repo::subtraits::AuditEntryTrait;
+
+pub struct AuditEntryRepo {
+    db_connection: DatabaseConnection
+}
+
+impl AuditEntryRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl AuditEntryTrait for AuditEntryRepo {
+    #[instrument(name = "repo.audit_entry.last", level = "debug", skip_all)]
+    async fn last(&self) -> Outcome<Option<Model>> {
+        Entity::find()
+            .order_by_desc(Column::Seq)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve last audit entry", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.audit_entry.get_after", level = "debug", skip_all)]
+    async fn get_after(&self, seq: i64, limit: u64) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .filter(Column::Seq.gt(seq))
+            .order_by_asc(Column::Seq)
+            .limit(limit)
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve audit entries", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.audit_entry.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to append audit entry", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,126 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, QueryFilter,
+    QueryOrder, QuerySelect, TransactionTrait
+};
+use tracing::instrument;
+use ymir::data::entities::vc_request;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::callback_outbox::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::services::outbox::types::CallbackStatus;
+use crate::services::repo::subtraits::CallbackOutboxTrait;
+
+pub struct CallbackOutboxRepo {
+    db_connection: DatabaseConnection
+}
+
+impl CallbackOutboxRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl CallbackOutboxTrait for CallbackOutboxRepo {
+    #[instrument(name = "repo.callback_outbox.get_by_id", level = "debug", skip_all)]
+    async fn get_by_id(&self, id: &str) -> Outcome<Model> {
+        Entity::find_by_id(id)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve callback", Some(Box::new(e))))?
+            .ok_or_else(|| Errors::db(format!("Callback '{}' not found", id), None))
+    }
+
+    #[instrument(name = "repo.callback_outbox.get_by_request", level = "debug", skip_all)]
+    async fn get_by_request(&self, request_id: &str) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .filter(Column::RequestId.eq(request_id))
+            .order_by_asc(Column::CreatedAt)
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve callbacks", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.callback_outbox.get_due", level = "debug", skip_all)]
+    async fn get_due(&self, now: NaiveDateTime, limit: u64) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .filter(Column::Status.eq(CallbackStatus::Pending.to_string()))
+            .filter(Column::NextAttemptAt.lte(now))
+            .order_by_asc(Column::NextAttemptAt)
+            .limit(limit)
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve due callbacks", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.callback_outbox.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to queue callback", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.callback_outbox.update", level = "debug", skip_all)]
+    async fn update(&self, model: Model) -> Outcome<Model> {
+        model
+            .into_active_model()
+            .reset_all()
+            .update(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to update callback", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.callback_outbox.create_with_request", level = "debug", skip_all)]
+    async fn create_with_request(
+        &self,
+        request: vc_request::Model,
+        from_status: &str,
+        model: NewModel
+    ) -> Outcome<Option<Model>> {
+        let txn = self
+            .db_connection
+            .begin()
+            .await
+            .map_err(|e| Errors::db("Unable to start transaction", Some(Box::new(e))))?;
+
+        let id = request.id.clone();
+        let updated = vc_request::Entity::update_many()
+            .set(request.into_active_model().reset_all())
+            .filter(vc_request::Column::Id.eq(id))
+            .filter(vc_request::Column::Status.eq(from_status))
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to update request", Some(Box::new(e))))?;
+        // Dropping the transaction rolls it back
+        if updated.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        let callback = ActiveModel::from(model)
+            .insert(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to queue callback", Some(Box::new(e))))?;
+        txn.commit()
+            .await
+            .map_err(|e| Errors::db("Unable to commit transaction", Some(Box::new(e))))?;
+        Ok(Some(callback))
+    }
+}
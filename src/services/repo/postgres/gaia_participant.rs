@@ -0,0 +1,156 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel,
+    QueryFilter, QuerySelect, TransactionTrait
+};
+use tracing::instrument;
+use ymir::data::entities::{issuing, vc_request};
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::gaia_participant::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::data::entities::request_event;
+use crate::services::approval::types::RequestEvent;
+use crate::services::repo::subtraits::GaiaParticipantTrait;
+
+pub struct GaiaParticipantRepo {
+    db_connection: DatabaseConnection
+}
+
+impl GaiaParticipantRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl GaiaParticipantTrait for GaiaParticipantRepo {
+    #[instrument(name = "repo.gaia_participant.get_all", level = "debug", skip_all)]
+    async fn get_all(&self, limit: Option<u64>, offset: Option<u64>) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .limit(limit)
+            .offset(offset)
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve gaia participants", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.gaia_participant.get_by_id", level = "debug", skip_all)]
+    async fn get_by_id(&self, id: &str) -> Outcome<Model> {
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| Errors::db(format!("Gaia participant '{}' not found", id), None))
+    }
+
+    #[instrument(name = "repo.gaia_participant.find_by_id", level = "debug", skip_all)]
+    async fn find_by_id(&self, id: &str) -> Outcome<Option<Model>> {
+        Entity::find_by_id(id)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve gaia participant", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.gaia_participant.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to create gaia participant", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.gaia_participant.update", level = "debug", skip_all)]
+    async fn update(&self, model: Model) -> Outcome<Model> {
+        model
+            .into_active_model()
+            .reset_all()
+            .update(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to update gaia participant", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.gaia_participant.approve", level = "debug", skip_all)]
+    async fn approve(
+        &self,
+        model: Model,
+        requests: Vec<(vc_request::NewModel, String)>,
+        credential_data: &str,
+        actor: Option<String>,
+        prepare: &(dyn Fn(&vc_request::Model) -> issuing::NewModel + Send + Sync)
+    ) -> Outcome<Option<Model>> {
+        let txn = self
+            .db_connection
+            .begin()
+            .await
+            .map_err(|e| Errors::db("Unable to start transaction", Some(Box::new(e))))?;
+
+        let id = model.id.clone();
+        let updated = Entity::update_many()
+            .set(model.into_active_model().reset_all())
+            .filter(Column::Id.eq(id.clone()))
+            .filter(Column::Status.eq("Verified"))
+            .exec(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to update gaia participant", Some(Box::new(e))))?;
+        // Dropping the transaction rolls it back
+        if updated.rows_affected == 0 {
+            return Ok(None);
+        }
+
+        for (n_request, vc_uri) in requests {
+            let mut request = vc_request::ActiveModel::from(n_request);
+            request.status = ActiveValue::Set("Approved".to_string());
+            request.vc_uri = ActiveValue::Set(Some(vc_uri.clone()));
+            let request = request
+                .insert(&txn)
+                .await
+                .map_err(|e| Errors::db("Unable to create request", Some(Box::new(e))))?;
+
+            let events = [(RequestEvent::Created, None), (RequestEvent::Approved, actor.clone())];
+            for (event, actor) in events {
+                let n_event = request_event::NewModel {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    request_id: request.id.clone(),
+                    event: event.to_string(),
+                    actor,
+                    reason: None
+                };
+                request_event::ActiveModel::from(n_event)
+                    .insert(&txn)
+                    .await
+                    .map_err(|e| Errors::db("Unable to record request event", Some(Box::new(e))))?;
+            }
+
+            let mut issuing = issuing::ActiveModel::from(prepare(&request));
+            issuing.uri = ActiveValue::Set(Some(vc_uri));
+            issuing.credential_data = ActiveValue::Set(Some(credential_data.to_string()));
+            issuing
+                .insert(&txn)
+                .await
+                .map_err(|e| Errors::db("Unable to create issuing session", Some(Box::new(e))))?;
+        }
+
+        let model = Entity::find_by_id(id)
+            .one(&txn)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve gaia participant", Some(Box::new(e))))?
+            .ok_or_else(|| Errors::db("Gaia participant vanished during review", None))?;
+        txn.commit()
+            .await
+            .map_err(|e| Errors::db("Unable to commit transaction", Some(Box::new(e))))?;
+        Ok(Some(model))
+    }
+}
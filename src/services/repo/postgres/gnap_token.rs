@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, QueryFilter
+};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::gnap_token::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::services::repo::subtraits::GnapTokenTrait;
+
+pub struct GnapTokenRepo {
+    db_connection: DatabaseConnection
+}
+
+impl GnapTokenRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl GnapTokenTrait for GnapTokenRepo {
+    #[instrument(name = "repo.gnap_token.find_by_value_hash", level = "debug", skip_all)]
+    async fn find_by_value_hash(&self, value_hash: &str) -> Outcome<Option<Model>> {
+        Entity::find()
+            .filter(Column::ValueHash.eq(value_hash))
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve access token", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.gnap_token.get_by_grant", level = "debug", skip_all)]
+    async fn get_by_grant(&self, grant_id: &str) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .filter(Column::GrantId.eq(grant_id))
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve access tokens", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.gnap_token.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to create access token", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.gnap_token.update", level = "debug", skip_all)]
+    async fn update(&self, model: Model) -> Outcome<Model> {
+        model
+            .into_active_model()
+            .reset_all()
+            .update(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to update access token", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,80 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, IntoActiveModel, QueryFilter
+};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::interaction_start::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::services::repo::subtraits::InteractionStartTrait;
+
+pub struct InteractionStartRepo {
+    db_connection: DatabaseConnection
+}
+
+impl InteractionStartRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl InteractionStartTrait for InteractionStartRepo {
+    #[instrument(name = "repo.interaction_start.get_by_id", level = "debug", skip_all)]
+    async fn get_by_id(&self, id: &str) -> Outcome<Model> {
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| Errors::db(format!("Interaction '{}' not found", id), None))
+    }
+
+    #[instrument(name = "repo.interaction_start.find_by_id", level = "debug", skip_all)]
+    async fn find_by_id(&self, id: &str) -> Outcome<Option<Model>> {
+        Entity::find_by_id(id)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve interaction", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.interaction_start.get_by_user_code", level = "debug", skip_all)]
+    async fn get_by_user_code(&self, code: &str) -> Outcome<Model> {
+        Entity::find()
+            .filter(Column::UserCode.eq(code))
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve interaction", Some(Box::new(e))))?
+            .ok_or_else(|| Errors::db("No interaction matches the user code", None))
+    }
+
+    #[instrument(name = "repo.interaction_start.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to create interaction", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.interaction_start.update", level = "debug", skip_all)]
+    async fn update(&self, model: Model) -> Outcome<Model> {
+        model
+            .into_active_model()
+            .reset_all()
+            .update(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to update interaction", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait, Select
+};
+use tracing::instrument;
+use ymir::data::entities::{minions, vc_request};
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+use crate::data::entities::gnap_grant;
+use crate::services::repo::subtraits::ListingTrait;
+use crate::services::repo::types::{MinionQuery, Page, PageQuery, RequestQuery, SortOrder};
+
+pub struct ListingRepo {
+    db_connection: DatabaseConnection
+}
+
+impl ListingRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+
+    fn order(order: &SortOrder) -> Order {
+        match order {
+            SortOrder::Asc => Order::Asc,
+            SortOrder::Desc => Order::Desc
+        }
+    }
+
+    fn invalid_sort(sort: &str) -> Errors {
+        Errors::format(BadFormat::Received, format!("Cannot sort by '{}'", sort), None)
+    }
+
+    async fn page<E>(&self, select: Select<E>, page: u64, limit: u64) -> Outcome<Page<E::Model>>
+    where
+        E: EntityTrait,
+        E::Model: Sync
+    {
+        let paginator = select.paginate(&self.db_connection, limit);
+        let total = paginator
+            .num_items()
+            .await
+            .map_err(|e| Errors::db("Unable to count rows", Some(Box::new(e))))?;
+        let items = paginator
+            .fetch_page(page - 1)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve page", Some(Box::new(e))))?;
+        Ok(Page { items, total, page, limit })
+    }
+}
+
+#[async_trait]
+impl ListingTrait for ListingRepo {
+    #[instrument(name = "repo.listing.requests", level = "debug", skip_all)]
+    async fn requests(&self, query: &RequestQuery) -> Outcome<Page<vc_request::Model>> {
+        use vc_request::{Column, Entity};
+
+        let mut cond = Condition::all();
+        if let Some(status) = &query.status {
+            cond = cond.add(Column::Status.eq(status));
+        }
+        if let Some(vc_type) = &query.vc_type {
+            cond = cond.add(Column::VcType.eq(vc_type));
+        }
+        if let Some(participant) = &query.participant {
+            cond = cond.add(Column::ParticipantSlug.contains(participant));
+        }
+        if let Some(from) = query.from {
+            cond = cond.add(Column::CreatedAt.gte(from));
+        }
+        if let Some(to) = query.to {
+            cond = cond.add(Column::CreatedAt.lte(to));
+        }
+
+        let column = match query.sort.as_deref().unwrap_or("created_at") {
+            "created_at" => Column::CreatedAt,
+            "id" => Column::Id,
+            "participant_slug" => Column::ParticipantSlug,
+            "vc_type" => Column::VcType,
+            "status" => Column::Status,
+            sort => return Err(Self::invalid_sort(sort))
+        };
+
+        let select = Entity::find()
+            .filter(cond)
+            .order_by(column, Self::order(&query.order))
+            .order_by_asc(Column::Id);
+        self.page(select, query.current_page(), query.page_limit()).await
+    }
+
+    #[instrument(name = "repo.listing.minions", level = "debug", skip_all)]
+    async fn minions(&self, query: &MinionQuery) -> Outcome<Page<minions::Model>> {
+        use minions::{Column, Entity};
+
+        let mut cond = Condition::all();
+        if let Some(participant) = &query.participant {
+            cond = cond.add(Column::ParticipantSlug.contains(participant));
+        }
+        if let Some(participant_type) = &query.participant_type {
+            cond = cond.add(Column::ParticipantType.eq(participant_type));
+        }
+        if let Some(is_vc_issued) = query.is_vc_issued {
+            cond = cond.add(Column::IsVcIssued.eq(is_vc_issued));
+        }
+        if let Some(from) = query.from {
+            cond = cond.add(Column::SavedAt.gte(from));
+        }
+        if let Some(to) = query.to {
+            cond = cond.add(Column::SavedAt.lte(to));
+        }
+
+        let column = match query.sort.as_deref().unwrap_or("saved_at") {
+            "saved_at" => Column::SavedAt,
+            "participant_id" => Column::ParticipantId,
+            "participant_slug" => Column::ParticipantSlug,
+            "participant_type" => Column::ParticipantType,
+            "is_vc_issued" => Column::IsVcIssued,
+            sort => return Err(Self::invalid_sort(sort))
+        };
+
+        let select = Entity::find()
+            .filter(cond)
+            .order_by(column, Self::order(&query.order))
+            .order_by_asc(Column::ParticipantId);
+        self.page(select, query.current_page(), query.page_limit()).await
+    }
+
+    #[instrument(name = "repo.listing.count_pending", level = "debug", skip_all)]
+    async fn count_pending(&self, participant_slug: &str) -> Outcome<u64> {
+        use vc_request::{Column, Entity};
+
+        Entity::find()
+            .filter(Column::ParticipantSlug.eq(participant_slug))
+            .filter(Column::Status.eq("Pending"))
+            .count(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to count pending requests", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.listing.count_pending_from", level = "debug", skip_all)]
+    async fn count_pending_from(&self, client_ip: &str) -> Outcome<u64> {
+        let grants = gnap_grant::Entity::find()
+            .select_only()
+            .column(gnap_grant::Column::Id)
+            .filter(gnap_grant::Column::ClientIp.eq(client_ip))
+            .into_query();
+        vc_request::Entity::find()
+            .filter(vc_request::Column::Id.in_subquery(grants))
+            .filter(vc_request::Column::Status.eq("Pending"))
+            .count(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to count pending requests", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.listing.has_issued", level = "debug", skip_all)]
+    async fn has_issued(&self, participant_slug: &str) -> Outcome<bool> {
+        use minions::{Column, Entity};
+
+        Entity::find()
+            .filter(Column::ParticipantSlug.eq(participant_slug))
+            .filter(Column::IsVcIssued.eq(true))
+            .count(&self.db_connection)
+            .await
+            .map(|count| count > 0)
+            .map_err(|e| Errors::db("Unable to count issued minions", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod admin_login;
+mod approval_decision;
+mod approval_vote;
+mod audit_entry;
+mod callback_outbox;
+mod gaia_participant;
+mod gnap_grant;
+mod gnap_token;
+mod interaction_start;
+mod listing;
+mod request_event;
+mod resource_server;
+mod response_key;
+mod session_deadline;
+mod verification_session;
+mod verification_trust;
+
+pub use admin_login::AdminLoginRepo;
+pub use approval_decision::ApprovalDecisionRepo;
+pub use approval_vote::ApprovalVoteRepo;
+pub use audit_entry::AuditEntryRepo;
+pub use callback_outbox::CallbackOutboxRepo;
+pub use gaia_participant::GaiaParticipantRepo;
+pub use gnap_grant::GnapGrantRepo;
+pub use gnap_token::GnapTokenRepo;
+pub use interaction_start::InteractionStartRepo;
+pub use listing::ListingRepo;
+pub use request_event::RequestEventRepo;
+pub use resource_server::ResourceServerRepo;
+pub use response_key::ResponseKeyRepo;
+pub use session_deadline::SessionDeadlineRepo;
+pub use verification_session::VerificationSessionRepo;
+pub use verification_trust::VerificationTrustRepo;
@@ -0,0 +1,69 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder
+};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::request_event::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::services::approval::types::RequestEvent;
+use crate::services::repo::subtraits::RequestEventTrait;
+
+pub struct RequestEventRepo {
+    db_connection: DatabaseConnection
+}
+
+impl RequestEventRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl RequestEventTrait for RequestEventRepo {
+    #[instrument(name = "repo.request_event.get_by_request", level = "debug", skip_all)]
+    async fn get_by_request(&self, request_id: &str) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .filter(Column::RequestId.eq(request_id))
+            .order_by_asc(Column::CreatedAt)
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve request history", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.request_event.record", level = "debug", skip_all)]
+    async fn record(
+        &self,
+        request_id: &str,
+        event: &RequestEvent,
+        actor: Option<String>,
+        reason: Option<String>
+    ) -> Outcome<Model> {
+        let model = NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            request_id: request_id.to_string(),
+            event: event.to_string(),
+            actor,
+            reason
+        };
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to record request event", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::resource_server::{ActiveModel, Column, Entity, Model, NewModel};
+use crate::services::repo::subtraits::ResourceServerTrait;
+
+pub struct ResourceServerRepo {
+    db_connection: DatabaseConnection
+}
+
+impl ResourceServerRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl ResourceServerTrait for ResourceServerRepo {
+    #[instrument(name = "repo.resource_server.find_by_secret_hash", level = "debug", skip_all)]
+    async fn find_by_secret_hash(&self, secret_hash: &str) -> Outcome<Option<Model>> {
+        Entity::find()
+            .filter(Column::SecretHash.eq(secret_hash))
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve resource server", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.resource_server.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to register resource server", Some(Box::new(e))))
+    }
+}
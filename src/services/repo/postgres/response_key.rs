@@ -0,0 +1,67 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::response_key::{ActiveModel, Entity, Model, NewModel};
+use crate::services::repo::subtraits::ResponseKeyTrait;
+
+pub struct ResponseKeyRepo {
+    db_connection: DatabaseConnection
+}
+
+impl ResponseKeyRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl ResponseKeyTrait for ResponseKeyRepo {
+    #[instrument(name = "repo.response_key.get_by_id", level = "debug", skip_all)]
+    async fn get_by_id(&self, id: &str) -> Outcome<Model> {
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| Errors::security("No encryption key for this verification", None))
+    }
+
+    #[instrument(name = "repo.response_key.find_by_id", level = "debug", skip_all)]
+    async fn find_by_id(&self, id: &str) -> Outcome<Option<Model>> {
+        Entity::find_by_id(id)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve response key", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.response_key.create", level = "debug", skip_all)]
+    async fn create(&self, model: NewModel) -> Outcome<Model> {
+        ActiveModel::from(model)
+            .insert(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to create response key", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.response_key.delete", level = "debug", skip_all)]
+    async fn delete(&self, id: &str) -> Outcome<()> {
+        Entity::delete_by_id(id)
+            .exec(&self.db_connection)
+            .await
+            .map(|_| ())
+            .map_err(|e| Errors::db("Unable to delete response key", Some(Box::new(e))))
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    IntoActiveModel, QueryFilter
+};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::session_deadline::{ActiveModel, Column, Entity, Model};
+use crate::services::expiry::types::{SessionOrigin, SessionPhase};
+use crate::services::repo::subtraits::SessionDeadlineTrait;
+
+pub struct SessionDeadlineRepo {
+    db_connection: DatabaseConnection
+}
+
+impl SessionDeadlineRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+
+    fn set(model: &mut ActiveModel, phase: &SessionPhase, until: Option<NaiveDateTime>) {
+        let value = ActiveValue::Set(until);
+        match phase {
+            SessionPhase::Interaction => model.interaction_until = value,
+            SessionPhase::Verification => model.verification_until = value,
+            SessionPhase::Issuance => model.issuance_until = value
+        }
+    }
+}
+
+#[async_trait]
+impl SessionDeadlineTrait for SessionDeadlineRepo {
+    #[instrument(name = "repo.session_deadline.find_by_id", level = "debug", skip_all)]
+    async fn find_by_id(&self, id: &str) -> Outcome<Option<Model>> {
+        Entity::find_by_id(id)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve session deadline", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.session_deadline.open", level = "debug", skip_all)]
+    async fn open(
+        &self,
+        id: &str,
+        origin: &SessionOrigin,
+        phase: &SessionPhase,
+        until: NaiveDateTime
+    ) -> Outcome<Model> {
+        let result = match self.find_by_id(id).await? {
+            Some(model) => {
+                let mut model = model.into_active_model();
+                Self::set(&mut model, phase, Some(until));
+                model.update(&self.db_connection).await
+            }
+            None => {
+                let mut model = ActiveModel {
+                    id: ActiveValue::Set(id.to_string()),
+                    origin: ActiveValue::Set(origin.to_string()),
+                    interaction_until: ActiveValue::Set(None),
+                    verification_until: ActiveValue::Set(None),
+                    issuance_until: ActiveValue::Set(None),
+                    expired_at: ActiveValue::Set(None)
+                };
+                Self::set(&mut model, phase, Some(until));
+                model.insert(&self.db_connection).await
+            }
+        };
+        result.map_err(|e| Errors::db("Unable to save session deadline", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.session_deadline.close", level = "debug", skip_all)]
+    async fn close(&self, id: &str, phase: &SessionPhase) -> Outcome<()> {
+        let Some(model) = self.find_by_id(id).await? else {
+            return Ok(());
+        };
+        let mut model = model.into_active_model();
+        Self::set(&mut model, phase, None);
+        model
+            .update(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to close session deadline", Some(Box::new(e))))?;
+        Ok(())
+    }
+
+    #[instrument(name = "repo.session_deadline.expire", level = "debug", skip_all)]
+    async fn expire(&self, id: &str, origin: &SessionOrigin, now: NaiveDateTime) -> Outcome<()> {
+        let mut model = self.open(id, origin, &SessionPhase::Issuance, now).await?;
+        model.expired_at = Some(now);
+        self.update(model).await?;
+        Ok(())
+    }
+
+    #[instrument(name = "repo.session_deadline.get_expired", level = "debug", skip_all)]
+    async fn get_expired(&self, now: NaiveDateTime) -> Outcome<Vec<Model>> {
+        Entity::find()
+            .filter(Column::ExpiredAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(Column::InteractionUntil.lt(now))
+                    .add(Column::VerificationUntil.lt(now))
+                    .add(Column::IssuanceUntil.lt(now))
+            )
+            .all(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve expired sessions", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.session_deadline.update", level = "debug", skip_all)]
+    async fn update(&self, model: Model) -> Outcome<Model> {
+        model
+            .into_active_model()
+            .reset_all()
+            .update(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to update session deadline", Some(Box::new(e))))
+    }
+}
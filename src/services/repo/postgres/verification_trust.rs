@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait};
+use tracing::instrument;
+use ymir::errors::{Errors, Outcome};
+
+use crate::data::entities::verification_trust::{ActiveModel, Entity, Model, NewModel};
+use crate::services::repo::subtraits::VerificationTrustTrait;
+
+pub struct VerificationTrustRepo {
+    db_connection: DatabaseConnection
+}
+
+impl VerificationTrustRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self { Self { db_connection } }
+}
+
+#[async_trait]
+impl VerificationTrustTrait for VerificationTrustRepo {
+    #[instrument(name = "repo.verification_trust.get_by_id", level = "debug", skip_all)]
+    async fn get_by_id(&self, id: &str) -> Outcome<Model> {
+        self.find_by_id(id)
+            .await?
+            .ok_or_else(|| Errors::db(format!("Trust evaluation '{}' not found", id), None))
+    }
+
+    #[instrument(name = "repo.verification_trust.find_by_id", level = "debug", skip_all)]
+    async fn find_by_id(&self, id: &str) -> Outcome<Option<Model>> {
+        Entity::find_by_id(id)
+            .one(&self.db_connection)
+            .await
+            .map_err(|e| Errors::db("Unable to retrieve trust evaluation", Some(Box::new(e))))
+    }
+
+    #[instrument(name = "repo.verification_trust.save", level = "debug", skip_all)]
+    async fn save(&self, model: NewModel) -> Outcome<Model> {
+        let exists = self.find_by_id(&model.id).await?.is_some();
+        let active = ActiveModel::from(model);
+        let result = match exists {
+            true => active.reset_all().update(&self.db_connection).await,
+            false => active.insert(&self.db_connection).await
+        };
+        result.map_err(|e| Errors::db("Unable to save trust evaluation", Some(Box::new(e))))
+    }
+}
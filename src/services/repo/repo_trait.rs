@@ -21,10 +21,33 @@ use ymir::services::repo::subtraits::{
     IssuingTrait, MinionsTrait, RecvInteractionTrait, RecvVerificationTrait, VcRequestTrait
 };
 
+use crate::services::repo::subtraits::{
+    AdminLoginTrait, ApprovalDecisionTrait, ApprovalVoteTrait, AuditEntryTrait,
+    CallbackOutboxTrait, GaiaParticipantTrait, GnapGrantTrait, GnapTokenTrait,
+    InteractionStartTrait, ListingTrait, RequestEventTrait, ResourceServerTrait, ResponseKeyTrait,
+    SessionDeadlineTrait, VerificationSessionTrait, VerificationTrustTrait
+};
+
 pub trait RepoTrait: Send + Sync + 'static {
     fn request(&self) -> Arc<dyn VcRequestTrait>;
     fn interaction(&self) -> Arc<dyn RecvInteractionTrait>;
     fn verification(&self) -> Arc<dyn RecvVerificationTrait>;
     fn minions(&self) -> Arc<dyn MinionsTrait>;
     fn issuing(&self) -> Arc<dyn IssuingTrait>;
+    fn gaia(&self) -> Arc<dyn GaiaParticipantTrait>;
+    fn trust(&self) -> Arc<dyn VerificationTrustTrait>;
+    fn sessions(&self) -> Arc<dyn VerificationSessionTrait>;
+    fn deadlines(&self) -> Arc<dyn SessionDeadlineTrait>;
+    fn starts(&self) -> Arc<dyn InteractionStartTrait>;
+    fn grants(&self) -> Arc<dyn GnapGrantTrait>;
+    fn tokens(&self) -> Arc<dyn GnapTokenTrait>;
+    fn resource_servers(&self) -> Arc<dyn ResourceServerTrait>;
+    fn decisions(&self) -> Arc<dyn ApprovalDecisionTrait>;
+    fn votes(&self) -> Arc<dyn ApprovalVoteTrait>;
+    fn history(&self) -> Arc<dyn RequestEventTrait>;
+    fn listings(&self) -> Arc<dyn ListingTrait>;
+    fn callbacks(&self) -> Arc<dyn CallbackOutboxTrait>;
+    fn logins(&self) -> Arc<dyn AdminLoginTrait>;
+    fn audit(&self) -> Arc<dyn AuditEntryTrait>;
+    fn response_keys(&self) -> Arc<dyn ResponseKeyTrait>;
 }
@@ -25,6 +25,18 @@ use ymir::services::repo::subtraits::{
     IssuingTrait, MinionsTrait, RecvInteractionTrait, RecvVerificationTrait, VcRequestTrait
 };
 
+use crate::services::repo::postgres::{
+    AdminLoginRepo, ApprovalDecisionRepo, ApprovalVoteRepo, AuditEntryRepo, CallbackOutboxRepo,
+    GaiaParticipantRepo, GnapGrantRepo, GnapTokenRepo, InteractionStartRepo, ListingRepo,
+    RequestEventRepo, ResourceServerRepo, ResponseKeyRepo, SessionDeadlineRepo,
+    VerificationSessionRepo, VerificationTrustRepo
+};
+use crate::services::repo::subtraits::{
+    AdminLoginTrait, ApprovalDecisionTrait, ApprovalVoteTrait, AuditEntryTrait,
+    CallbackOutboxTrait, GaiaParticipantTrait, GnapGrantTrait, GnapTokenTrait,
+    InteractionStartTrait, ListingTrait, RequestEventTrait, ResourceServerTrait, ResponseKeyTrait,
+    SessionDeadlineTrait, VerificationSessionTrait, VerificationTrustTrait
+};
 use crate::services::repo::RepoTrait;
 
 #[derive(Clone)]
@@ -33,7 +45,23 @@ pub struct RepoForSql {
     interaction_repo: Arc<dyn RecvInteractionTrait>,
     verification_repo: Arc<dyn RecvVerificationTrait>,
     issuing_repo: Arc<dyn IssuingTrait>,
-    minions_repo: Arc<dyn MinionsTrait>
+    minions_repo: Arc<dyn MinionsTrait>,
+    gaia_repo: Arc<dyn GaiaParticipantTrait>,
+    trust_repo: Arc<dyn VerificationTrustTrait>,
+    session_repo: Arc<dyn VerificationSessionTrait>,
+    deadline_repo: Arc<dyn SessionDeadlineTrait>,
+    start_repo: Arc<dyn InteractionStartTrait>,
+    grant_repo: Arc<dyn GnapGrantTrait>,
+    token_repo: Arc<dyn GnapTokenTrait>,
+    resource_server_repo: Arc<dyn ResourceServerTrait>,
+    decision_repo: Arc<dyn ApprovalDecisionTrait>,
+    vote_repo: Arc<dyn ApprovalVoteTrait>,
+    event_repo: Arc<dyn RequestEventTrait>,
+    listing_repo: Arc<dyn ListingTrait>,
+    callback_repo: Arc<dyn CallbackOutboxTrait>,
+    login_repo: Arc<dyn AdminLoginTrait>,
+    audit_repo: Arc<dyn AuditEntryTrait>,
+    response_key_repo: Arc<dyn ResponseKeyTrait>
 }
 
 impl RepoForSql {
@@ -43,7 +71,23 @@ impl RepoForSql {
             interaction_repo: Arc::new(RecvInteractionRepo::new(db_connection.clone())),
             verification_repo: Arc::new(RecvVerificationRepo::new(db_connection.clone())),
             issuing_repo: Arc::new(IssuingRepo::new(db_connection.clone())),
-            minions_repo: Arc::new(MinionsRepo::new(db_connection.clone()))
+            minions_repo: Arc::new(MinionsRepo::new(db_connection.clone())),
+            gaia_repo: Arc::new(GaiaParticipantRepo::new(db_connection.clone())),
+            trust_repo: Arc::new(VerificationTrustRepo::new(db_connection.clone())),
+            session_repo: Arc::new(VerificationSessionRepo::new(db_connection.clone())),
+            deadline_repo: Arc::new(SessionDeadlineRepo::new(db_connection.clone())),
+            start_repo: Arc::new(InteractionStartRepo::new(db_connection.clone())),
+            grant_repo: Arc::new(GnapGrantRepo::new(db_connection.clone())),
+            token_repo: Arc::new(GnapTokenRepo::new(db_connection.clone())),
+            resource_server_repo: Arc::new(ResourceServerRepo::new(db_connection.clone())),
+            decision_repo: Arc::new(ApprovalDecisionRepo::new(db_connection.clone())),
+            vote_repo: Arc::new(ApprovalVoteRepo::new(db_connection.clone())),
+            event_repo: Arc::new(RequestEventRepo::new(db_connection.clone())),
+            listing_repo: Arc::new(ListingRepo::new(db_connection.clone())),
+            callback_repo: Arc::new(CallbackOutboxRepo::new(db_connection.clone())),
+            login_repo: Arc::new(AdminLoginRepo::new(db_connection.clone())),
+            audit_repo: Arc::new(AuditEntryRepo::new(db_connection.clone())),
+            response_key_repo: Arc::new(ResponseKeyRepo::new(db_connection.clone()))
         }
     }
 }
@@ -57,4 +101,38 @@ impl RepoTrait for RepoForSql {
     fn minions(&self) -> Arc<dyn MinionsTrait> { self.minions_repo.clone() }
 
     fn issuing(&self) -> Arc<dyn IssuingTrait> { self.issuing_repo.clone() }
+
+    fn gaia(&self) -> Arc<dyn GaiaParticipantTrait> { self.gaia_repo.clone() }
+
+    fn trust(&self) -> Arc<dyn VerificationTrustTrait> { self.trust_repo.clone() }
+
+    fn sessions(&self) -> Arc<dyn VerificationSessionTrait> { self.session_repo.clone() }
+
+    fn deadlines(&self) -> Arc<dyn SessionDeadlineTrait> { self.deadline_repo.clone() }
+
+    fn starts(&self) -> Arc<dyn InteractionStartTrait> { self.start_repo.clone() }
+
+    fn grants(&self) -> Arc<dyn GnapGrantTrait> { self.grant_repo.clone() }
+
+    fn tokens(&self) -> Arc<dyn GnapTokenTrait> { self.token_repo.clone() }
+
+    fn resource_servers(&self) -> Arc<dyn ResourceServerTrait> {
+        self.resource_server_repo.clone()
+    }
+
+    fn decisions(&self) -> Arc<dyn ApprovalDecisionTrait> { self.decision_repo.clone() }
+
+    fn votes(&self) -> Arc<dyn ApprovalVoteTrait> { self.vote_repo.clone() }
+
+    fn history(&self) -> Arc<dyn RequestEventTrait> { self.event_repo.clone() }
+
+    fn listings(&self) -> Arc<dyn ListingTrait> { self.listing_repo.clone() }
+
+    fn callbacks(&self) -> Arc<dyn CallbackOutboxTrait> { self.callback_repo.clone() }
+
+    fn logins(&self) -> Arc<dyn AdminLoginTrait> { self.login_repo.clone() }
+
+    fn audit(&self) -> Arc<dyn AuditEntryTrait> { self.audit_repo.clone() }
+
+    fn response_keys(&self) -> Arc<dyn ResponseKeyTrait> { self.response_key_repo.clone() }
 }
@@ -0,0 +1,28 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use ymir::errors::Outcome;
+
+use crate::data::entities::approval_vote::{Model, NewModel};
+
+#[async_trait]
+pub trait ApprovalVoteTrait: Send + Sync + 'static {
+    async fn get_by_request(&self, request_id: &str) -> Outcome<Vec<Model>>;
+    async fn create(&self, model: NewModel) -> Outcome<Model>;
+    async fn delete_by_request(&self, request_id: &str) -> Outcome<u64>;
+}
@@ -0,0 +1,39 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use ymir::data::entities::vc_request;
+use ymir::errors::Outcome;
+
+use crate::data::entities::callback_outbox::{Model, NewModel};
+
+#[async_trait]
+pub trait CallbackOutboxTrait: Send + Sync + 'static {
+    async fn get_by_id(&self, id: &str) -> Outcome<Model>;
+    async fn get_by_request(&self, request_id: &str) -> Outcome<Vec<Model>>;
+    async fn get_due(&self, now: NaiveDateTime, limit: u64) -> Outcome<Vec<Model>>;
+    async fn create(&self, model: NewModel) -> Outcome<Model>;
+    async fn update(&self, model: Model) -> Outcome<Model>;
+    // Saves the request and queues its callback at once, `None` if it already left `from_status`
+    async fn create_with_request(
+        &self,
+        request: vc_request::Model,
+        from_status: &str,
+        model: NewModel
+    ) -> Outcome<Option<Model>>;
+}
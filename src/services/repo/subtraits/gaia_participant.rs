@@ -0,0 +1,41 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use ymir::data::entities::{issuing, vc_request};
+use ymir::errors::Outcome;
+
+use crate::data::entities::gaia_participant::{Model, NewModel};
+
+#[async_trait]
+pub trait GaiaParticipantTrait: Send + Sync + 'static {
+    async fn get_all(&self, limit: Option<u64>, offset: Option<u64>) -> Outcome<Vec<Model>>;
+    async fn get_by_id(&self, id: &str) -> Outcome<Model>;
+    async fn find_by_id(&self, id: &str) -> Outcome<Option<Model>>;
+    async fn create(&self, model: NewModel) -> Outcome<Model>;
+    async fn update(&self, model: Model) -> Outcome<Model>;
+    // Stores an approved review along with every credential request (and its offer uri) and
+    // issuing session at once, None when the participant was no longer awaiting review
+    async fn approve(
+        &self,
+        model: Model,
+        requests: Vec<(vc_request::NewModel, String)>,
+        credential_data: &str,
+        actor: Option<String>,
+        prepare: &(dyn Fn(&vc_request::Model) -> issuing::NewModel + Send + Sync)
+    ) -> Outcome<Option<Model>>;
+}
@@ -0,0 +1,32 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use async_trait::async_trait;
+use ymir::data::entities::{minions, vc_request};
+use ymir::errors::Outcome;
+
+use crate::services::repo::types::{MinionQuery, Page, RequestQuery};
+
+// Filtered listings over the tables owned by ymir
+#[async_trait]
+pub trait ListingTrait: Send + Sync + 'static {
+    async fn requests(&self, query: &RequestQuery) -> Outcome<Page<vc_request::Model>>;
+    async fn minions(&self, query: &MinionQuery) -> Outcome<Page<minions::Model>>;
+    async fn count_pending(&self, participant_slug: &str) -> Outcome<u64>;
+    async fn count_pending_from(&self, client_ip: &str) -> Outcome<u64>;
+    async fn has_issued(&self, participant_slug: &str) -> Outcome<bool>;
+}
@@ -0,0 +1,50 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+mod admin_login;
+mod approval_decision;
+mod approval_vote;
+mod audit_entry;
+mod callback_outbox;
+mod gaia_participant;
+mod gnap_grant;
+mod gnap_token;
+mod interaction_start;
+mod listing;
+mod request_event;
+mod resource_server;
+mod response_key;
+mod session_deadline;
+mod verification_session;
+mod verification_trust;
+
+pub use admin_login::AdminLoginTrait;
+pub use approval_decision::ApprovalDecisionTrait;
+pub use approval_vote::ApprovalVoteTrait;
+pub use audit_entry::AuditEntryTrait;
+pub use callback_outbox::CallbackOutboxTrait;
+pub use gaia_participant::GaiaParticipantTrait;
+pub use gnap_grant::GnapGrantTrait;
+pub use gnap_token::GnapTokenTrait;
+pub use interaction_start::InteractionStartTrait;
+pub use listing::ListingTrait;
+pub use request_event::RequestEventTrait;
+pub use resource_server::ResourceServerTrait;
+pub use response_key::ResponseKeyTrait;
+pub use session_deadline::SessionDeadlineTrait;
+pub use verification_session::VerificationSessionTrait;
+pub use verification_trust::VerificationTrustTrait;
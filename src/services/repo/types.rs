@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_PAGE_LIMIT: u64 = 50;
+pub const MAX_PAGE_LIMIT: u64 = 500;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64
+}
+
+// Pages are 1-based
+pub trait PageQuery {
+    fn page(&self) -> Option<u64>;
+    fn limit(&self) -> Option<u64>;
+    fn current_page(&self) -> u64 { self.page().unwrap_or(1).max(1) }
+    fn page_limit(&self) -> u64 {
+        self.limit().unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RequestQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    pub status: Option<String>,
+    pub vc_type: Option<String>,
+    // Substring of the participant slug
+    pub participant: Option<String>,
+    // Bounds on `created_at`
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    // `created_at` (default), `id`, `participant_slug`, `vc_type` or `status`
+    pub sort: Option<String>,
+    #[serde(default)]
+    pub order: SortOrder
+}
+
+impl PageQuery for RequestQuery {
+    fn page(&self) -> Option<u64> { self.page }
+    fn limit(&self) -> Option<u64> { self.limit }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MinionQuery {
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+    // Substring of the participant slug
+    pub participant: Option<String>,
+    pub participant_type: Option<String>,
+    pub is_vc_issued: Option<bool>,
+    // Bounds on `saved_at`
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    // `saved_at` (default), `participant_id`, `participant_slug`, `participant_type` or
+    // `is_vc_issued`
+    pub sort: Option<String>,
+    #[serde(default)]
+    pub order: SortOrder
+}
+
+impl PageQuery for MinionQuery {
+    fn page(&self) -> Option<u64> { self.page }
+    fn limit(&self) -> Option<u64> { self.limit }
+}
@@ -0,0 +1,701 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use axum::http::{HeaderMap, Uri};
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use chrono::{Duration, Utc};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use jsonwebtoken::{decode, decode_header, DecodingKey, Validation};
+use serde_json::Value;
+use tracing::{debug, info};
+use ymir::errors::{BadFormat, Errors, Outcome};
+use ymir::services::client::ClientTrait;
+
+use super::types::{CachedStatusList, StatusList, StatusListFormat, StatusReference};
+use super::StatusTrait;
+use crate::config::status::{StatusConfig, StatusConfigTrait};
+use crate::utils::{extract_vp_credentials, jwk_algorithms, trace_headers, vc_issuer};
+
+// Inflated lists above this are refused, enough for 128M one-bit entries
+const MAX_LIST_BYTES: u64 = 16 * 1024 * 1024;
+// Lists cached at once, expired and then soonest to expire ones are dropped first
+const MAX_CACHED_LISTS: usize = 1_000;
+
+pub struct StatusService {
+    config: StatusConfig,
+    client: Arc<dyn ClientTrait>,
+    cache: RwLock<HashMap<String, CachedStatusList>>
+}
+
+impl StatusService {
+    pub fn new(config: StatusConfig, client: Arc<dyn ClientTrait>) -> Self {
+        Self { config, client, cache: RwLock::new(HashMap::new()) }
+    }
+
+    fn references(vc: &Value) -> Vec<StatusReference> {
+        let mut references = Vec::new();
+
+        let entries = match vc.get("credentialStatus") {
+            Some(Value::Array(entries)) => entries.clone(),
+            Some(entry) => vec![entry.clone()],
+            None => vec![]
+        };
+        for entry in entries {
+            let kind = entry.get("type").and_then(Value::as_str).unwrap_or_default();
+            if kind != "StatusList2021Entry" && kind != "BitstringStatusListEntry" {
+                continue;
+            }
+            let uri = entry.get("statusListCredential").and_then(Value::as_str);
+            let index = match entry.get("statusListIndex") {
+                Some(Value::String(index)) => index.parse().ok(),
+                Some(index) => index.as_u64().map(|i| i as usize),
+                None => None
+            };
+            if let (Some(uri), Some(index)) = (uri, index) {
+                references.push(StatusReference {
+                    uri: uri.to_string(),
+                    index,
+                    purpose: entry
+                        .get("statusPurpose")
+                        .and_then(Value::as_str)
+                        .unwrap_or("revocation")
+                        .to_string(),
+                    format: StatusListFormat::Bitstring
+                });
+            }
+        }
+
+        let token = vc.get("status").and_then(|status| status.get("status_list"));
+        if let Some(token) = token {
+            let uri = token.get("uri").and_then(Value::as_str);
+            let index = token.get("idx").and_then(Value::as_u64);
+            if let (Some(uri), Some(index)) = (uri, index) {
+                references.push(StatusReference {
+                    uri: uri.to_string(),
+                    index: index as usize,
+                    purpose: "revocation".to_string(),
+                    format: StatusListFormat::Token
+                });
+            }
+        }
+        references
+    }
+
+    fn inflate(data: &[u8], format: &StatusListFormat) -> Outcome<Vec<u8>> {
+        let mut out = Vec::new();
+        let result = match format {
+            StatusListFormat::Bitstring => {
+                GzDecoder::new(data).take(MAX_LIST_BYTES + 1).read_to_end(&mut out)
+            }
+            StatusListFormat::Token => {
+                ZlibDecoder::new(data).take(MAX_LIST_BYTES + 1).read_to_end(&mut out)
+            }
+        };
+        result.map_err(|e| {
+            Errors::format(BadFormat::Received, "Unable to inflate status list", Some(Box::new(e)))
+        })?;
+        if out.len() as u64 > MAX_LIST_BYTES {
+            return Err(Errors::format(BadFormat::Received, "Status list is too large", None));
+        }
+        Ok(out)
+    }
+
+    fn decode_list(encoded: &str) -> Outcome<Vec<u8>> {
+        // Bitstring Status List uses multibase, 'u' being base64url
+        let encoded = encoded.strip_prefix('u').unwrap_or(encoded).trim_end_matches('=');
+        URL_SAFE_NO_PAD.decode(encoded).or_else(|_| STANDARD.decode(encoded)).map_err(|e| {
+            Errors::format(BadFormat::Received, "Status list is not base64", Some(Box::new(e)))
+        })
+    }
+
+    fn is_internal(host: &str) -> bool {
+        match host.parse::<IpAddr>() {
+            Ok(IpAddr::V4(ip)) => {
+                ip.is_loopback()
+                    || ip.is_private()
+                    || ip.is_link_local()
+                    || ip.is_unspecified()
+                    || ip.is_broadcast()
+            }
+            Ok(IpAddr::V6(ip)) => match ip.to_ipv4_mapped() {
+                Some(ip) => Self::is_internal(&ip.to_string()),
+                None => {
+                    let prefix = ip.segments()[0];
+                    ip.is_loopback()
+                        || ip.is_unspecified()
+                        || prefix & 0xfe00 == 0xfc00
+                        || prefix & 0xffc0 == 0xfe80
+                }
+            },
+            Err(_) => host == "localhost" || host.ends_with(".localhost")
+        }
+    }
+
+    // Uris come from the presented credential, so only allowed or public https hosts are fetched
+    fn check_host(&self, uri: &str, what: &str) -> Outcome<()> {
+        let refused = || {
+            let msg = format!("Refusing to fetch {} from {}", what, uri);
+            Errors::unauthorized(msg, None)
+        };
+        let parsed: Uri = uri.parse().map_err(|_| refused())?;
+        let host = parsed.host().ok_or_else(refused)?;
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let allowed = self.get_status_allowed_hosts();
+        if allowed.iter().any(|allowed| allowed.eq_ignore_ascii_case(host)) {
+            return Ok(());
+        }
+        if !allowed.is_empty() || parsed.scheme_str() != Some("https") || Self::is_internal(host)
+        {
+            return Err(refused());
+        }
+        Ok(())
+    }
+
+    // Body of the response along with its max-age, if any
+    async fn fetch(&self, uri: &str, what: &str) -> Outcome<(String, Option<i64>)> {
+        self.check_host(uri, what)?;
+        let res = self.client.get(uri, Some(trace_headers(HeaderMap::new()))).await?;
+        if !res.status().is_success() {
+            let (status, msg) = (Some(res.status()), format!("Unable to resolve {}", what));
+            return Err(Errors::consumer(uri, "GET", status, msg, None));
+        }
+        let max_age =
+            Self::max_age(res.headers().get("cache-control").and_then(|h| h.to_str().ok()));
+        let body = res.text().await.map_err(|e| {
+            let msg = format!("Unable to read {}", what);
+            Errors::consumer(uri, "GET", None, msg, Some(Box::new(e)))
+        })?;
+        Ok((body, max_age))
+    }
+
+    async fn fetch_json(&self, uri: &str, what: &str) -> Outcome<Value> {
+        let (body, _) = self.fetch(uri, what).await?;
+        serde_json::from_str(&body)
+            .map_err(|e| Errors::parse(format!("Unable to parse {}", what), Some(Box::new(e))))
+    }
+
+    fn parse_jwk(value: &Value, kid: Option<&str>) -> Outcome<Jwk> {
+        let mut jwk: Jwk = serde_json::from_value(value.clone())
+            .map_err(|e| Errors::parse("Unable to parse issuer key", Some(Box::new(e))))?;
+        if let Some(kid) = kid {
+            jwk.common.key_id = Some(kid.to_string());
+        }
+        Ok(jwk)
+    }
+
+    fn did_jwk(did: &str) -> Outcome<Vec<Jwk>> {
+        let encoded = did.trim_start_matches("did:jwk:").split('#').next().unwrap_or_default();
+        let bytes = URL_SAFE_NO_PAD.decode(encoded).map_err(|e| {
+            Errors::format(BadFormat::Received, "Invalid did:jwk", Some(Box::new(e)))
+        })?;
+        let jwk = serde_json::from_slice(&bytes)
+            .map_err(|e| Errors::parse("Unable to parse did:jwk", Some(Box::new(e))))?;
+        Ok(vec![Self::parse_jwk(&jwk, Some(&format!("{}#0", did)))?])
+    }
+
+    async fn did_web(&self, did: &str) -> Outcome<Vec<Jwk>> {
+        let mut parts = did.trim_start_matches("did:web:").split(':');
+        let host = parts.next().unwrap_or_default().replace("%3A", ":");
+        let path = parts.collect::<Vec<_>>().join("/");
+        let uri = match path.is_empty() {
+            true => format!("https://{}/.well-known/did.json", host),
+            false => format!("https://{}/{}/did.json", host, path)
+        };
+        let document = self.fetch_json(&uri, "issuer DID document").await?;
+        let methods = document.get("verificationMethod").and_then(Value::as_array);
+        methods
+            .into_iter()
+            .flatten()
+            .filter_map(|method| {
+                let jwk = method.get("publicKeyJwk")?;
+                let id = method.get("id").and_then(Value::as_str).unwrap_or_default();
+                let id = match id.starts_with('#') {
+                    true => format!("{}{}", did, id),
+                    false => id.to_string()
+                };
+                Some(Self::parse_jwk(jwk, Some(&id)))
+            })
+            .collect()
+    }
+
+    // SD-JWT VC issuers publish their keys in JWT VC Issuer Metadata
+    async fn issuer_metadata(&self, issuer: &str) -> Outcome<Vec<Jwk>> {
+        let rest = issuer.trim_start_matches("https://");
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let path = path.trim_end_matches('/');
+        let uri = match path.is_empty() {
+            true => format!("https://{}/.well-known/jwt-vc-issuer", host),
+            false => format!("https://{}/.well-known/jwt-vc-issuer/{}", host, path)
+        };
+        let metadata = self.fetch_json(&uri, "issuer metadata").await?;
+        let jwks = match (metadata.get("jwks"), metadata.get("jwks_uri").and_then(Value::as_str)) {
+            (Some(jwks), _) => jwks.clone(),
+            (None, Some(jwks_uri)) => self.fetch_json(jwks_uri, "issuer JWKS").await?,
+            (None, None) => {
+                return Err(Errors::format(BadFormat::Received, "Issuer publishes no keys", None))
+            }
+        };
+        let jwks: JwkSet = serde_json::from_value(jwks)
+            .map_err(|e| Errors::parse("Unable to parse issuer JWKS", Some(Box::new(e))))?;
+        Ok(jwks.keys)
+    }
+
+    async fn issuer_key(&self, issuer: &str, kid: Option<&str>) -> Outcome<Jwk> {
+        let keys = match issuer {
+            did if did.starts_with("did:jwk:") => Self::did_jwk(did)?,
+            did if did.starts_with("did:web:") => self.did_web(did).await?,
+            url if url.starts_with("https://") => self.issuer_metadata(url).await?,
+            _ => {
+                return Err(Errors::unauthorized(
+                    format!("Issuer '{}' cannot be resolved to a key", issuer),
+                    None
+                ))
+            }
+        };
+        // A kid may be a full DID URL or just its fragment
+        let fragment = |id: &str| id.rsplit('#').next().unwrap_or(id).to_string();
+        let found = match kid {
+            Some(kid) => keys.into_iter().find(|jwk| {
+                let id = jwk.common.key_id.as_deref().unwrap_or_default();
+                id == kid || fragment(id) == fragment(kid)
+            }),
+            None if keys.len() == 1 => keys.into_iter().next(),
+            None => None
+        };
+        found.ok_or_else(|| {
+            let msg = format!("No key of issuer '{}' signed the status list", issuer);
+            Errors::unauthorized(msg, None)
+        })
+    }
+
+    // The list has to be signed by the issuer of the credential pointing to it, for that uri
+    async fn verified_claims(
+        &self,
+        body: &str,
+        reference: &StatusReference,
+        issuer: &str
+    ) -> Outcome<Value> {
+        let token = body.trim();
+        let header = decode_header(token).map_err(|e| {
+            Errors::unauthorized("Status list is not a signed JWT", Some(Box::new(e)))
+        })?;
+        let jwk = self.issuer_key(issuer, header.kid.as_deref()).await?;
+        let key = DecodingKey::from_jwk(&jwk).map_err(|e| {
+            Errors::unauthorized("Invalid status list issuer key", Some(Box::new(e)))
+        })?;
+        let algorithms = jwk_algorithms(&jwk);
+        let Some(alg) = algorithms.first() else {
+            let msg = "Status list issuer key has no usable algorithm";
+            return Err(Errors::unauthorized(msg, None));
+        };
+        let mut validation = Validation::new(*alg);
+        validation.algorithms = algorithms;
+        validation.set_issuer(&[issuer]);
+        validation.set_required_spec_claims(&["iss"]);
+        validation.validate_aud = false;
+        let claims = decode::<Value>(token, &key, &validation)
+            .map_err(|e| {
+                Errors::unauthorized("Status list signature is not valid", Some(Box::new(e)))
+            })?
+            .claims;
+
+        let subject = match reference.format {
+            StatusListFormat::Token => claims.get("sub"),
+            StatusListFormat::Bitstring => {
+                claims.get("vc").and_then(|vc| vc.get("id")).or_else(|| claims.get("jti"))
+            }
+        };
+        if subject.and_then(Value::as_str) != Some(reference.uri.as_str()) {
+            return Err(Errors::unauthorized(
+                format!("Status list at {} was issued for another location", reference.uri),
+                None
+            ));
+        }
+        Ok(claims)
+    }
+
+    fn parse(claims: &Value, format: &StatusListFormat) -> Outcome<(StatusList, Option<i64>)> {
+        let invalid = || Errors::format(BadFormat::Received, "Malformed status list", None);
+
+        match format {
+            StatusListFormat::Bitstring => {
+                let vc = claims.get("vc").unwrap_or(claims);
+                let encoded = vc
+                    .get("credentialSubject")
+                    .and_then(|s| s.get("encodedList"))
+                    .and_then(Value::as_str)
+                    .ok_or_else(invalid)?;
+                let lst = Self::inflate(&Self::decode_list(encoded)?, format)?;
+                Ok((StatusList { format: format.clone(), bits: 1, lst }, None))
+            }
+            StatusListFormat::Token => {
+                let status_list = claims.get("status_list").ok_or_else(invalid)?;
+                let bits = status_list.get("bits").and_then(Value::as_u64).unwrap_or(1) as usize;
+                if ![1, 2, 4, 8].contains(&bits) {
+                    return Err(invalid());
+                }
+                let encoded =
+                    status_list.get("lst").and_then(Value::as_str).ok_or_else(invalid)?;
+                let lst = Self::inflate(&Self::decode_list(encoded)?, format)?;
+                let ttl = claims.get("ttl").and_then(Value::as_i64);
+                Ok((StatusList { format: format.clone(), bits, lst }, ttl))
+            }
+        }
+    }
+
+    fn max_age(cache_control: Option<&str>) -> Option<i64> {
+        cache_control?
+            .split(',')
+            .filter_map(|directive| directive.trim().strip_prefix("max-age="))
+            .find_map(|age| age.parse().ok())
+    }
+
+    async fn list(&self, reference: &StatusReference, issuer: &str) -> Outcome<StatusList> {
+        let cached = {
+            let cache = self.cache.read().expect("Status cache lock poisoned");
+            cache.get(&reference.uri).cloned()
+        };
+        if let Some(cached) = cached {
+            if cached.expires_at > Utc::now() && cached.issuer == issuer {
+                debug!("Status list {} served from cache", reference.uri);
+                return Ok(cached.list);
+            }
+        }
+
+        info!("Resolving status list {}", reference.uri);
+        let (body, max_age) = self.fetch(&reference.uri, "status list").await?;
+        let claims = self.verified_claims(&body, reference, issuer).await?;
+        let (list, ttl) = Self::parse(&claims, &reference.format)?;
+        let ttl = max_age.or(ttl).unwrap_or(self.get_default_ttl());
+        let cached = CachedStatusList {
+            list: list.clone(),
+            issuer: issuer.to_string(),
+            expires_at: Utc::now() + Duration::seconds(ttl)
+        };
+        let mut cache = self.cache.write().expect("Status cache lock poisoned");
+        if cache.len() >= MAX_CACHED_LISTS && !cache.contains_key(&reference.uri) {
+            let now = Utc::now();
+            cache.retain(|_, cached| cached.expires_at > now);
+            let soonest = match cache.len() >= MAX_CACHED_LISTS {
+                true => cache.iter().min_by_key(|(_, c)| c.expires_at).map(|(uri, _)| uri.clone()),
+                false => None
+            };
+            if let Some(soonest) = soonest {
+                cache.remove(&soonest);
+            }
+        }
+        cache.insert(reference.uri.clone(), cached);
+        Ok(list)
+    }
+}
+
+impl StatusConfigTrait for StatusService {
+    fn status_config(&self) -> &StatusConfig { &self.config }
+}
+
+#[async_trait]
+impl StatusTrait for StatusService {
+    async fn check(&self, vp_token: &str) -> Outcome<()> {
+        let (_, vcs) = extract_vp_credentials(vp_token)?;
+
+        for (position, vc) in vcs.iter().enumerate() {
+            let credential = vc
+                .get("id")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("#{}", position));
+
+            for reference in Self::references(vc) {
+                let issuer = vc_issuer(vc).ok_or_else(|| {
+                    let msg = format!("Credential {} has no issuer to check against", credential);
+                    Errors::format(BadFormat::Received, msg, None)
+                })?;
+                let list = self.list(&reference, issuer).await?;
+                let status = list.get(reference.index)?;
+
+                let state = match (&reference.format, status) {
+                    (_, 0) => continue,
+                    (StatusListFormat::Bitstring, _) => reference.purpose.clone(),
+                    (StatusListFormat::Token, 2) => "suspension".to_string(),
+                    (StatusListFormat::Token, _) => "revocation".to_string()
+                };
+                return Err(Errors::unauthorized(
+                    format!("Credential {} failed its {} status check", credential, state),
+                    None
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use axum::routing::get;
+    use axum::Router;
+    use flate2::write::{GzEncoder, ZlibEncoder};
+    use flate2::Compression;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use serde_json::json;
+    use ymir::services::client::ClientService;
+
+    use super::*;
+
+    struct Issuer {
+        did: String,
+        key: Ed25519KeyPair
+    }
+
+    impl Issuer {
+        fn new() -> Self {
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+            let key = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+            let jwk = json!({
+                "kty": "OKP",
+                "crv": "Ed25519",
+                "x": URL_SAFE_NO_PAD.encode(key.public_key().as_ref())
+            });
+            let did = format!("did:jwk:{}", URL_SAFE_NO_PAD.encode(jwk.to_string()));
+            Self { did, key }
+        }
+
+        fn sign(&self, claims: &Value) -> String {
+            let header = json!({ "alg": "EdDSA", "typ": "statuslist+jwt", "kid": "0" });
+            let input = format!(
+                "{}.{}",
+                URL_SAFE_NO_PAD.encode(header.to_string()),
+                URL_SAFE_NO_PAD.encode(claims.to_string())
+            );
+            let signature = self.key.sign(input.as_bytes());
+            format!("{}.{}", input, URL_SAFE_NO_PAD.encode(signature.as_ref()))
+        }
+
+        // Token Status List where index 1 is revoked
+        fn status_list(&self, uri: &str) -> String {
+            self.sign(&json!({
+                "iss": self.did,
+                "sub": uri,
+                "iat": Utc::now().timestamp(),
+                "status_list": { "bits": 1, "lst": URL_SAFE_NO_PAD.encode(zlib(&[0b10])) }
+            }))
+        }
+
+        // StatusList2021 or Bitstring Status List credential, bits read from the most significant
+        fn bitstring_list(&self, uri: &str, lst: &[u8]) -> String {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(lst).unwrap();
+            let encoded = format!("u{}", URL_SAFE_NO_PAD.encode(encoder.finish().unwrap()));
+            self.sign(&json!({
+                "iss": self.did,
+                "iat": Utc::now().timestamp(),
+                "vc": { "id": uri, "credentialSubject": { "encodedList": encoded } }
+            }))
+        }
+    }
+
+    fn zlib(data: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    // Local stand-in for a host serving status lists, the base uri is known before the bodies
+    struct Host {
+        listener: tokio::net::TcpListener,
+        base: String
+    }
+
+    impl Host {
+        async fn bind() -> Self {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let base = format!("http://{}", listener.local_addr().unwrap());
+            Self { listener, base }
+        }
+
+        fn uri(&self, path: &str) -> String { format!("{}{}", self.base, path) }
+
+        fn serve(self, lists: Vec<(&'static str, String)>) {
+            let mut router = Router::new();
+            for (path, body) in lists {
+                router = router.route(path, get(move || async move { body }));
+            }
+            tokio::spawn(async move { axum::serve(self.listener, router).await.unwrap() });
+        }
+    }
+
+    fn vp_token(issuer: &str, uri: &str, idx: usize) -> String {
+        presentation(json!({
+            "id": "urn:uuid:credential",
+            "issuer": issuer,
+            "status": { "status_list": { "idx": idx, "uri": uri } }
+        }))
+    }
+
+    fn bitstring_vp_token(issuer: &str, uri: &str, idx: usize, kind: &str) -> String {
+        presentation(json!({
+            "id": "urn:uuid:credential",
+            "issuer": issuer,
+            "credentialStatus": {
+                "type": kind,
+                "statusPurpose": "revocation",
+                "statusListIndex": idx.to_string(),
+                "statusListCredential": uri
+            }
+        }))
+    }
+
+    fn presentation(vc: Value) -> String {
+        let claims = json!({
+            "iss": "did:example:holder",
+            "vp": { "holder": "did:example:holder", "verifiableCredential": [vc] }
+        });
+        format!(
+            "{}.{}.",
+            URL_SAFE_NO_PAD.encode(json!({ "alg": "none" }).to_string()),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
+    fn service() -> StatusService {
+        let config =
+            StatusConfig { allowed_hosts: vec!["127.0.0.1".to_string()], ..Default::default() };
+        StatusService::new(config, Arc::new(ClientService::default()))
+    }
+
+    #[tokio::test]
+    async fn accepts_a_valid_entry() {
+        let issuer = Issuer::new();
+        let host = Host::bind().await;
+        let uri = host.uri("/lists/1");
+        host.serve(vec![("/lists/1", issuer.status_list(&uri))]);
+
+        assert!(service().check(&vp_token(&issuer.did, &uri, 0)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_a_revoked_entry() {
+        let issuer = Issuer::new();
+        let host = Host::bind().await;
+        let uri = host.uri("/lists/1");
+        host.serve(vec![("/lists/1", issuer.status_list(&uri))]);
+
+        assert!(service().check(&vp_token(&issuer.did, &uri, 1)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn rejects_a_list_signed_by_another_issuer() {
+        let (issuer, other) = (Issuer::new(), Issuer::new());
+        let host = Host::bind().await;
+        let uri = host.uri("/lists/1");
+        host.serve(vec![("/lists/1", other.status_list(&uri))]);
+
+        assert!(service().check(&vp_token(&issuer.did, &uri, 0)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn rejects_a_list_issued_for_another_uri() {
+        let issuer = Issuer::new();
+        let host = Host::bind().await;
+        let (uri, other) = (host.uri("/lists/1"), host.uri("/lists/2"));
+        host.serve(vec![("/lists/1", issuer.status_list(&other))]);
+
+        assert!(service().check(&vp_token(&issuer.did, &uri, 0)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn rejects_an_unsigned_list() {
+        let issuer = Issuer::new();
+        let host = Host::bind().await;
+        let uri = host.uri("/lists/1");
+        let unsigned = json!({
+            "iss": issuer.did,
+            "sub": uri,
+            "status_list": { "bits": 1, "lst": URL_SAFE_NO_PAD.encode(zlib(&[0])) }
+        });
+        host.serve(vec![("/lists/1", unsigned.to_string())]);
+
+        assert!(service().check(&vp_token(&issuer.did, &uri, 0)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn reads_bitstring_entries_most_significant_bit_first() {
+        for kind in ["StatusList2021Entry", "BitstringStatusListEntry"] {
+            let issuer = Issuer::new();
+            let host = Host::bind().await;
+            let uri = host.uri("/lists/1");
+            host.serve(vec![("/lists/1", issuer.bitstring_list(&uri, &[0b0100_0000]))]);
+
+            let service = service();
+            assert!(service.check(&bitstring_vp_token(&issuer.did, &uri, 1, kind)).await.is_err());
+            assert!(service.check(&bitstring_vp_token(&issuer.did, &uri, 0, kind)).await.is_ok());
+            assert!(service.check(&bitstring_vp_token(&issuer.did, &uri, 6, kind)).await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn refuses_hosts_that_are_not_allowed() {
+        let issuer = Issuer::new();
+        let host = Host::bind().await;
+        let uri = host.uri("/lists/1");
+        host.serve(vec![("/lists/1", issuer.status_list(&uri))]);
+        let client = Arc::new(ClientService::default());
+
+        // Loopback is never fetched without being allowed
+        let open = StatusService::new(StatusConfig::default(), client.clone());
+        assert!(open.check(&vp_token(&issuer.did, &uri, 0)).await.is_err());
+
+        assert!(open.check_host("https://status.example.org/lists/1", "status list").is_ok());
+        assert!(open.check_host("http://status.example.org/lists/1", "status list").is_err());
+
+        let config = StatusConfig {
+            allowed_hosts: vec!["status.example.org".to_string()],
+            ..Default::default()
+        };
+        let pinned = StatusService::new(config, client);
+        assert!(pinned.check(&vp_token(&issuer.did, &uri, 0)).await.is_err());
+        assert!(pinned.check_host("https://status.example.org/lists/1", "status list").is_ok());
+        assert!(pinned.check_host("https://169.254.169.254/latest", "status list").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_indexes() {
+        let list = StatusList { format: StatusListFormat::Token, bits: 8, lst: vec![0; 4] };
+        assert!(list.get(3).is_ok());
+        assert!(list.get(4).is_err());
+        assert!(list.get(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn refuses_to_inflate_oversized_lists() {
+        let bomb = zlib(&vec![0; MAX_LIST_BYTES as usize + 1]);
+        assert!(StatusService::inflate(&bomb, &StatusListFormat::Token).is_err());
+
+        let list = zlib(&[0; 16]);
+        assert_eq!(StatusService::inflate(&list, &StatusListFormat::Token).unwrap(), vec![0; 16]);
+    }
+}
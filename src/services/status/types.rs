@@ -0,0 +1,67 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{DateTime, Utc};
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatusListFormat {
+    // StatusList2021 and Bitstring Status List credentials
+    Bitstring,
+    // IETF Token Status List, used by SD-JWT VCs
+    Token
+}
+
+#[derive(Clone, Debug)]
+pub struct StatusReference {
+    pub uri: String,
+    pub index: usize,
+    pub purpose: String,
+    pub format: StatusListFormat
+}
+
+#[derive(Clone, Debug)]
+pub struct StatusList {
+    pub format: StatusListFormat,
+    pub bits: usize,
+    pub lst: Vec<u8>
+}
+
+impl StatusList {
+    pub fn get(&self, index: usize) -> Outcome<u8> {
+        let out_of_range = || {
+            let msg = format!("Status index {} is out of range", index);
+            Errors::format(BadFormat::Received, msg, None)
+        };
+        // The index comes from the credential, so it may be anything
+        let position = index.checked_mul(self.bits).ok_or_else(out_of_range)?;
+        let byte = *self.lst.get(position / 8).ok_or_else(out_of_range)?;
+        let mask = ((1u16 << self.bits) - 1) as u8;
+        match self.format {
+            StatusListFormat::Bitstring => Ok((byte >> (7 - position % 8)) & 1),
+            StatusListFormat::Token => Ok((byte >> (position % 8)) & mask)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CachedStatusList {
+    pub list: StatusList,
+    // Issuer the list was verified against
+    pub issuer: String,
+    pub expires_at: DateTime<Utc>
+}
@@ -0,0 +1,186 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{Duration, Utc};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tracing::info;
+use ymir::config::traits::HostsConfigTrait;
+use ymir::config::types::HostType;
+use ymir::errors::{BadFormat, Errors, Outcome};
+use ymir::utils::create_opaque_token;
+
+use super::types::{IntrospectionRequest, IntrospectionResponse, ResourceServerRequest};
+use super::{TokenServiceConfig, TokenTrait};
+use crate::config::tokens::TokenConfigTrait;
+use crate::data::entities::{gnap_grant, gnap_token, resource_server};
+use crate::services::gatekeeper::types::AccessTokenResponse;
+
+pub struct TokenService {
+    config: TokenServiceConfig
+}
+
+impl TokenService {
+    pub fn new(config: TokenServiceConfig) -> Self { Self { config } }
+
+    fn issuer(&self) -> String {
+        format!(
+            "{}{}/gate",
+            self.config.hosts().get_host(HostType::Http),
+            self.config.get_api_path()
+        )
+    }
+
+    fn access_types(access: &Value) -> Vec<String> {
+        access
+            .as_array()
+            .map(|rights| {
+                rights
+                    .iter()
+                    .filter_map(|right| match right {
+                        Value::String(reference) => Some(reference.clone()),
+                        right => right.get("type").and_then(Value::as_str).map(String::from)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn parse<T: DeserializeOwned>(value: &Value) -> Outcome<T> {
+        serde_json::from_value(value.clone())
+            .map_err(|e| Errors::parse("Unable to parse stored token data", Some(Box::new(e))))
+    }
+}
+
+impl TokenTrait for TokenService {
+    fn grant_access(&self, access: Value) -> Value {
+        match access {
+            Value::Array(rights) => Value::Array(rights),
+            right => Value::Array(vec![right])
+        }
+    }
+
+    fn continue_wait(&self) -> u64 { self.config.get_continue_wait() }
+
+    fn issue(&self, grant: &gnap_grant::Model) -> Outcome<(gnap_token::NewModel, String)> {
+        info!("Issuing access token for grant {}", grant.id);
+
+        let expires_at = Utc::now() + Duration::seconds(self.config.get_token_ttl());
+        // Tokens of proofed grants are bound to the client key (RFC 9635 §3.2.1)
+        let flags = match grant.key {
+            Some(_) => Vec::new(),
+            None => vec![Value::String("bearer".to_string())]
+        };
+        let value = create_opaque_token();
+        let model = gnap_token::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            grant_id: grant.id.clone(),
+            value_hash: self.hash_secret(&value),
+            access: grant.access.clone(),
+            flags: Value::Array(flags),
+            expires_at: expires_at.naive_utc()
+        };
+        Ok((model, value))
+    }
+
+    fn token_response(
+        &self,
+        model: &gnap_token::Model,
+        value: &str
+    ) -> Outcome<AccessTokenResponse> {
+        let expires_in = (model.expires_at - Utc::now().naive_utc()).num_seconds().max(0);
+        Ok(AccessTokenResponse {
+            value: value.to_string(),
+            access: Self::parse(&model.access)?,
+            expires_in: Some(expires_in),
+            flags: Self::parse(&model.flags)?,
+            ..Default::default()
+        })
+    }
+
+    fn authorize_registration(&self, key: &str) -> Outcome<()> {
+        match self.config.is_registration_key_valid(key) {
+            true => Ok(()),
+            false => Err(Errors::unauthorized("Invalid registration key", None))
+        }
+    }
+
+    fn register(
+        &self,
+        payload: &ResourceServerRequest
+    ) -> Outcome<(resource_server::NewModel, String)> {
+        info!("Registering resource server {}", payload.name);
+
+        if payload.access_types.is_empty() {
+            return Err(Errors::format(
+                BadFormat::Received,
+                "A resource server must protect at least one access type",
+                None
+            ));
+        }
+        let secret = create_opaque_token();
+        let model = resource_server::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: payload.name.clone(),
+            secret_hash: self.hash_secret(&secret),
+            access_types: Value::from(payload.access_types.clone())
+        };
+        Ok((model, secret))
+    }
+
+    fn hash_secret(&self, secret: &str) -> String {
+        Sha256::digest(secret.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    fn introspect(
+        &self,
+        token: Option<&gnap_token::Model>,
+        resource_server: &resource_server::Model,
+        payload: &IntrospectionRequest
+    ) -> Outcome<IntrospectionResponse> {
+        let Some(token) = token else {
+            return Ok(IntrospectionResponse::inactive());
+        };
+        if token.revoked_at.is_some() || token.expires_at < Utc::now().naive_utc() {
+            return Ok(IntrospectionResponse::inactive());
+        }
+
+        let token_types = Self::access_types(&token.access);
+        let rs_types: Vec<String> = Self::parse(&resource_server.access_types)?;
+        if !token_types.iter().any(|t| rs_types.contains(t)) {
+            info!("Token is not meant for resource server {}", resource_server.name);
+            return Ok(IntrospectionResponse::inactive());
+        }
+        if let Some(access) = &payload.access {
+            let requested = Self::access_types(&Value::from(access.clone()));
+            if !requested.iter().all(|t| token_types.contains(t)) {
+                return Ok(IntrospectionResponse::inactive());
+            }
+        }
+
+        Ok(IntrospectionResponse {
+            active: true,
+            access: Some(Self::parse(&token.access)?),
+            flags: Some(Self::parse(&token.flags)?),
+            exp: Some(token.expires_at.and_utc().timestamp()),
+            iat: Some(token.created_at.and_utc().timestamp()),
+            iss: Some(self.issuer()),
+            key: None
+        })
+    }
+}
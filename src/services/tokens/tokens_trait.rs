@@ -0,0 +1,47 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use serde_json::Value;
+use ymir::errors::Outcome;
+
+use super::types::{IntrospectionRequest, IntrospectionResponse, ResourceServerRequest};
+use crate::data::entities::{gnap_grant, gnap_token, resource_server};
+use crate::services::gatekeeper::types::AccessTokenResponse;
+
+pub trait TokenTrait: Send + Sync + 'static {
+    fn grant_access(&self, access: Value) -> Value;
+    fn continue_wait(&self) -> u64;
+    // Only the hash of the token is stored, the value is returned next to it
+    fn issue(&self, grant: &gnap_grant::Model) -> Outcome<(gnap_token::NewModel, String)>;
+    fn token_response(
+        &self,
+        model: &gnap_token::Model,
+        value: &str
+    ) -> Outcome<AccessTokenResponse>;
+    fn authorize_registration(&self, key: &str) -> Outcome<()>;
+    fn register(
+        &self,
+        payload: &ResourceServerRequest
+    ) -> Outcome<(resource_server::NewModel, String)>;
+    fn hash_secret(&self, secret: &str) -> String;
+    fn introspect(
+        &self,
+        token: Option<&gnap_token::Model>,
+        resource_server: &resource_server::Model,
+        payload: &IntrospectionRequest
+    ) -> Outcome<IntrospectionResponse>;
+}
@@ -0,0 +1,132 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::{Duration, Utc};
+use serde_json::Value;
+use tracing::info;
+use ymir::errors::Outcome;
+
+use super::types::{RuleResult, TrustEvaluation, TrustRule};
+use super::TrustTrait;
+use crate::config::trust::{TrustConfig, TrustConfigTrait, TrustPolicy};
+use crate::utils::{extract_vp_credentials, vc_has_type, vc_issued_at, vc_issuer, vc_subject_ids};
+
+pub struct TrustService {
+    config: TrustConfig
+}
+
+impl TrustService {
+    pub fn new(config: TrustConfig) -> Self { Self { config } }
+
+    fn check_issuer(vc: &Value, policy: &TrustPolicy) -> (bool, String) {
+        match vc_issuer(vc) {
+            Some(issuer) if policy.trusted_issuers.iter().any(|i| i == issuer) => {
+                (true, format!("Issuer '{}' is trusted", issuer))
+            }
+            Some(issuer) => (false, format!("Issuer '{}' is not in the allowlist", issuer)),
+            None => (false, "Credential has no issuer".to_string())
+        }
+    }
+
+    fn check_age(vc: &Value, max_age: i64) -> (bool, String) {
+        match vc_issued_at(vc) {
+            Some(issued) => {
+                let age = Utc::now() - issued;
+                match age <= Duration::seconds(max_age) {
+                    true => (true, format!("Issued {}s ago", age.num_seconds())),
+                    false => (
+                        false,
+                        format!("Issued {}s ago, maximum is {}s", age.num_seconds(), max_age)
+                    )
+                }
+            }
+            None => (false, "Credential has no issuance date".to_string())
+        }
+    }
+
+    fn check_holder(vc: &Value, holder: &str) -> (bool, String) {
+        match vc_subject_ids(vc).contains(&holder) {
+            true => (true, format!("Subject is bound to holder '{}'", holder)),
+            false => (false, format!("Subject is not bound to holder '{}'", holder))
+        }
+    }
+}
+
+impl TrustConfigTrait for TrustService {
+    fn trust_config(&self) -> &TrustConfig { &self.config }
+}
+
+impl TrustTrait for TrustService {
+    fn evaluate(&self, vp_token: &str) -> Outcome<TrustEvaluation> {
+        let (holder, vcs) = extract_vp_credentials(vp_token)?;
+        let mut results = Vec::new();
+
+        for (index, vc) in vcs.iter().enumerate() {
+            let credential = vc
+                .get("id")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("#{}", index));
+
+            let policies: Vec<_> = self
+                .get_trust_policies()
+                .iter()
+                .filter(|p| p.applies_to_all() || vc_has_type(vc, &p.vc_type))
+                .collect();
+
+            if policies.is_empty() {
+                let passed = self.allows_unmatched();
+                let detail = match passed {
+                    true => "No trust policy applies, unmatched credentials are allowed",
+                    false => "No trust policy applies to this credential"
+                };
+                results.push(RuleResult {
+                    rule: TrustRule::Unmatched,
+                    vc_type: "*".to_string(),
+                    credential: credential.clone(),
+                    passed,
+                    detail: detail.to_string()
+                });
+            }
+
+            for policy in policies {
+                let mut checks = Vec::new();
+                if !policy.trusted_issuers.is_empty() {
+                    checks.push((TrustRule::TrustedIssuer, Self::check_issuer(vc, policy)));
+                }
+                if let Some(max_age) = policy.max_age {
+                    checks.push((TrustRule::MaxAge, Self::check_age(vc, max_age)));
+                }
+                if policy.require_holder_binding {
+                    checks.push((TrustRule::HolderBinding, Self::check_holder(vc, &holder)));
+                }
+
+                results.extend(checks.into_iter().map(|(rule, (passed, detail))| RuleResult {
+                    rule,
+                    vc_type: policy.vc_type.clone(),
+                    credential: credential.clone(),
+                    passed,
+                    detail
+                }));
+            }
+        }
+
+        let passed = results.iter().all(|r| r.passed);
+        info!("Trust policy evaluated: {} rules, passed: {}", results.len(), passed);
+        Ok(TrustEvaluation { passed, results })
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum TrustRule {
+    TrustedIssuer,
+    MaxAge,
+    HolderBinding,
+    Unmatched
+}
+
+impl Display for TrustRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TrustRule::TrustedIssuer => "TrustedIssuer",
+            TrustRule::MaxAge => "MaxAge",
+            TrustRule::HolderBinding => "HolderBinding",
+            TrustRule::Unmatched => "Unmatched"
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RuleResult {
+    pub rule: TrustRule,
+    pub vc_type: String,
+    pub credential: String,
+    pub passed: bool,
+    pub detail: String
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TrustEvaluation {
+    pub passed: bool,
+    pub results: Vec<RuleResult>
+}
+
+impl TrustEvaluation {
+    pub fn failures(&self) -> Vec<&RuleResult> {
+        self.results.iter().filter(|r| !r.passed).collect()
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use ymir::config::traits::VcConfigTrait;
+use ymir::config::types::VcConfig;
+
+use crate::config::gaia::{GaiaConfig, GaiaConfigTrait};
+use crate::config::role::{AuthorityRole, RoleConfigTrait};
+use crate::config::CoreApplicationConfig;
+use crate::services::vcs_builder::BuilderConfigDefaultTrait;
+
+pub struct GaiaAuthorityConfig {
+    vc_config: VcConfig,
+    gaia_config: GaiaConfig,
+    role: AuthorityRole
+}
+
+impl VcConfigTrait for GaiaAuthorityConfig {
+    fn vc_config(&self) -> &VcConfig { &self.vc_config }
+}
+
+impl RoleConfigTrait for GaiaAuthorityConfig {
+    fn get_role(&self) -> &AuthorityRole { &self.role }
+}
+
+impl GaiaConfigTrait for GaiaAuthorityConfig {
+    fn gaia_config(&self) -> &GaiaConfig { &self.gaia_config }
+}
+
+impl BuilderConfigDefaultTrait for GaiaAuthorityConfig {}
+
+impl From<CoreApplicationConfig> for GaiaAuthorityConfig {
+    fn from(value: CoreApplicationConfig) -> Self {
+        Self {
+            vc_config: value.vc_config().clone(),
+            gaia_config: value.gaia_config().clone(),
+            role: value.get_role().clone()
+        }
+    }
+}
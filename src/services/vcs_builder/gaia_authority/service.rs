@@ -0,0 +1,100 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+use tracing::info;
+use ymir::data::entities::{issuing, vc_request};
+use ymir::errors::{Errors, Outcome};
+use ymir::utils::{get_from_opt, parse_from_str};
+
+use super::super::VcBuilderTrait;
+use super::config::GaiaAuthorityConfig;
+use crate::config::gaia::GaiaConfigTrait;
+use crate::config::role::{AuthorityRole, RoleConfigTrait};
+use crate::services::gaia::types::{GaiaParticipantData, GaiaVcType};
+
+pub struct GaiaAuthorityVcBuilder {
+    config: GaiaAuthorityConfig
+}
+
+impl GaiaAuthorityVcBuilder {
+    pub fn new(config: GaiaAuthorityConfig) -> Self { Self { config } }
+}
+
+impl RoleConfigTrait for GaiaAuthorityVcBuilder {
+    fn get_role(&self) -> &AuthorityRole { &self.config.get_role() }
+}
+
+impl VcBuilderTrait for GaiaAuthorityVcBuilder {
+    fn build_vc(&self, model: &issuing::Model) -> Outcome<Value> {
+        let vc_type = GaiaVcType::from_str(&model.vc_type)?;
+        info!("Building {} credential", vc_type);
+
+        let data: GaiaParticipantData =
+            parse_from_str(&get_from_opt(model.credential_data.as_ref(), "credential data")?)?;
+
+        let credential_subject = match vc_type {
+            GaiaVcType::LegalParticipant => json!({
+                "id": vc_type.subject_id(&data.holder_did),
+                "type": vc_type.name(),
+                "gx:legalName": data.legal_name,
+                "gx:legalRegistrationNumber": {
+                    "id": GaiaVcType::LegalRegistrationNumber.subject_id(&data.holder_did)
+                },
+                "gx:headquarterAddress": data.headquarter_address.to_gx(),
+                "gx:legalAddress": data.legal_address.to_gx()
+            }),
+            GaiaVcType::TermsAndConditions => json!({
+                "id": vc_type.subject_id(&data.holder_did),
+                "type": vc_type.name(),
+                "gx:termsAndConditions": self.config.get_terms_and_conditions()
+            }),
+            GaiaVcType::LegalRegistrationNumber => {
+                let mut subject = json!({
+                    "id": vc_type.subject_id(&data.holder_did),
+                    "type": vc_type.name()
+                });
+                if let (Some(obj), Value::Object(fields)) =
+                    (subject.as_object_mut(), data.registration_number)
+                {
+                    obj.extend(fields);
+                }
+                subject
+            }
+        };
+
+        self.build_with(
+            model,
+            credential_subject,
+            &self.config,
+            vc_type.name(),
+            self.config.get_gaia_contexts()
+        )
+    }
+
+    fn gather_data(&self, req_model: &vc_request::Model) -> Outcome<String> {
+        Err(Errors::not_impl(
+            format!(
+                "{} credentials are only issued through the Gaia-X onboarding flow",
+                req_model.vc_type
+            ),
+            None
+        ))
+    }
+}
@@ -20,6 +20,7 @@ pub mod clearing_house;
 pub mod clearing_house_proxy;
 pub mod dataspace_authority;
 mod eco_authority;
+pub mod gaia_authority;
 pub mod legal_authority;
 mod vcs_builder_trait;
 
@@ -38,6 +38,17 @@ pub trait VcBuilderTrait: RoleConfigTrait + Send + Sync + 'static {
         model: &issuing::Model,
         credential_subject: Value,
         config: &dyn BuilderConfigDefaultTrait
+    ) -> Outcome<Value> {
+        let vc_type = VcType::from_str(&model.vc_type)?;
+        self.build_with(model, credential_subject, config, vc_type.name(), vec![])
+    }
+    fn build_with(
+        &self,
+        model: &issuing::Model,
+        credential_subject: Value,
+        config: &dyn BuilderConfigDefaultTrait,
+        type_name: String,
+        contexts: Vec<String>
     ) -> Outcome<Value> {
         let subject_id =
             credential_subject.get("id").and_then(|v| v.as_str()).ok_or_else(|| {
@@ -49,7 +60,6 @@ pub trait VcBuilderTrait: RoleConfigTrait + Send + Sync + 'static {
             })?;
 
         let now = Utc::now();
-        let vc_type = VcType::from_str(&model.vc_type)?;
         let issuer_did = get_from_opt(model.issuer_did.as_ref(), "issuer did")?;
         match config.get_vc_model() {
             VcModel::JwtVc => {
@@ -65,8 +75,12 @@ pub trait VcBuilderTrait: RoleConfigTrait + Send + Sync + 'static {
                         iss: Some(issuer_did.clone()),
                         sub: Some(subject_id.to_string()),
                         vc: VCFromClaimsV1 {
-                            context: vec!["https://www.w3.org/ns/credentials/v1".to_string()],
-                            r#type: vec!["VerifiableCredential".to_string(), vc_type.name()],
+                            context: [
+                                vec!["https://www.w3.org/ns/credentials/v1".to_string()],
+                                contexts
+                            ]
+                            .concat(),
+                            r#type: vec!["VerifiableCredential".to_string(), type_name],
                             id: model.credential_id.clone(),
                             credential_subject,
                             issuer: VCIssuer {
@@ -83,8 +97,12 @@ pub trait VcBuilderTrait: RoleConfigTrait + Send + Sync + 'static {
                         jti: Some(model.credential_id.clone()),
                         iss: Some(issuer_did.clone()),
                         sub: Some(subject_id.to_string()),
-                        context: vec!["https://www.w3.org/ns/credentials/v2".to_string()],
-                        r#type: vec!["VerifiableCredential".to_string(), vc_type.name()],
+                        context: [
+                            vec!["https://www.w3.org/ns/credentials/v2".to_string()],
+                            contexts
+                        ]
+                        .concat(),
+                        r#type: vec!["VerifiableCredential".to_string(), type_name],
                         id: model.credential_id.clone(),
                         credential_subject,
                         issuer: VCIssuer {
@@ -0,0 +1,137 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::Utc;
+use ring::hmac;
+use serde_json::Value;
+use tracing::info;
+use ymir::errors::{BadFormat, Errors, Outcome};
+use ymir::utils::parse_to_value;
+
+use super::types::{SessionRequest, SessionResult};
+use super::VerificationTrait;
+use crate::config::verification::{VerificationConfig, VerificationConfigTrait};
+use crate::data::entities::{callback_outbox, verification_session};
+use crate::utils::extract_vp_credentials;
+
+pub struct VerificationService {
+    config: VerificationConfig
+}
+
+impl VerificationService {
+    pub fn new(config: VerificationConfig) -> Self { Self { config } }
+
+    // `sha256=<hex>` over the exact bytes posted, so receivers can check the raw body
+    fn signature(secret: &str, body: &Value) -> Outcome<String> {
+        let bytes = serde_json::to_vec(body).map_err(|e| {
+            Errors::parse("Unable to serialize webhook body", Some(Box::new(e)))
+        })?;
+        let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+        let tag = hmac::sign(&key, &bytes);
+        let hex: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(format!("sha256={}", hex))
+    }
+}
+
+impl VerificationConfigTrait for VerificationService {
+    fn verification_config(&self) -> &VerificationConfig { &self.config }
+}
+
+impl VerificationTrait for VerificationService {
+    fn authorize(&self, token: &str) -> Outcome<()> {
+        match self.is_api_key_valid(token) {
+            true => Ok(()),
+            false => Err(Errors::unauthorized("Invalid verification API key", None))
+        }
+    }
+
+    fn start(&self, payload: &SessionRequest) -> Outcome<verification_session::NewModel> {
+        info!("Starting standalone verification session");
+
+        if payload.vc_type.trim().is_empty() {
+            return Err(Errors::format(BadFormat::Received, "vc_type cannot be empty", None));
+        }
+        if let Some(webhook) = &payload.webhook {
+            if self.config.webhook_secret.is_none() {
+                let msg = "Webhooks need verification_config.webhook_secret to be set";
+                return Err(Errors::format(BadFormat::Received, msg, None));
+            }
+            if !webhook.starts_with("http://") && !webhook.starts_with("https://") {
+                return Err(Errors::format(
+                    BadFormat::Received,
+                    format!("Webhook '{}' is not an http(s) url", webhook),
+                    None
+                ));
+            }
+        }
+
+        Ok(verification_session::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            vc_type: payload.vc_type.clone(),
+            webhook: payload.webhook.clone()
+        })
+    }
+
+    fn complete(&self, model: &mut verification_session::Model, vp_token: &str) -> Outcome<()> {
+        let (holder, credentials) = extract_vp_credentials(vp_token)?;
+        model.holder = Some(holder);
+        model.claims = Some(parse_to_value(&credentials)?);
+        model.status = "Verified".to_string();
+        model.error = None;
+        model.ended_at = Some(Utc::now().naive_utc());
+        Ok(())
+    }
+
+    fn reject(&self, model: &mut verification_session::Model, error: &Errors) {
+        model.error = Some(error.to_string());
+    }
+
+    fn expire(&self, model: &mut verification_session::Model) {
+        model.status = "Expired".to_string();
+        model.error = Some("Session expired before a presentation was received".to_string());
+        model.ended_at = Some(Utc::now().naive_utc());
+    }
+
+    fn webhook(
+        &self,
+        model: &verification_session::Model
+    ) -> Outcome<Option<callback_outbox::NewModel>> {
+        let Some(url) = &model.webhook else {
+            return Ok(None);
+        };
+
+        let body = SessionResult {
+            id: model.id.clone(),
+            status: model.status.clone(),
+            holder: model.holder.clone(),
+            claims: model.claims.clone(),
+            error: model.error.clone()
+        };
+        let body = parse_to_value(&body)?;
+        let signature = match &self.config.webhook_secret {
+            Some(secret) => Some(Self::signature(secret, &body)?),
+            None => None
+        };
+        Ok(Some(callback_outbox::NewModel {
+            id: uuid::Uuid::new_v4().to_string(),
+            request_id: model.id.clone(),
+            uri: url.clone(),
+            body,
+            signature
+        }))
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use ymir::errors::{Errors, Outcome};
+
+use super::types::SessionRequest;
+use crate::data::entities::{callback_outbox, verification_session};
+
+pub trait VerificationTrait: Send + Sync + 'static {
+    fn authorize(&self, token: &str) -> Outcome<()>;
+    fn start(&self, payload: &SessionRequest) -> Outcome<verification_session::NewModel>;
+    fn complete(&self, model: &mut verification_session::Model, vp_token: &str) -> Outcome<()>;
+    // Records the error, the session stays open for another presentation until it expires
+    fn reject(&self, model: &mut verification_session::Model, error: &Errors);
+    fn expire(&self, model: &mut verification_session::Model);
+    // Webhook with the session result, queued in the callback outbox
+    fn webhook(
+        &self,
+        model: &verification_session::Model
+    ) -> Outcome<Option<callback_outbox::NewModel>>;
+}
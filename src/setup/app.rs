@@ -17,6 +17,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::{serve, Router};
 use axum_server::tls_rustls::RustlsConfig;
@@ -30,6 +31,7 @@ use ymir::types::secrets::StringHelper;
 use ymir::utils::expect_from_env;
 
 use crate::config::CoreApplicationConfig;
+use crate::core::traits::{CoreExpiryTrait, CoreOutboxTrait, CoreVerifierTrait};
 use crate::core::CoreBuilder;
 use crate::http::RainbowAuthorityRouter;
 
@@ -37,9 +39,37 @@ pub struct AuthorityApp;
 
 impl AuthorityApp {
     pub async fn create_router(config: &CoreApplicationConfig, vault: Arc<VaultService>) -> Router {
-        let core = CoreBuilder::from_config(config.clone(), vault).await.build();
+        let core = Arc::new(CoreBuilder::from_config(config.clone(), vault).await.build());
 
-        RainbowAuthorityRouter::new(Arc::new(core)).router()
+        Self::spawn_cleanup(core.clone());
+        Self::spawn_outbox(core.clone());
+        RainbowAuthorityRouter::new(core).router()
+    }
+
+    fn spawn_cleanup(core: Arc<dyn CoreExpiryTrait>) {
+        let period = Duration::from_secs(core.expiry().cleanup_interval().max(1));
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                if let Err(e) = core.expire_sessions().await {
+                    e.log();
+                }
+            }
+        });
+    }
+
+    fn spawn_outbox(core: Arc<dyn CoreOutboxTrait>) {
+        let period = Duration::from_secs(core.outbox().interval().max(1));
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                if let Err(e) = core.deliver_callbacks().await {
+                    e.log();
+                }
+            }
+        });
     }
 
     pub async fn run_basic(config: CoreApplicationConfig, vault: Arc<VaultService>) -> Outcome<()> {
@@ -56,7 +86,7 @@ impl AuthorityApp {
                 .await
                 .map_err(|e| Errors::crazy("Error with tcp listener", Some(Box::new(e))))?;
 
-        serve(listener, router)
+        serve(listener, router.into_make_service_with_connect_info::<SocketAddr>())
             .await
             .map_err(|e| Errors::crazy("Error while running basic server", Some(Box::new(e))))
     }
@@ -87,7 +117,7 @@ impl AuthorityApp {
         info!("Starting Authority server with TLS in {}", addr);
 
         axum_server::bind_rustls(addr, tls_config)
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .await
             .map_err(|e| Errors::crazy("Error while running basic server", Some(Box::new(e))))?;
         Ok(())
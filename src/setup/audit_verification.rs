@@ -0,0 +1,73 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use ymir::errors::{Errors, Outcome};
+use ymir::services::vault::{VaultService, VaultTrait};
+use ymir::utils::read;
+
+use crate::config::audit::AuditConfigTrait;
+use crate::config::CoreApplicationConfig;
+use crate::data::entities::audit_entry;
+use crate::services::audit::types::AuditReport;
+use crate::services::audit::{AuditService, AuditTrait};
+use crate::services::repo::postgres::AuditEntryRepo;
+use crate::services::repo::subtraits::AuditEntryTrait;
+
+const PAGE: u64 = 500;
+
+// Checks the audit chain without starting the authority
+pub struct AuditVerification;
+
+impl AuditVerification {
+    pub async fn run(
+        config: &CoreApplicationConfig,
+        vault: Arc<VaultService>,
+        file: Option<String>
+    ) -> Outcome<AuditReport> {
+        let audit = AuditService::new(config.audit_config().clone(), vault.clone());
+        let key = audit.verifying_key().await?;
+        let mut report = AuditReport::default();
+
+        if let Some(file) = file {
+            let data = read(PathBuf::from(file))?;
+            let entries: Vec<audit_entry::Model> = serde_json::from_str(&data)
+                .map_err(|e| Errors::parse("Invalid audit export", Some(Box::new(e))))?;
+            for entry in &entries {
+                if !audit.verify_entry(&mut report, entry, &key) {
+                    break;
+                }
+            }
+            return Ok(report);
+        }
+
+        let repo = AuditEntryRepo::new(vault.get_db_connection(config).await);
+        loop {
+            let entries = repo.get_after(report.last_seq.unwrap_or(0), PAGE).await?;
+            if entries.is_empty() {
+                return Ok(report);
+            }
+            for entry in &entries {
+                if !audit.verify_entry(&mut report, entry, &key) {
+                    return Ok(report);
+                }
+            }
+        }
+    }
+}
@@ -25,11 +25,14 @@ use ymir::errors::{Errors, Outcome};
 use ymir::services::vault::fake_vault::FakeVaultService;
 use ymir::services::vault::vault_rs::RealVaultService;
 use ymir::services::vault::{VaultService, VaultTrait};
+use ymir::utils::parse_to_value;
 
 use super::env_extraction::extract_env_config;
 use crate::config::CoreApplicationConfig;
 use crate::setup::app::AuthorityApp;
+use crate::setup::audit_verification::AuditVerification;
 use crate::setup::db_migrations::AuthorityMigration;
+use crate::setup::telemetry::Telemetry;
 
 #[derive(Parser, Debug)]
 #[command(name = "Rainbow Dataspace Authority Server")]
@@ -45,26 +48,37 @@ pub struct AuthCliArgs {
     env_file: String
 }
 
+#[derive(Parser, Debug, PartialEq)]
+pub struct AuditCliArgs {
+    #[arg(short, long)]
+    env_file: String,
+    // Entries exported from `/api/v1/audit`, the database is checked when unset
+    #[arg(short, long)]
+    file: Option<String>
+}
+
 #[derive(Subcommand, Debug, PartialEq)]
 pub enum AuthorityCliCommands {
     Start(AuthCliArgs),
-    Setup(AuthCliArgs)
+    Setup(AuthCliArgs),
+    VerifyAudit(AuditCliArgs)
 }
 
 pub struct AuthorityCommands;
 
 impl AuthorityCommands {
-    pub async fn init_command_line() -> Outcome<()> {
+    pub async fn init_command_line(telemetry: &Telemetry) -> Outcome<()> {
         debug!("Init the command line application");
         let cli = AuthorityCli::parse();
 
         match cli.command {
             AuthorityCliCommands::Start(args) => {
-                let (config, vault) = Self::bootstrap(args)?;
+                let (config, vault) = Self::bootstrap(args.env_file)?;
+                telemetry.enable(&config)?;
                 AuthorityApp::run(config, Arc::new(vault)).await?
             }
             AuthorityCliCommands::Setup(args) => {
-                let (config, vault) = Self::bootstrap(args)?;
+                let (config, vault) = Self::bootstrap(args.env_file)?;
                 match config.is_tls_enabled() {
                     true => vault.write_all_secrets(None).await?,
                     false => vault.write_local_secrets(None).await?
@@ -72,13 +86,22 @@ impl AuthorityCommands {
                 let db_connection = vault.get_db_connection(&config).await;
                 AuthorityMigration::run(&db_connection).await?;
             }
+            AuthorityCliCommands::VerifyAudit(args) => {
+                let (config, vault) = Self::bootstrap(args.env_file)?;
+                let report = AuditVerification::run(&config, Arc::new(vault), args.file).await?;
+                let table = json_to_table::json_to_table(&parse_to_value(&report)?).to_string();
+                info!("Audit chain verification:\n{}", table);
+                if !report.valid {
+                    return Err(Errors::security("Audit chain is broken", None));
+                }
+            }
         }
 
         Ok(())
     }
 
-    fn bootstrap(args: AuthCliArgs) -> Outcome<(CoreApplicationConfig, VaultService)> {
-        let config = extract_env_config(args.env_file)?;
+    fn bootstrap(env_file: String) -> Outcome<(CoreApplicationConfig, VaultService)> {
+        let config = extract_env_config(env_file)?;
         let vault = if config.is_vault_real() {
             VaultService::Real(RealVaultService::new())
         } else {
@@ -16,5 +16,7 @@
  */
 
 pub mod app;
+pub mod audit_verification;
 pub mod commands;
 pub mod db_migrations;
+pub mod telemetry;
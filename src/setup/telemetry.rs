@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::sync::{Mutex, PoisonError};
+
+use opentelemetry::global;
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracer, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use tracing::{info, warn};
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, EnvFilter, Registry};
+use ymir::errors::{Errors, Outcome};
+
+use crate::config::telemetry::TelemetryConfigTrait;
+
+type OtelLayer = Option<OpenTelemetryLayer<Registry, SdkTracer>>;
+
+pub struct Telemetry {
+    handle: reload::Handle<OtelLayer, Registry>,
+    provider: Mutex<Option<SdkTracerProvider>>
+}
+
+impl Telemetry {
+    // Logging starts before the config is read, so the exporter is plugged in afterwards
+    pub fn init(filter: EnvFilter) -> Outcome<Self> {
+        let (layer, handle) = reload::Layer::new(None);
+        tracing_subscriber::registry()
+            .with(layer)
+            .with(filter)
+            .with(fmt::layer())
+            .try_init()
+            .map_err(|e| Errors::crazy("Unable to install tracing", Some(Box::new(e))))?;
+        Ok(Self { handle, provider: Mutex::new(None) })
+    }
+
+    pub fn enable(&self, config: &dyn TelemetryConfigTrait) -> Outcome<()> {
+        if !config.is_telemetry_enabled() {
+            return Ok(());
+        }
+
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(config.get_otlp_endpoint())
+            .build()
+            .map_err(|e| Errors::crazy("Unable to build the OTLP exporter", Some(Box::new(e))))?;
+        let ratio = Sampler::TraceIdRatioBased(config.get_sample_ratio());
+        let resource =
+            Resource::builder().with_service_name(config.get_service_name().to_string()).build();
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_sampler(Sampler::ParentBased(Box::new(ratio)))
+            .with_resource(resource)
+            .build();
+
+        let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("heimdall"));
+        self.handle
+            .reload(Some(layer))
+            .map_err(|e| Errors::crazy("Unable to enable trace export", Some(Box::new(e))))?;
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        *self.provider.lock().unwrap_or_else(PoisonError::into_inner) = Some(provider);
+        info!("Exporting traces to {}", config.get_otlp_endpoint());
+        Ok(())
+    }
+
+    // Flushes the spans still waiting in the batch
+    pub fn shutdown(&self) {
+        let provider = self.provider.lock().unwrap_or_else(PoisonError::into_inner).take();
+        if let Some(provider) = provider {
+            if let Err(e) = provider.shutdown() {
+                warn!("Unable to flush pending spans: {}", e);
+            }
+        }
+    }
+}
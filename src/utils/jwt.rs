@@ -0,0 +1,217 @@
+/*
+ * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk, KeyAlgorithm};
+use jsonwebtoken::Algorithm;
+use serde_json::Value;
+use ymir::errors::{BadFormat, Errors, Outcome};
+
+// Signature is NOT checked, only use it over tokens that were already verified
+pub fn decode_jwt_claims(token: &str) -> Outcome<Value> {
+    let payload = token.split('.').nth(1).ok_or_else(|| {
+        Errors::format(BadFormat::Received, "Token is not a compact JWT", None)
+    })?;
+    let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).map_err(|e| {
+        Errors::format(BadFormat::Received, "Unable to decode JWT payload", Some(Box::new(e)))
+    })?;
+    serde_json::from_slice(&bytes)
+        .map_err(|e| Errors::parse("Unable to parse JWT payload", Some(Box::new(e))))
+}
+
+// Algorithms a key may verify, taken from the key and never from the token being checked
+pub fn jwk_algorithms(jwk: &Jwk) -> Vec<Algorithm> {
+    if let Some(alg) = &jwk.common.key_algorithm {
+        let alg = match alg {
+            KeyAlgorithm::ES256 => Algorithm::ES256,
+            KeyAlgorithm::ES384 => Algorithm::ES384,
+            KeyAlgorithm::RS256 => Algorithm::RS256,
+            KeyAlgorithm::RS384 => Algorithm::RS384,
+            KeyAlgorithm::RS512 => Algorithm::RS512,
+            KeyAlgorithm::PS256 => Algorithm::PS256,
+            KeyAlgorithm::PS384 => Algorithm::PS384,
+            KeyAlgorithm::PS512 => Algorithm::PS512,
+            KeyAlgorithm::EdDSA => Algorithm::EdDSA,
+            _ => return Vec::new()
+        };
+        return vec![alg];
+    }
+    match &jwk.algorithm {
+        AlgorithmParameters::EllipticCurve(params) => match params.curve {
+            EllipticCurve::P256 => vec![Algorithm::ES256],
+            EllipticCurve::P384 => vec![Algorithm::ES384],
+            _ => Vec::new()
+        },
+        AlgorithmParameters::OctetKeyPair(_) => vec![Algorithm::EdDSA],
+        AlgorithmParameters::RSA(_) => vec![
+            Algorithm::RS256,
+            Algorithm::RS384,
+            Algorithm::RS512,
+            Algorithm::PS256,
+            Algorithm::PS384,
+            Algorithm::PS512
+        ],
+        AlgorithmParameters::OctetKey(_) => Vec::new()
+    }
+}
+
+pub fn extract_vp_credentials(vp_token: &str) -> Outcome<(String, Vec<Value>)> {
+    let claims = decode_jwt_claims(vp_token)?;
+    let vp = claims.get("vp").unwrap_or(&claims);
+
+    let holder = vp
+        .get("holder")
+        .or_else(|| claims.get("iss"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| Errors::format(BadFormat::Received, "VP has no holder", None))?
+        .to_string();
+
+    let credentials = match vp.get("verifiableCredential") {
+        Some(Value::Array(vcs)) => vcs.clone(),
+        Some(vc) => vec![vc.clone()],
+        None => vec![]
+    };
+
+    let credentials = credentials
+        .into_iter()
+        .map(|vc| {
+            let vc = match vc {
+                Value::String(jwt) => decode_jwt_claims(&jwt)?,
+                other => other
+            };
+            Ok(normalize_jwt_vc(vc))
+        })
+        .collect::<Outcome<Vec<Value>>>()?;
+
+    Ok((holder, credentials))
+}
+
+// Format each credential is embedded in, in the order `extract_vp_credentials` returns them
+pub fn vp_credential_formats(vp_token: &str) -> Outcome<Vec<&'static str>> {
+    let claims = decode_jwt_claims(vp_token)?;
+    let vp = claims.get("vp").unwrap_or(&claims);
+    let formats = match vp.get("verifiableCredential") {
+        Some(Value::Array(vcs)) => vcs.iter().map(vc_format).collect(),
+        Some(vc) => vec![vc_format(vc)],
+        None => vec![]
+    };
+    Ok(formats)
+}
+
+fn vc_format(vc: &Value) -> &'static str {
+    match vc {
+        Value::String(token) if token.contains('~') => "dc+sd-jwt",
+        Value::String(_) => "jwt_vc_json",
+        _ => "ldp_vc"
+    }
+}
+
+// DCQL responses map each credential query id to its presentations; only one distinct
+// presentation is supported, as verification checks a single vp_token
+pub fn single_presentation(vp_token: &Value) -> Outcome<String> {
+    let mut found: Vec<String> = Vec::new();
+    match vp_token {
+        Value::String(token) if token.trim_start().starts_with('{') => {
+            let value = serde_json::from_str(token).map_err(|e| {
+                Errors::format(BadFormat::Received, "Invalid vp_token", Some(Box::new(e)))
+            })?;
+            return single_presentation(&value);
+        }
+        Value::String(token) => found.push(token.clone()),
+        Value::Object(entries) => {
+            for entry in entries.values() {
+                match entry {
+                    Value::String(token) => found.push(token.clone()),
+                    Value::Array(tokens) => {
+                        found.extend(tokens.iter().filter_map(Value::as_str).map(str::to_string))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+    found.sort();
+    found.dedup();
+    match found.as_slice() {
+        [token] => Ok(token.clone()),
+        [] => Err(Errors::format(BadFormat::Received, "vp_token has no presentation", None)),
+        _ => Err(Errors::format(
+            BadFormat::Received,
+            "vp_token must carry a single presentation for all credential queries",
+            None
+        ))
+    }
+}
+
+pub fn vc_has_type(vc: &Value, vc_type: &str) -> bool {
+    match vc.get("type") {
+        Some(Value::Array(types)) => types.iter().any(|t| t.as_str() == Some(vc_type)),
+        Some(Value::String(t)) => t == vc_type,
+        _ => false
+    }
+}
+
+// JWT registered claims take the place of their VC counterparts (VC-JWT encoding rules)
+fn normalize_jwt_vc(claims: Value) -> Value {
+    let Some(mut vc) = claims.get("vc").cloned() else {
+        return claims;
+    };
+    if let Some(vc) = vc.as_object_mut() {
+        if let Some(iss) = claims.get("iss") {
+            vc.entry("issuer").or_insert_with(|| iss.clone());
+        }
+        if let Some(jti) = claims.get("jti") {
+            vc.entry("id").or_insert_with(|| jti.clone());
+        }
+        let issued = claims.get("nbf").or_else(|| claims.get("iat")).and_then(Value::as_i64);
+        if let Some(date) = issued.and_then(|ts| DateTime::from_timestamp(ts, 0)) {
+            vc.entry("validFrom").or_insert_with(|| Value::String(date.to_rfc3339()));
+        }
+        if let (Some(sub), Some(Value::Object(subject))) =
+            (claims.get("sub"), vc.get_mut("credentialSubject"))
+        {
+            subject.entry("id").or_insert_with(|| sub.clone());
+        }
+    }
+    vc
+}
+
+pub fn vc_issuer(vc: &Value) -> Option<&str> {
+    let issuer = vc.get("issuer").or_else(|| vc.get("iss"))?;
+    issuer.as_str().or_else(|| issuer.get("id").and_then(Value::as_str))
+}
+
+pub fn vc_issued_at(vc: &Value) -> Option<DateTime<Utc>> {
+    ["validFrom", "issuanceDate"]
+        .iter()
+        .filter_map(|key| vc.get(*key).and_then(Value::as_str))
+        .find_map(|date| DateTime::parse_from_rfc3339(date).ok())
+        .map(|date| date.with_timezone(&Utc))
+}
+
+pub fn vc_subject_ids(vc: &Value) -> Vec<&str> {
+    match vc.get("credentialSubject") {
+        Some(Value::Array(subjects)) => {
+            subjects.iter().filter_map(|s| s.get("id").and_then(Value::as_str)).collect()
+        }
+        Some(subject) => subject.get("id").and_then(Value::as_str).into_iter().collect(),
+        None => vec![]
+    }
+}